@@ -1,14 +1,15 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    sub_app::write_schedule_graphs, First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin,
+    Plugins, PluginsState, SubApp, SubApps,
 };
+use alloc::collections::BTreeMap;
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     component::RequiredComponentsError,
     event::{event_update_system, EventCursor},
     intern::Interned,
     prelude::*,
-    schedule::{ScheduleBuildSettings, ScheduleLabel},
+    schedule::{ScheduleBuildError, ScheduleBuildSettings, ScheduleGraphFormat, ScheduleLabel},
     system::{IntoObserverSystem, SystemId, SystemInput},
 };
 #[cfg(feature = "trace")]
@@ -18,6 +19,7 @@ use core::{fmt::Debug, num::NonZero, panic::AssertUnwindSafe};
 use derive_more::derive::{Display, Error};
 use std::{
     panic::{catch_unwind, resume_unwind},
+    path::Path,
     process::{ExitCode, Termination},
 };
 
@@ -105,6 +107,7 @@ impl Default for App {
                 .run_if(bevy_ecs::event::event_update_condition),
         );
         app.add_event::<AppExit>();
+        app.add_event::<bevy_ecs::system::CommandFailed>();
 
         app
     }
@@ -604,6 +607,39 @@ impl App {
         self
     }
 
+    /// Registers the [`TypeMigrations`] of type `T` in the [`AppTypeRegistry`] resource,
+    /// which are used to read scenes and other reflected data written by older versions of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_app::App;
+    /// use bevy_reflect::{Reflect, serde::{Migration, TypeMigrations, TypeVersion}};
+    ///
+    /// #[derive(Reflect)]
+    /// #[reflect(@TypeVersion(1))]
+    /// struct Player {
+    ///     health: u32,
+    /// }
+    ///
+    /// App::new()
+    ///     .register_type::<Player>()
+    ///     .register_migrations::<Player>(
+    ///         TypeMigrations::new().with_migration(Migration::new(0).rename_field("hp", "health")),
+    ///     );
+    /// ```
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_migrations`].
+    ///
+    /// [`TypeMigrations`]: bevy_reflect::serde::TypeMigrations
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_migrations<T: bevy_reflect::Reflect + bevy_reflect::TypePath>(
+        &mut self,
+        migrations: bevy_reflect::serde::TypeMigrations,
+    ) -> &mut Self {
+        self.main_mut().register_migrations::<T>(migrations);
+        self
+    }
+
     /// Registers the given function into the [`AppFunctionRegistry`] resource.
     ///
     /// The given function will internally be stored as a [`DynamicFunction`]
@@ -1123,6 +1159,61 @@ impl App {
         self
     }
 
+    /// Builds every schedule of the main app and its [sub-apps](SubApp), and exports its graph in the
+    /// given `format`, keyed by the debug representation of the schedule label.
+    /// The keys of schedules in sub-apps are prefixed with the label of the sub-app, such as `RenderApp/Render`.
+    ///
+    /// The exported graphs contain the systems, system sets, hierarchy and dependency edges, run
+    /// conditions and ambiguities of each schedule, and are stable between runs. This is useful to
+    /// visualize system ordering or to check schedule changes into version control and diff them.
+    /// See [`Schedule::describe_graph`] for details.
+    ///
+    /// This should usually be called after all plugins are [finished](Self::finish).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first schedule that fails to build.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleGraphFormat;
+    /// fn hello() {}
+    ///
+    /// let mut app = App::new();
+    /// app.add_systems(Update, hello);
+    ///
+    /// let graphs = app.export_schedule_graphs(ScheduleGraphFormat::Dot).unwrap();
+    /// assert!(graphs["Update"].contains("hello"));
+    /// ```
+    pub fn export_schedule_graphs(
+        &mut self,
+        format: ScheduleGraphFormat,
+    ) -> Result<BTreeMap<String, String>, ScheduleBuildError> {
+        let mut graphs = self.main_mut().export_schedule_graphs(format)?;
+        for (app_label, sub_app) in &mut self.sub_apps.sub_apps {
+            for (label, graph) in sub_app.export_schedule_graphs(format)? {
+                graphs.insert(format!("{app_label:?}/{label}"), graph);
+            }
+        }
+        Ok(graphs)
+    }
+
+    /// Exports the graph of every schedule of the main app and its sub-apps with
+    /// [`export_schedule_graphs`](Self::export_schedule_graphs), and writes each of them to a
+    /// file named after its key in `directory`.
+    ///
+    /// The directory is created if it doesn't exist, and existing files are overwritten.
+    pub fn write_schedule_graphs(
+        &mut self,
+        directory: impl AsRef<Path>,
+        format: ScheduleGraphFormat,
+    ) -> std::io::Result<()> {
+        let graphs = self
+            .export_schedule_graphs(format)
+            .map_err(std::io::Error::other)?;
+        write_schedule_graphs(directory.as_ref(), format, graphs)
+    }
+
     /// When doing [ambiguity checking](ScheduleBuildSettings) this
     /// ignores systems that are ambiguous on [`Component`] T.
     ///
@@ -1380,7 +1471,7 @@ mod tests {
         event::{Event, EventWriter, Events},
         query::With,
         removal_detection::RemovedComponents,
-        schedule::{IntoSystemConfigs, ScheduleLabel},
+        schedule::{IntoSystemConfigs, ScheduleGraphFormat, ScheduleLabel},
         system::{Commands, Query, Resource},
         world::{FromWorld, World},
     };
@@ -1778,4 +1869,29 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn export_sub_app_schedule_graphs() {
+        use super::AppLabel;
+        use crate::{self as bevy_app};
+
+        #[derive(AppLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct RenderApp;
+
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Render;
+
+        fn render() {}
+
+        let mut render_app = SubApp::new();
+        render_app.add_systems(Render, render);
+        let mut app = App::new();
+        app.insert_sub_app(RenderApp, render_app);
+
+        let graphs = app
+            .export_schedule_graphs(ScheduleGraphFormat::Dot)
+            .unwrap();
+        assert!(graphs.contains_key("Main"));
+        assert!(graphs["RenderApp/Render"].contains("render"));
+    }
 }
@@ -2,14 +2,19 @@ use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
 use bevy_ecs::{
     event::EventRegistry,
     prelude::*,
-    schedule::{InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel},
+    schedule::{
+        InternedScheduleLabel, ScheduleBuildError, ScheduleBuildSettings, ScheduleGraphFormat,
+        ScheduleLabel,
+    },
     system::{SystemId, SystemInput},
 };
 
+use alloc::collections::BTreeMap;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
 use bevy_utils::{HashMap, HashSet};
 use core::fmt::Debug;
+use std::path::Path;
 
 type ExtractFn = Box<dyn Fn(&mut World, &mut World) + Send>;
 
@@ -272,6 +277,41 @@ impl SubApp {
         self
     }
 
+    /// See [`App::export_schedule_graphs`].
+    pub fn export_schedule_graphs(
+        &mut self,
+        format: ScheduleGraphFormat,
+    ) -> Result<BTreeMap<String, String>, ScheduleBuildError> {
+        let labels: Vec<_> = self
+            .world
+            .resource::<Schedules>()
+            .iter()
+            .map(|(_, schedule)| schedule.label())
+            .collect();
+
+        let mut graphs = BTreeMap::new();
+        for label in labels {
+            let graph = self.world.schedule_scope(label, |world, schedule| {
+                schedule.initialize(world)?;
+                Ok(schedule.describe_graph(world.components()).export(format))
+            })?;
+            graphs.insert(format!("{label:?}"), graph);
+        }
+        Ok(graphs)
+    }
+
+    /// See [`App::write_schedule_graphs`].
+    pub fn write_schedule_graphs(
+        &mut self,
+        directory: impl AsRef<Path>,
+        format: ScheduleGraphFormat,
+    ) -> std::io::Result<()> {
+        let graphs = self
+            .export_schedule_graphs(format)
+            .map_err(std::io::Error::other)?;
+        write_schedule_graphs(directory.as_ref(), format, graphs)
+    }
+
     /// See [`App::allow_ambiguous_component`].
     pub fn allow_ambiguous_component<T: Component>(&mut self) -> &mut Self {
         self.world_mut().allow_ambiguous_component::<T>();
@@ -413,6 +453,17 @@ impl SubApp {
         self
     }
 
+    /// See [`App::register_migrations`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_migrations<T: bevy_reflect::Reflect + bevy_reflect::TypePath>(
+        &mut self,
+        migrations: bevy_reflect::serde::TypeMigrations,
+    ) -> &mut Self {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        registry.write().register_migrations::<T>(migrations);
+        self
+    }
+
     /// See [`App::register_function`].
     #[cfg(feature = "reflect_functions")]
     pub fn register_function<F, Marker>(&mut self, function: F) -> &mut Self
@@ -488,3 +539,29 @@ impl SubApps {
         }
     }
 }
+
+/// Writes each of the exported schedule `graphs` to a file named after its key in `directory`.
+pub(crate) fn write_schedule_graphs(
+    directory: &Path,
+    format: ScheduleGraphFormat,
+    graphs: BTreeMap<String, String>,
+) -> std::io::Result<()> {
+    std::fs::create_dir_all(directory)?;
+    for (label, graph) in graphs {
+        let file_name: String = label
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        std::fs::write(
+            directory.join(file_name).with_extension(format.extension()),
+            graph,
+        )?;
+    }
+    Ok(())
+}
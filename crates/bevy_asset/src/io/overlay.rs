@@ -0,0 +1,377 @@
+use crate::io::{
+    AssetReader, AssetReaderError, AssetSourceEvent, AssetWatcher, ErasedAssetReader, PathStream,
+    Reader,
+};
+use bevy_utils::HashSet;
+use futures_lite::StreamExt;
+use std::path::Path;
+
+/// An [`AssetReader`] that composes several readers ("layers"), such as a mods folder,
+/// a patch archive and the base game assets.
+///
+/// Layers are ordered from highest to lowest priority. Each path is read from the
+/// highest-priority layer that contains it, so files in higher layers override files
+/// in lower layers without modifying them. Directory listings are merged across all layers.
+///
+/// The `.meta` file of an asset is read from the same layer as the asset itself, so that an
+/// overriding asset without a `.meta` file uses the default meta instead of the meta of the
+/// asset it overrides.
+///
+/// To also forward file watcher events from every layer, use [`AssetSourceBuilder::overlay`].
+///
+/// [`AssetSourceBuilder::overlay`]: crate::io::AssetSourceBuilder::overlay
+pub struct OverlayAssetReader {
+    layers: Vec<Box<dyn ErasedAssetReader>>,
+}
+
+impl OverlayAssetReader {
+    /// Creates a new [`OverlayAssetReader`] from the given `layers`, ordered from highest to lowest priority.
+    pub fn new(layers: impl IntoIterator<Item = Box<dyn ErasedAssetReader>>) -> Self {
+        Self {
+            layers: layers.into_iter().collect(),
+        }
+    }
+
+    /// Returns the layers of this reader, ordered from highest to lowest priority.
+    pub fn layers(&self) -> &[Box<dyn ErasedAssetReader>] {
+        &self.layers
+    }
+}
+
+impl AssetReader for OverlayAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read_meta(path).await {
+                Err(AssetReaderError::NotFound(not_found)) => {
+                    // the asset is in this layer, but its meta is not
+                    if layer.exists(path).await? {
+                        return Err(AssetReaderError::NotFound(not_found));
+                    }
+                }
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let mut found = false;
+        let mut seen = HashSet::new();
+        let mut paths = Vec::new();
+        for layer in &self.layers {
+            match layer.read_directory(path).await {
+                Ok(stream) => {
+                    found = true;
+                    let layer_paths = stream.collect::<Vec<_>>().await;
+                    paths.extend(
+                        layer_paths
+                            .into_iter()
+                            .filter(|path| seen.insert(path.clone())),
+                    );
+                }
+                Err(AssetReaderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        if !found {
+            return Err(AssetReaderError::NotFound(path.to_owned()));
+        }
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(paths));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.is_directory(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        for layer in &self.layers {
+            if layer.exists(path).await? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Translates an event of the watcher of the given layer into an event of the overlay source.
+///
+/// An asset added to or removed from a layer while another layer also contains it still exists in the
+/// overlay, but may now be read from a different layer, so the event is turned into a
+/// [`ModifiedAsset`](AssetSourceEvent::ModifiedAsset) event to reload it.
+#[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+pub(crate) fn overlay_event(
+    layers: &[Option<Box<dyn ErasedAssetReader>>],
+    layer: usize,
+    event: AssetSourceEvent,
+) -> AssetSourceEvent {
+    let in_other_layer = |path: &Path| {
+        layers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != layer)
+            .filter_map(|(_, reader)| reader.as_ref())
+            .any(|reader| bevy_tasks::block_on(reader.exists(path)).unwrap_or(false))
+    };
+    match event {
+        AssetSourceEvent::AddedAsset(path) | AssetSourceEvent::RemovedAsset(path)
+            if in_other_layer(&path) =>
+        {
+            AssetSourceEvent::ModifiedAsset(path)
+        }
+        event => event,
+    }
+}
+
+/// An [`AssetWatcher`] that keeps the watchers of every layer of an [`OverlayAssetReader`] alive.
+pub struct OverlayAssetWatcher {
+    _watchers: Vec<Box<dyn AssetWatcher>>,
+}
+
+impl OverlayAssetWatcher {
+    /// Creates a new [`OverlayAssetWatcher`] from the given layer `watchers`.
+    pub fn new(watchers: Vec<Box<dyn AssetWatcher>>) -> Self {
+        Self {
+            _watchers: watchers,
+        }
+    }
+}
+
+impl AssetWatcher for OverlayAssetWatcher {}
+
+#[cfg(test)]
+mod tests {
+    use super::OverlayAssetReader;
+    use crate::io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetReader, AssetReaderError, AssetSource, AssetSourceBuilder, AssetSourceEvent,
+        AssetSourceId, AssetWatcher, PathStream, Reader,
+    };
+    use alloc::sync::Arc;
+    use core::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+    use futures_lite::{future::block_on, StreamExt};
+    use std::path::{Path, PathBuf};
+
+    struct TestWatcher;
+
+    impl AssetWatcher for TestWatcher {}
+
+    /// A [`MemoryAssetReader`] that counts how many assets it has opened.
+    struct CountingReader {
+        inner: MemoryAssetReader,
+        reads: Arc<AtomicUsize>,
+    }
+
+    impl AssetReader for CountingReader {
+        async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            self.inner.read(path).await
+        }
+
+        async fn read_meta<'a>(
+            &'a self,
+            path: &'a Path,
+        ) -> Result<impl Reader + 'a, AssetReaderError> {
+            self.inner.read_meta(path).await
+        }
+
+        async fn read_directory<'a>(
+            &'a self,
+            path: &'a Path,
+        ) -> Result<Box<PathStream>, AssetReaderError> {
+            self.inner.read_directory(path).await
+        }
+
+        async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+            self.inner.is_directory(path).await
+        }
+
+        async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+            self.inner.exists(path).await
+        }
+    }
+
+    fn read_to_string(reader: &mut impl Reader) -> String {
+        let mut bytes = Vec::new();
+        block_on(reader.read_to_end(&mut bytes)).unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    fn layer(dir: &Dir) -> Box<dyn crate::io::ErasedAssetReader> {
+        Box::new(MemoryAssetReader { root: dir.clone() })
+    }
+
+    #[test]
+    fn overlay_reader() {
+        let mods = Dir::default();
+        mods.insert_asset_text(Path::new("a.txt"), "mod a");
+        mods.insert_asset_text(Path::new("x/c.txt"), "mod c");
+        let base = Dir::default();
+        base.insert_asset_text(Path::new("a.txt"), "base a");
+        base.insert_meta_text(Path::new("a.txt"), "base a meta");
+        base.insert_asset_text(Path::new("b.txt"), "base b");
+        base.insert_meta_text(Path::new("b.txt"), "base b meta");
+        base.insert_asset_text(Path::new("x/d.txt"), "base d");
+        let reader = OverlayAssetReader::new([layer(&mods), layer(&base)]);
+
+        let mut asset = block_on(reader.read(Path::new("a.txt"))).unwrap();
+        assert_eq!(read_to_string(&mut asset), "mod a");
+        let mut asset = block_on(reader.read(Path::new("b.txt"))).unwrap();
+        assert_eq!(read_to_string(&mut asset), "base b");
+        assert!(matches!(
+            block_on(reader.read(Path::new("missing.txt"))),
+            Err(AssetReaderError::NotFound(_))
+        ));
+
+        // the overriding asset has no meta, so the base meta is not used
+        assert!(matches!(
+            block_on(reader.read_meta(Path::new("a.txt"))),
+            Err(AssetReaderError::NotFound(_))
+        ));
+        let mut meta = block_on(reader.read_meta(Path::new("b.txt"))).unwrap();
+        assert_eq!(read_to_string(&mut meta), "base b meta");
+
+        let mut paths = block_on(async {
+            let stream = reader.read_directory(Path::new("x")).await.unwrap();
+            stream.collect::<Vec<_>>().await
+        });
+        paths.sort();
+        assert_eq!(paths, [PathBuf::from("x/c.txt"), PathBuf::from("x/d.txt")]);
+
+        assert!(block_on(reader.is_directory(Path::new("x"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("b.txt"))).unwrap());
+    }
+
+    #[test]
+    fn overlay_read_meta_does_not_open_assets() {
+        let mods = Dir::default();
+        mods.insert_asset_text(Path::new("a.txt"), "mod a");
+        let base = Dir::default();
+        base.insert_asset_text(Path::new("a.txt"), "base a");
+        base.insert_meta_text(Path::new("a.txt"), "base a meta");
+        base.insert_asset_text(Path::new("b.txt"), "base b");
+        base.insert_meta_text(Path::new("b.txt"), "base b meta");
+        let reads = Arc::new(AtomicUsize::new(0));
+        let counting_layer = |dir: &Dir| -> Box<dyn crate::io::ErasedAssetReader> {
+            Box::new(CountingReader {
+                inner: MemoryAssetReader { root: dir.clone() },
+                reads: reads.clone(),
+            })
+        };
+        let reader = OverlayAssetReader::new([counting_layer(&mods), counting_layer(&base)]);
+
+        assert!(matches!(
+            block_on(reader.read_meta(Path::new("a.txt"))),
+            Err(AssetReaderError::NotFound(_))
+        ));
+        let mut meta = block_on(reader.read_meta(Path::new("b.txt"))).unwrap();
+        assert_eq!(read_to_string(&mut meta), "base b meta");
+        assert!(block_on(reader.exists(Path::new("b.txt"))).unwrap());
+        assert!(!block_on(reader.exists(Path::new("missing.txt"))).unwrap());
+        assert_eq!(reads.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn overlay_source_forwards_events() {
+        let layer_builder = |path: &'static str| {
+            AssetSourceBuilder::default()
+                .with_reader(|| Box::new(MemoryAssetReader::default()))
+                .with_watcher(move |sender| {
+                    sender
+                        .send(AssetSourceEvent::ModifiedAsset(PathBuf::from(path)))
+                        .unwrap();
+                    Some(Box::new(TestWatcher))
+                })
+        };
+        let mut builder =
+            AssetSourceBuilder::overlay([layer_builder("mod.txt"), layer_builder("base.txt")]);
+        let source = builder.build(AssetSourceId::Default, true, false).unwrap();
+
+        assert_events(
+            &source,
+            [
+                AssetSourceEvent::ModifiedAsset(PathBuf::from("mod.txt")),
+                AssetSourceEvent::ModifiedAsset(PathBuf::from("base.txt")),
+            ],
+        );
+    }
+
+    #[test]
+    fn overlay_source_reloads_overridden_assets() {
+        let mods = Dir::default();
+        mods.insert_asset_text(Path::new("new.txt"), "mod new");
+        let base = Dir::default();
+        base.insert_asset_text(Path::new("a.txt"), "base a");
+        base.insert_asset_text(Path::new("b.txt"), "base b");
+        let mods_reader = mods.clone();
+        let mods_builder = AssetSourceBuilder::default()
+            .with_reader(move || layer(&mods_reader))
+            .with_watcher(|sender| {
+                // the override of `a.txt` was removed, so the base `a.txt` is used instead
+                sender
+                    .send(AssetSourceEvent::RemovedAsset(PathBuf::from("a.txt")))
+                    .unwrap();
+                sender
+                    .send(AssetSourceEvent::RemovedAsset(PathBuf::from("c.txt")))
+                    .unwrap();
+                // `b.txt` is now overridden
+                sender
+                    .send(AssetSourceEvent::AddedAsset(PathBuf::from("b.txt")))
+                    .unwrap();
+                sender
+                    .send(AssetSourceEvent::AddedAsset(PathBuf::from("new.txt")))
+                    .unwrap();
+                Some(Box::new(TestWatcher))
+            });
+        let base_builder = AssetSourceBuilder::default()
+            .with_reader(move || layer(&base))
+            .with_watcher(|_| Some(Box::new(TestWatcher)));
+        let mut builder = AssetSourceBuilder::overlay([mods_builder, base_builder]);
+        let source = builder.build(AssetSourceId::Default, true, false).unwrap();
+
+        assert_events(
+            &source,
+            [
+                AssetSourceEvent::ModifiedAsset(PathBuf::from("a.txt")),
+                AssetSourceEvent::RemovedAsset(PathBuf::from("c.txt")),
+                AssetSourceEvent::ModifiedAsset(PathBuf::from("b.txt")),
+                AssetSourceEvent::AddedAsset(PathBuf::from("new.txt")),
+            ],
+        );
+    }
+
+    /// Asserts that the given source receives the `expected` events, in any order,
+    /// since the events of each layer are forwarded on a separate thread.
+    fn assert_events<const N: usize>(source: &AssetSource, expected: [AssetSourceEvent; N]) {
+        let receiver = source.event_receiver().unwrap();
+        let events: Vec<_> = (0..N)
+            .map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        for event in &expected {
+            assert!(events.contains(event), "missing {event:?} in {events:?}");
+        }
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,502 @@
+//! Reading and writing of Bevy pak archives, which store many assets in a single file.
+//!
+//! A pak archive is laid out as follows, with all integers stored in little endian:
+//!
+//! | Field         | Type                  | Description                                      |
+//! |---------------|-----------------------|--------------------------------------------------|
+//! | `magic`       | `[u8; 4]`             | Always `b"BPAK"`.                                |
+//! | `version`     | `u32`                 | The format version, currently `1`.               |
+//! | `entry_count` | `u32`                 | The number of entries in the index.              |
+//! | `index`       | `[Entry; entry_count]`| The index of all stored files.                   |
+//! | `data`        | `[u8]`                | The contents of all stored files.                |
+//!
+//! Each `Entry` is a `u32` path length, followed by the UTF-8 path (using `/` as separator),
+//! a `u64` offset of the file contents from the start of the archive and a `u64` length.
+//!
+//! Asset `.meta` files are stored next to their asset, just like in an asset folder.
+//! Directories are not stored explicitly and are inferred from the stored paths.
+
+use crate::io::{get_meta_path, AssetReader, AssetReaderError, PathStream, Reader, VecReader};
+use alloc::{collections::BTreeMap, sync::Arc};
+use bevy_utils::HashMap;
+use derive_more::derive::{Display, Error, From};
+use std::{
+    io::{Read, Write},
+    path::{Component, Path, PathBuf},
+};
+
+const PAK_MAGIC: &[u8; 4] = b"BPAK";
+const PAK_VERSION: u32 = 1;
+
+/// Errors that occur while reading or writing a pak archive.
+#[derive(Error, Display, Debug, From)]
+pub enum PakError {
+    /// Encountered an I/O error while reading or writing the archive.
+    #[display("encountered an io error while reading or writing the pak archive: {_0}")]
+    Io(std::io::Error),
+    /// The data does not start with the pak magic bytes.
+    #[display("the data is not a pak archive")]
+    InvalidMagic,
+    /// The archive was written with an unsupported version of the format.
+    #[display("unsupported pak archive version {_0}, expected version {PAK_VERSION}")]
+    #[error(ignore)]
+    #[from(ignore)]
+    UnsupportedVersion(u32),
+    /// The index of the archive is malformed.
+    #[display("the pak archive index is invalid: {_0}")]
+    #[error(ignore)]
+    #[from(ignore)]
+    InvalidIndex(&'static str),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PakEntry {
+    offset: u64,
+    len: u64,
+}
+
+#[derive(Debug)]
+enum PakData {
+    Bytes(Arc<[u8]>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+struct PakArchive {
+    data: PakData,
+    entries: HashMap<PathBuf, PakEntry>,
+    directories: HashMap<PathBuf, Vec<PathBuf>>,
+}
+
+/// An [`AssetReader`] that reads assets from a Bevy pak archive.
+///
+/// The archive index is read once when the reader is created, and the reader can be cheaply cloned.
+/// Archives can be created from a folder of assets, such as the output of the
+/// [`AssetProcessor`](crate::processor::AssetProcessor), using a [`PakWriter`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_asset::{AssetApp, io::{AssetSourceBuilder, pak::PakAssetReader}};
+/// # let mut app = App::new();
+/// let reader = PakAssetReader::open("assets.pak").unwrap();
+/// app.register_asset_source(
+///     "pak",
+///     AssetSourceBuilder::default().with_reader(move || Box::new(reader.clone())),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct PakAssetReader {
+    archive: Arc<PakArchive>,
+}
+
+impl PakAssetReader {
+    /// Opens the pak archive at the given `path`, reading its index.
+    ///
+    /// File contents are read from the archive on demand.
+    /// This blocks while the index is read.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, PakError> {
+        let path = path.as_ref();
+        let mut file = std::io::BufReader::new(std::fs::File::open(path)?);
+        let archive_len = file.get_ref().metadata()?.len();
+        let entries = read_index(&mut file, archive_len)?;
+        Ok(Self::new(PakData::File(path.to_owned()), entries))
+    }
+
+    /// Creates a reader for a pak archive stored in memory.
+    pub fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> Result<Self, PakError> {
+        let bytes = bytes.into();
+        let entries = read_index(&mut &bytes[..], bytes.len() as u64)?;
+        Ok(Self::new(PakData::Bytes(bytes), entries))
+    }
+
+    fn new(data: PakData, entries: HashMap<PathBuf, PakEntry>) -> Self {
+        let mut directories = HashMap::<PathBuf, Vec<PathBuf>>::default();
+        directories.insert(PathBuf::new(), Vec::new());
+        for path in entries.keys() {
+            let mut child = path.as_path();
+            while let Some(parent) = child.parent() {
+                let is_new_directory = !directories.contains_key(parent);
+                let children = directories.entry(parent.to_owned()).or_default();
+                // meta files are not considered assets
+                if !is_meta_file(child) && !children.iter().any(|c| c == child) {
+                    children.push(child.to_owned());
+                }
+                if !is_new_directory {
+                    break;
+                }
+                child = parent;
+            }
+        }
+        for children in directories.values_mut() {
+            children.sort();
+        }
+        Self {
+            archive: Arc::new(PakArchive {
+                data,
+                entries,
+                directories,
+            }),
+        }
+    }
+
+    /// Returns an iterator over the paths of all files stored in the archive, including `.meta` files.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.archive.entries.keys().map(PathBuf::as_path)
+    }
+
+    async fn read_entry(&self, path: &Path) -> Result<VecReader, AssetReaderError> {
+        let entry = *self
+            .archive
+            .entries
+            .get(path)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        // The index is validated against the archive length, so offsets fit in memory for in-memory archives.
+        let bytes = match &self.archive.data {
+            PakData::Bytes(bytes) => {
+                bytes[entry.offset as usize..(entry.offset + entry.len) as usize].to_vec()
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            PakData::File(archive_path) => {
+                use futures_lite::{AsyncReadExt, AsyncSeekExt};
+                let mut file = async_fs::File::open(archive_path).await?;
+                file.seek(std::io::SeekFrom::Start(entry.offset)).await?;
+                let mut bytes = vec![0; entry.len as usize];
+                file.read_exact(&mut bytes).await?;
+                bytes
+            }
+        };
+        Ok(VecReader::new(bytes))
+    }
+}
+
+impl AssetReader for PakAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_entry(path).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_entry(&get_meta_path(path)).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let children = self
+            .archive
+            .directories
+            .get(path)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(children.clone()));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        if self.archive.directories.contains_key(path) {
+            Ok(true)
+        } else if self.archive.entries.contains_key(path) {
+            Ok(false)
+        } else {
+            Err(AssetReaderError::NotFound(path.to_owned()))
+        }
+    }
+
+    async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.archive.entries.contains_key(path))
+    }
+}
+
+enum PakSource {
+    Bytes(Vec<u8>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+}
+
+/// Creates Bevy pak archives, which can be read using a [`PakAssetReader`].
+///
+/// To pack processed assets, add the processed asset folder
+/// (`imported_assets/Default` by default) using [`PakWriter::add_directory`].
+/// This includes the `.meta` files written by the [`AssetProcessor`](crate::processor::AssetProcessor).
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy_asset::io::pak::PakWriter;
+/// PakWriter::new()
+///     .add_directory("imported_assets/Default")
+///     .unwrap()
+///     .write_to_file("assets.pak")
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct PakWriter {
+    entries: BTreeMap<String, PakSource>,
+}
+
+impl PakWriter {
+    /// Creates an empty [`PakWriter`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file at `path` with the given contents, replacing any file previously added at that path.
+    pub fn add(&mut self, path: impl AsRef<Path>, bytes: impl Into<Vec<u8>>) -> &mut Self {
+        self.entries
+            .insert(pak_path(path.as_ref()), PakSource::Bytes(bytes.into()));
+        self
+    }
+
+    /// Adds the file at `source` to the archive at `path`, replacing any file previously added at that path.
+    ///
+    /// The file is read when the archive is written.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_file(&mut self, path: impl AsRef<Path>, source: impl Into<PathBuf>) -> &mut Self {
+        self.entries
+            .insert(pak_path(path.as_ref()), PakSource::File(source.into()));
+        self
+    }
+
+    /// Recursively adds all files in the `root` folder, using their paths relative to `root`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_directory(&mut self, root: impl AsRef<Path>) -> Result<&mut Self, PakError> {
+        let root = root.as_ref();
+        let mut directories = vec![root.to_owned()];
+        while let Some(directory) = directories.pop() {
+            for dir_entry in std::fs::read_dir(&directory)? {
+                let source = dir_entry?.path();
+                if source.is_dir() {
+                    directories.push(source);
+                } else {
+                    let path = source.strip_prefix(root).unwrap().to_owned();
+                    self.add_file(path, source);
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Writes the archive to `writer`.
+    pub fn write(&self, mut writer: impl Write) -> Result<(), PakError> {
+        let mut lens = Vec::with_capacity(self.entries.len());
+        for source in self.entries.values() {
+            lens.push(match source {
+                PakSource::Bytes(bytes) => bytes.len() as u64,
+                #[cfg(not(target_arch = "wasm32"))]
+                PakSource::File(path) => std::fs::metadata(path)?.len(),
+            });
+        }
+
+        let entry_count = u32::try_from(self.entries.len())
+            .map_err(|_| PakError::InvalidIndex("too many entries"))?;
+        let index_len = self
+            .entries
+            .keys()
+            .map(|path| 4 + path.len() as u64 + 16)
+            .sum::<u64>();
+        let mut offset = (PAK_MAGIC.len() + 8) as u64 + index_len;
+
+        writer.write_all(PAK_MAGIC)?;
+        writer.write_all(&PAK_VERSION.to_le_bytes())?;
+        writer.write_all(&entry_count.to_le_bytes())?;
+        for (path, len) in self.entries.keys().zip(&lens) {
+            let path_len =
+                u32::try_from(path.len()).map_err(|_| PakError::InvalidIndex("path too long"))?;
+            writer.write_all(&path_len.to_le_bytes())?;
+            writer.write_all(path.as_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&len.to_le_bytes())?;
+            offset += len;
+        }
+
+        for (source, len) in self.entries.values().zip(lens) {
+            match source {
+                PakSource::Bytes(bytes) => writer.write_all(bytes)?,
+                #[cfg(not(target_arch = "wasm32"))]
+                PakSource::File(path) => {
+                    let file = std::fs::File::open(path)?;
+                    if std::io::copy(&mut file.take(len), &mut writer)? != len {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            format!("{} changed while it was being packed", path.display()),
+                        )
+                        .into());
+                    }
+                }
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the archive to a file at `path`, creating its parent folders if needed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), PakError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        self.write(std::io::BufWriter::new(std::fs::File::create(path)?))
+    }
+}
+
+/// Converts `path` to the `/` separated form stored in the archive index.
+fn pak_path(path: &Path) -> String {
+    let mut pak_path = String::new();
+    for component in path.components() {
+        if let Component::Normal(name) = component {
+            if !pak_path.is_empty() {
+                pak_path.push('/');
+            }
+            pak_path.push_str(&name.to_string_lossy());
+        }
+    }
+    pak_path
+}
+
+fn is_meta_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("meta"))
+}
+
+fn read_index(
+    reader: &mut impl Read,
+    archive_len: u64,
+) -> Result<HashMap<PathBuf, PakEntry>, PakError> {
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != PAK_MAGIC {
+        return Err(PakError::InvalidMagic);
+    }
+    let version = read_u32(reader)?;
+    if version != PAK_VERSION {
+        return Err(PakError::UnsupportedVersion(version));
+    }
+
+    let entry_count = read_u32(reader)?;
+    let mut entries = HashMap::default();
+    for _ in 0..entry_count {
+        let path_len = read_u32(reader)? as u64;
+        if path_len > archive_len {
+            return Err(PakError::InvalidIndex(
+                "path length exceeds the archive length",
+            ));
+        }
+        let mut path = Vec::new();
+        reader.take(path_len).read_to_end(&mut path)?;
+        let path = String::from_utf8(path)
+            .map_err(|_| PakError::InvalidIndex("path is not valid UTF-8"))?;
+        let path = PathBuf::from(path);
+        if !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(PakError::InvalidIndex(
+                "path is not a normalized relative path",
+            ));
+        }
+
+        let entry = PakEntry {
+            offset: read_u64(reader)?,
+            len: read_u64(reader)?,
+        };
+        match entry.offset.checked_add(entry.len) {
+            Some(end) if end <= archive_len => {}
+            _ => return Err(PakError::InvalidIndex("entry exceeds the archive length")),
+        }
+        entries.insert(path, entry);
+    }
+    Ok(entries)
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> std::io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PakAssetReader, PakError, PakWriter};
+    use crate::io::{AssetReader, AssetReaderError, Reader};
+    use futures_lite::{future::block_on, StreamExt};
+    use std::path::{Path, PathBuf};
+
+    fn read_to_string(reader: &mut impl Reader) -> String {
+        let mut bytes = Vec::new();
+        block_on(reader.read_to_end(&mut bytes)).unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn pak_round_trip() {
+        let mut bytes = Vec::new();
+        PakWriter::new()
+            .add("a.txt", "a")
+            .add("a.txt.meta", "a meta")
+            .add("x/y/b.txt", "b")
+            .add("x/c.txt", "c")
+            .write(&mut bytes)
+            .unwrap();
+        let reader = PakAssetReader::from_bytes(bytes).unwrap();
+
+        let mut asset = block_on(reader.read(Path::new("a.txt"))).unwrap();
+        assert_eq!(read_to_string(&mut asset), "a");
+        let mut meta = block_on(reader.read_meta(Path::new("a.txt"))).unwrap();
+        assert_eq!(read_to_string(&mut meta), "a meta");
+        let mut asset = block_on(reader.read(Path::new("x/y/b.txt"))).unwrap();
+        assert_eq!(read_to_string(&mut asset), "b");
+        assert_eq!(
+            block_on(reader.read(Path::new("missing.txt"))).err(),
+            Some(AssetReaderError::NotFound(PathBuf::from("missing.txt")))
+        );
+
+        let read_directory = |path: &str| {
+            block_on(async {
+                let stream = reader.read_directory(Path::new(path)).await.unwrap();
+                stream.collect::<Vec<_>>().await
+            })
+        };
+        assert_eq!(
+            read_directory(""),
+            [PathBuf::from("a.txt"), PathBuf::from("x")]
+        );
+        assert_eq!(
+            read_directory("x"),
+            [PathBuf::from("x/c.txt"), PathBuf::from("x/y")]
+        );
+
+        assert!(block_on(reader.is_directory(Path::new("x/y"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("x/c.txt"))).unwrap());
+        assert!(block_on(reader.is_directory(Path::new("z"))).is_err());
+    }
+
+    #[test]
+    fn pak_invalid_data() {
+        assert!(matches!(
+            PakAssetReader::from_bytes(b"not a pak".to_vec()),
+            Err(PakError::InvalidMagic)
+        ));
+
+        let mut bytes = Vec::new();
+        PakWriter::new()
+            .add("a.txt", "a")
+            .write(&mut bytes)
+            .unwrap();
+        bytes.truncate(bytes.len() - 1);
+        assert!(matches!(
+            PakAssetReader::from_bytes(bytes),
+            Err(PakError::InvalidIndex(_))
+        ));
+    }
+}
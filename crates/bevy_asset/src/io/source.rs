@@ -1,5 +1,9 @@
 use crate::{
-    io::{processor_gated::ProcessorGatedReader, AssetSourceEvent, AssetWatcher},
+    io::{
+        overlay::{overlay_event, OverlayAssetReader, OverlayAssetWatcher},
+        processor_gated::ProcessorGatedReader,
+        AssetSourceEvent, AssetWatcher,
+    },
     processor::AssetProcessorData,
 };
 use alloc::sync::Arc;
@@ -11,6 +15,7 @@ use bevy_utils::{
 };
 use core::{fmt::Display, hash::Hash};
 use derive_more::derive::{Display, Error};
+use std::sync::{Mutex, PoisonError};
 
 use super::{ErasedAssetReader, ErasedAssetWriter};
 
@@ -306,6 +311,143 @@ impl AssetSourceBuilder {
             default
         }
     }
+
+    /// Returns a builder that layers the given sources on top of each other, ordered from highest to lowest priority,
+    /// such as a mods folder, a patch archive and the base game assets.
+    ///
+    /// The readers of the layers are combined into an [`OverlayAssetReader`], and the watchers of every layer
+    /// forward their events to the overlay source, so hot reloading works for files in any layer.
+    /// An asset added to or removed from a layer while another layer also contains it is reported as modified,
+    /// since the path still exists but may now be read from a different layer.
+    /// Assets are written to the highest-priority layer that has a writer.
+    pub fn overlay(layers: impl IntoIterator<Item = AssetSourceBuilder>) -> Self {
+        let mut layers: Vec<_> = layers.into_iter().collect();
+        let readers = Arc::new(Mutex::new(
+            layers.iter_mut().map(|l| l.reader.take()).collect(),
+        ));
+        let processed_readers = Arc::new(Mutex::new(
+            layers
+                .iter_mut()
+                .map(|l| l.processed_reader.take())
+                .collect(),
+        ));
+        Self {
+            watcher: overlay_watchers(
+                readers.clone(),
+                layers.iter_mut().map(|l| l.watcher.take()).collect(),
+            ),
+            reader: overlay_readers(readers),
+            writer: layers.iter_mut().find_map(|l| l.writer.take()),
+            processed_watcher: overlay_watchers(
+                processed_readers.clone(),
+                layers
+                    .iter_mut()
+                    .map(|l| l.processed_watcher.take())
+                    .collect(),
+            ),
+            processed_reader: overlay_readers(processed_readers),
+            processed_writer: layers.iter_mut().find_map(|l| l.processed_writer.take()),
+            watch_warning: layers.iter().find_map(|l| l.watch_warning),
+            processed_watch_warning: layers.iter().find_map(|l| l.processed_watch_warning),
+        }
+    }
+}
+
+type ReaderBuilder = Box<dyn FnMut() -> Box<dyn ErasedAssetReader> + Send + Sync>;
+type WatcherBuilder = Box<
+    dyn FnMut(crossbeam_channel::Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>>
+        + Send
+        + Sync,
+>;
+
+/// The reader builders of the layers of an overlay source, shared by its reader and watcher.
+type LayerReaderBuilders = Arc<Mutex<Vec<Option<ReaderBuilder>>>>;
+
+fn build_layer_readers(readers: &LayerReaderBuilders) -> Vec<Option<Box<dyn ErasedAssetReader>>> {
+    readers
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter_mut()
+        .map(|reader| reader.as_mut().map(|reader| reader()))
+        .collect()
+}
+
+fn overlay_readers(readers: LayerReaderBuilders) -> Option<ReaderBuilder> {
+    if readers
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .all(Option::is_none)
+    {
+        return None;
+    }
+    Some(Box::new(move || {
+        Box::new(OverlayAssetReader::new(
+            build_layer_readers(&readers).into_iter().flatten(),
+        ))
+    }))
+}
+
+fn overlay_watchers(
+    readers: LayerReaderBuilders,
+    mut watchers: Vec<Option<WatcherBuilder>>,
+) -> Option<WatcherBuilder> {
+    if watchers.iter().all(Option::is_none) {
+        return None;
+    }
+    Some(Box::new(move |sender| {
+        let layer_readers = Arc::new(build_layer_readers(&readers));
+        let watchers: Vec<_> = watchers
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(layer, watcher)| {
+                watch_layer(
+                    layer,
+                    watcher.as_mut()?,
+                    sender.clone(),
+                    layer_readers.clone(),
+                )
+            })
+            .collect();
+        if watchers.is_empty() {
+            None
+        } else {
+            Some(Box::new(OverlayAssetWatcher::new(watchers)))
+        }
+    }))
+}
+
+/// Starts the watcher of the given layer, forwarding its events to `sender` once they have been
+/// translated by [`overlay_event`].
+#[cfg(not(target_arch = "wasm32"))]
+fn watch_layer(
+    layer: usize,
+    watcher: &mut WatcherBuilder,
+    sender: crossbeam_channel::Sender<AssetSourceEvent>,
+    readers: Arc<Vec<Option<Box<dyn ErasedAssetReader>>>>,
+) -> Option<Box<dyn AssetWatcher>> {
+    let (layer_sender, layer_receiver) = crossbeam_channel::unbounded();
+    let watcher = watcher(layer_sender)?;
+    std::thread::spawn(move || {
+        for event in layer_receiver {
+            if sender.send(overlay_event(&readers, layer, event)).is_err() {
+                break;
+            }
+        }
+    });
+    Some(watcher)
+}
+
+/// Starts the watcher of the given layer, forwarding its events to `sender` as is,
+/// since events can't be translated without threads.
+#[cfg(target_arch = "wasm32")]
+fn watch_layer(
+    _layer: usize,
+    watcher: &mut WatcherBuilder,
+    sender: crossbeam_channel::Sender<AssetSourceEvent>,
+    _readers: Arc<Vec<Option<Box<dyn ErasedAssetReader>>>>,
+) -> Option<Box<dyn AssetWatcher>> {
+    watcher(sender)
 }
 
 /// A [`Resource`] that hold (repeatable) functions capable of producing new [`AssetReader`](crate::io::AssetReader) and [`AssetWriter`](crate::io::AssetWriter) instances
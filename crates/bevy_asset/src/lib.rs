@@ -1605,6 +1605,96 @@ mod tests {
         });
     }
 
+    #[test]
+    fn dependency_graph() {
+        let dir = Dir::default();
+
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: ["b.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: ["s"],
+)"#;
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: ["c.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let c_path = "c.cool.ron";
+        let c_ron = r#"
+(
+    text: "c",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+        dir.insert_asset_text(Path::new(c_path), c_ron);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        gate_opener.open(a_path);
+        gate_opener.open(b_path);
+        gate_opener.open(c_path);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load(a_path);
+        let a_id = handle.id().untyped();
+        run_app_until(&mut app, |_| {
+            asset_server.is_loaded_with_dependencies(a_id).then_some(())
+        });
+
+        let b_id = asset_server.get_path_id(b_path).unwrap();
+        let c_id = asset_server.get_path_id(c_path).unwrap();
+        let s_id = asset_server.get_path_id("a.cool.ron#s").unwrap();
+        let graph = asset_server.dependency_graph();
+        assert_eq!(graph.len(), 4);
+
+        let a = graph.get(a_id).unwrap();
+        assert_eq!(a.asset_type_name, Some(core::any::type_name::<CoolText>()));
+        assert_eq!(a.labeled_assets, [s_id]);
+        let stats = a.load_stats.as_ref().unwrap();
+        // with the `trace` feature, the loader is wrapped in an instrumented loader
+        assert!(stats
+            .loader_name
+            .contains(core::any::type_name::<CoolTextLoader>()));
+        assert_eq!(stats.bytes_read, a_ron.len() as u64);
+        assert_eq!(graph.get(s_id).unwrap().base_asset, Some(a_id));
+        assert!(graph.get(s_id).unwrap().load_stats.is_none());
+
+        let mut recursive_dependencies = graph.recursive_dependencies(a_id);
+        recursive_dependencies.sort();
+        let mut expected = vec![b_id, c_id, s_id];
+        expected.sort();
+        assert_eq!(recursive_dependencies, expected);
+        let mut recursive_dependents = graph.recursive_dependents(c_id);
+        recursive_dependents.sort();
+        let mut expected = vec![a_id, b_id];
+        expected.sort();
+        assert_eq!(recursive_dependents, expected);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph assets {"));
+        assert!(dot.contains("a.cool.ron#s"));
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+        let assets = json["assets"].as_array().unwrap();
+        assert_eq!(assets.len(), 4);
+        let s = assets
+            .iter()
+            .find(|asset| asset["path"] == "a.cool.ron#s")
+            .unwrap();
+        assert_eq!(s["base_asset"], a_id.to_string());
+        assert_eq!(s["load_stats"], serde_json::Value::Null);
+    }
+
     /// Tests that `AssetLoadFailedEvent<A>` events are emitted and can be used to retry failed assets.
     #[test]
     fn load_error_events() {
@@ -0,0 +1,340 @@
+use crate::{
+    io::{AsyncSeekForward, Reader},
+    AssetPath, LoadState, UntypedAssetId,
+};
+use alloc::collections::{BTreeMap, VecDeque};
+use bevy_utils::{Duration, HashMap, HashSet};
+use core::{
+    fmt::Write,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::AsyncRead;
+use futures_lite::ready;
+use serde::{Serialize, Serializer};
+
+/// Information about how an asset was loaded by the [`AssetServer`](crate::AssetServer).
+#[derive(Clone, Debug, Serialize)]
+pub struct AssetLoadStats {
+    /// The type name of the [`AssetLoader`](crate::AssetLoader) that loaded the asset.
+    pub loader_name: &'static str,
+    /// The time spent in the loader.
+    ///
+    /// This includes the time spent loading dependencies loaded with
+    /// [`NestedLoader::immediate`](crate::NestedLoader::immediate),
+    /// but not the time spent loading dependencies that are loaded in the background.
+    #[serde(serialize_with = "serialize_secs")]
+    pub load_duration: Duration,
+    /// The number of bytes the loader read from the asset's file.
+    ///
+    /// This does not include the `.meta` file or files read through the [`LoadContext`](crate::LoadContext).
+    pub bytes_read: u64,
+    /// The paths of other assets read by the loader ("loader dependencies").
+    pub loader_dependencies: Vec<AssetPath<'static>>,
+}
+
+/// An asset in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug, Serialize)]
+pub struct AssetDependencyNode {
+    /// The id of the asset.
+    #[serde(serialize_with = "serialize_id")]
+    pub id: UntypedAssetId,
+    /// The path of the asset, if it has one.
+    pub path: Option<AssetPath<'static>>,
+    /// The type name of the asset, if it has been loaded.
+    pub asset_type_name: Option<&'static str>,
+    /// The load state of the asset.
+    #[serde(serialize_with = "serialize_load_state")]
+    pub load_state: LoadState,
+    /// How the asset was loaded, if it was loaded from a file by the [`AssetServer`](crate::AssetServer).
+    ///
+    /// Labeled assets share the load of their base asset, so this is `None` for them.
+    pub load_stats: Option<AssetLoadStats>,
+    /// The assets this asset depends on.
+    #[serde(serialize_with = "serialize_ids")]
+    pub dependencies: Vec<UntypedAssetId>,
+    /// The assets that depend on this asset.
+    #[serde(serialize_with = "serialize_ids")]
+    pub dependents: Vec<UntypedAssetId>,
+    /// The labeled assets that were loaded alongside this asset.
+    #[serde(serialize_with = "serialize_ids")]
+    pub labeled_assets: Vec<UntypedAssetId>,
+    /// The base asset this asset was loaded alongside, if this is a labeled asset.
+    #[serde(serialize_with = "serialize_optional_id")]
+    pub base_asset: Option<UntypedAssetId>,
+}
+
+/// A snapshot of the assets tracked by the [`AssetServer`](crate::AssetServer) and the dependencies between them.
+///
+/// This can be used to find out why loading some assets takes long, by looking at the
+/// [recursive dependencies](Self::recursive_dependencies) of an asset and their [`AssetLoadStats`].
+/// The graph can be exported to the DOT format with [`to_dot`](Self::to_dot),
+/// or to JSON with [`to_json`](Self::to_json).
+///
+/// Returned by [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AssetDependencyGraph {
+    #[serde(rename = "assets", serialize_with = "serialize_nodes")]
+    nodes: BTreeMap<UntypedAssetId, AssetDependencyNode>,
+}
+
+impl AssetDependencyGraph {
+    /// Creates a graph from the given `nodes`, filling in their dependents and labeled assets.
+    pub(crate) fn new(nodes: impl IntoIterator<Item = AssetDependencyNode>) -> Self {
+        let mut nodes: BTreeMap<_, _> = nodes.into_iter().map(|node| (node.id, node)).collect();
+        let mut dependents = Vec::new();
+        let mut labeled_assets = Vec::new();
+        for node in nodes.values() {
+            dependents.extend(node.dependencies.iter().map(|dep| (*dep, node.id)));
+            if let Some(base_asset) = node.base_asset {
+                labeled_assets.push((base_asset, node.id));
+            }
+        }
+        for (dependency, dependent) in dependents {
+            if let Some(node) = nodes.get_mut(&dependency) {
+                node.dependents.push(dependent);
+            }
+        }
+        for (base_asset, labeled_asset) in labeled_assets {
+            if let Some(node) = nodes.get_mut(&base_asset) {
+                node.labeled_assets.push(labeled_asset);
+            }
+        }
+        for node in nodes.values_mut() {
+            node.dependencies.sort();
+            node.dependents.sort();
+            node.labeled_assets.sort();
+        }
+        Self { nodes }
+    }
+
+    /// Returns the node of the asset with the given `id`, if it is tracked.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetDependencyNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Returns an iterator over all assets in the graph.
+    pub fn iter(&self) -> impl Iterator<Item = &AssetDependencyNode> {
+        self.nodes.values()
+    }
+
+    /// Returns the number of assets in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the assets the given asset depends on, directly or indirectly,
+    /// including the labeled assets loaded alongside any of them.
+    pub fn recursive_dependencies(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        self.traverse(id.into(), |node| {
+            node.dependencies
+                .iter()
+                .chain(&node.labeled_assets)
+                .copied()
+        })
+    }
+
+    /// Returns the assets that depend on the given asset, directly or indirectly,
+    /// including the base assets of any labeled assets among them.
+    pub fn recursive_dependents(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        self.traverse(id.into(), |node| {
+            node.dependents.iter().chain(&node.base_asset).copied()
+        })
+    }
+
+    fn traverse<'a, I: Iterator<Item = UntypedAssetId> + 'a>(
+        &'a self,
+        id: UntypedAssetId,
+        edges: impl Fn(&'a AssetDependencyNode) -> I,
+    ) -> Vec<UntypedAssetId> {
+        let mut visited = HashSet::new();
+        visited.insert(id);
+        let mut queue = VecDeque::from([id]);
+        let mut result = Vec::new();
+        while let Some(id) = queue.pop_front() {
+            let Some(node) = self.nodes.get(&id) else {
+                continue;
+            };
+            for next in edges(node) {
+                if visited.insert(next) {
+                    result.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        result
+    }
+
+    /// Exports the graph in the [DOT](https://graphviz.org/doc/info/lang.html) format.
+    ///
+    /// Dependencies are drawn as solid edges and labeled assets as dashed edges from their base asset.
+    pub fn to_dot(&self) -> String {
+        let indices = self.indices();
+        let mut dot = String::from("digraph assets {\n");
+        for (index, node) in self.nodes.values().enumerate() {
+            let mut label = node_name(node);
+            if let Some(asset_type_name) = node.asset_type_name {
+                label.push('\n');
+                label.push_str(asset_type_name);
+            }
+            if let Some(stats) = &node.load_stats {
+                let _ = write!(
+                    label,
+                    "\n{:.2} ms, {} bytes",
+                    stats.load_duration.as_secs_f64() * 1000.0,
+                    stats.bytes_read
+                );
+            }
+            let _ = writeln!(dot, "    n{index} [label={}];", dot_quoted(&label));
+        }
+        for (index, node) in self.nodes.values().enumerate() {
+            for dependency in &node.dependencies {
+                if let Some(dependency) = indices.get(dependency) {
+                    let _ = writeln!(dot, "    n{index} -> n{dependency};");
+                }
+            }
+            for labeled_asset in &node.labeled_assets {
+                let _ = writeln!(
+                    dot,
+                    "    n{index} -> n{} [style=dashed];",
+                    indices[labeled_asset]
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the graph as JSON.
+    ///
+    /// The output is an object with an `assets` array, containing the [`AssetDependencyNode`] of each asset.
+    /// Assets are referred to by their id, written as a string. Load durations are in seconds.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("asset dependency graphs are always valid JSON")
+    }
+
+    fn indices(&self) -> HashMap<UntypedAssetId, usize> {
+        self.nodes
+            .keys()
+            .enumerate()
+            .map(|(index, id)| (*id, index))
+            .collect()
+    }
+}
+
+fn node_name(node: &AssetDependencyNode) -> String {
+    match &node.path {
+        Some(path) => path.to_string(),
+        None => node.id.to_string(),
+    }
+}
+
+fn serialize_nodes<S: Serializer>(
+    nodes: &BTreeMap<UntypedAssetId, AssetDependencyNode>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(nodes.values())
+}
+
+fn serialize_id<S: Serializer>(id: &UntypedAssetId, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(id)
+}
+
+fn serialize_ids<S: Serializer>(ids: &[UntypedAssetId], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(ids.iter().map(ToString::to_string))
+}
+
+fn serialize_optional_id<S: Serializer>(
+    id: &Option<UntypedAssetId>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    id.as_ref().map(ToString::to_string).serialize(serializer)
+}
+
+fn serialize_load_state<S: Serializer>(
+    load_state: &LoadState,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(match load_state {
+        LoadState::NotLoaded => "NotLoaded",
+        LoadState::Loading => "Loading",
+        LoadState::Loaded => "Loaded",
+        LoadState::Failed(_) => "Failed",
+    })
+}
+
+fn serialize_secs<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// Returns `value` as a quoted DOT string.
+fn dot_quoted(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A [`Reader`] that counts the bytes read from the wrapped reader.
+pub(crate) struct CountingReader<'a> {
+    reader: &'a mut dyn Reader,
+    pub(crate) bytes_read: u64,
+}
+
+impl<'a> CountingReader<'a> {
+    pub(crate) fn new(reader: &'a mut dyn Reader) -> Self {
+        Self {
+            reader,
+            bytes_read: 0,
+        }
+    }
+}
+
+impl AsyncRead for CountingReader<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<futures_io::Result<usize>> {
+        let n = ready!(Pin::new(&mut *self.reader).poll_read(cx, buf))?;
+        self.bytes_read += n as u64;
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncSeekForward for CountingReader<'_> {
+    fn poll_seek_forward(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: u64,
+    ) -> Poll<futures_io::Result<u64>> {
+        Pin::new(&mut *self.reader).poll_seek_forward(cx, offset)
+    }
+}
+
+impl Reader for CountingReader<'_> {
+    fn read_to_end<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+    ) -> crate::io::StackFuture<'a, std::io::Result<usize>, { crate::io::STACK_FUTURE_SIZE }> {
+        // Delegate to the wrapped reader, which may read the whole file more efficiently.
+        crate::io::StackFuture::from_or_box(async {
+            let n = self.reader.read_to_end(buf).await?;
+            self.bytes_read += n as u64;
+            Ok(n)
+        })
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
+    server::{AssetDependencyGraph, AssetDependencyNode, AssetLoadStats},
     Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
     Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
     UntypedAssetId, UntypedHandle,
@@ -26,6 +27,14 @@ pub(crate) struct AssetInfo {
     failed_rec_dependencies: HashSet<UntypedAssetId>,
     dependents_waiting_on_load: HashSet<UntypedAssetId>,
     dependents_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
+    /// The direct dependencies of this asset, as of its last load.
+    dependencies: HashSet<UntypedAssetId>,
+    /// The type name of this asset, set once it has loaded.
+    asset_type_name: Option<&'static str>,
+    /// How this asset was last loaded by the [`AssetServer`](crate::AssetServer), if it was loaded from a file.
+    pub(crate) load_stats: Option<AssetLoadStats>,
+    /// The base asset this asset was loaded alongside, if this is a labeled asset that has been loaded.
+    pub(crate) base_asset: Option<UntypedAssetId>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
     /// This is set using the value from [`LoadedAsset`].
     /// This will only be populated if [`AssetInfos::watching_for_changes`] is set to `true` to
@@ -55,6 +64,10 @@ impl AssetInfo {
             loader_dependencies: HashMap::default(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
+            dependencies: HashSet::default(),
+            asset_type_name: None,
+            load_stats: None,
+            base_asset: None,
             handle_drops_to_skip: 0,
             waiting_tasks: Vec::new(),
         }
@@ -393,8 +406,10 @@ impl AssetInfos {
             return;
         }
 
+        let asset_type_name = loaded_asset.value.asset_type_name();
         loaded_asset.value.insert(loaded_asset_id, world);
         let mut loading_deps = loaded_asset.dependencies;
+        let dependencies = loading_deps.clone();
         let mut failed_deps = HashSet::new();
         let mut dep_error = None;
         let mut loading_rec_deps = loading_deps.clone();
@@ -490,6 +505,8 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
+            info.asset_type_name = Some(asset_type_name);
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -546,6 +563,36 @@ impl AssetInfos {
         }
     }
 
+    /// Records `base_asset` as the base asset of the labeled assets of `loaded_asset`,
+    /// including labeled assets nested in other labeled assets.
+    pub(crate) fn set_base_asset(
+        &mut self,
+        base_asset: UntypedAssetId,
+        loaded_asset: &ErasedLoadedAsset,
+    ) {
+        for labeled_asset in loaded_asset.labeled_assets.values() {
+            if let Some(info) = self.get_mut(labeled_asset.handle.id()) {
+                info.base_asset = Some(base_asset);
+            }
+            self.set_base_asset(base_asset, &labeled_asset.asset);
+        }
+    }
+
+    /// Returns a snapshot of all tracked assets and the dependencies between them.
+    pub(crate) fn dependency_graph(&self) -> AssetDependencyGraph {
+        AssetDependencyGraph::new(self.infos.iter().map(|(id, info)| AssetDependencyNode {
+            id: *id,
+            path: info.path.clone(),
+            asset_type_name: info.asset_type_name,
+            load_state: info.load_state.clone(),
+            load_stats: info.load_stats.clone(),
+            dependencies: info.dependencies.iter().copied().collect(),
+            dependents: Vec::new(),
+            labeled_assets: Vec::new(),
+            base_asset: info.base_asset,
+        }))
+    }
+
     /// Recursively propagates loaded state up the dependency tree.
     fn propagate_loaded_state(
         infos: &mut AssetInfos,
@@ -1,6 +1,9 @@
+mod graph;
 mod info;
 mod loaders;
 
+pub use graph::{AssetDependencyGraph, AssetDependencyNode, AssetLoadStats};
+
 use crate::{
     folder::LoadedFolder,
     io::{
@@ -23,13 +26,14 @@ use bevy_ecs::prelude::*;
 use bevy_tasks::IoTaskPool;
 use bevy_utils::{
     tracing::{error, info},
-    HashSet,
+    HashSet, Instant,
 };
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use derive_more::derive::{Display, Error, From};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
+use graph::CountingReader;
 use info::*;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
@@ -646,10 +650,20 @@ impl AssetServer {
             (handle.clone().unwrap(), path.clone())
         };
 
-        match self
-            .load_with_meta_loader_and_reader(&base_path, meta, &*loader, &mut *reader, true, false)
-            .await
-        {
+        let start_time = Instant::now();
+        let mut counting_reader = CountingReader::new(&mut *reader);
+        let result = self
+            .load_with_meta_loader_and_reader(
+                &base_path,
+                meta,
+                &*loader,
+                &mut counting_reader,
+                true,
+                false,
+            )
+            .await;
+        let bytes_read = counting_reader.bytes_read;
+        match result {
             Ok(loaded_asset) => {
                 let final_handle = if let Some(label) = path.label_cow() {
                     match loaded_asset.labeled_assets.get(&label) {
@@ -673,6 +687,21 @@ impl AssetServer {
                     handle.unwrap()
                 };
 
+                let mut infos = self.data.infos.write();
+                infos.set_base_asset(base_handle.id(), &loaded_asset);
+                if let Some(info) = infos.get_mut(base_handle.id()) {
+                    info.load_stats = Some(AssetLoadStats {
+                        loader_name: loader.type_name(),
+                        load_duration: start_time.elapsed(),
+                        bytes_read,
+                        loader_dependencies: loaded_asset
+                            .loader_dependencies
+                            .keys()
+                            .cloned()
+                            .collect(),
+                    });
+                }
+                drop(infos);
                 self.send_loaded_asset(base_handle.id(), loaded_asset);
                 Ok(final_handle)
             }
@@ -1153,6 +1182,19 @@ impl AssetServer {
         Some(info.path.as_ref()?.clone())
     }
 
+    /// Returns how the asset with the given `id` was last loaded, if it was loaded from a file by this server.
+    pub fn get_load_stats(&self, id: impl Into<UntypedAssetId>) -> Option<AssetLoadStats> {
+        let infos = self.data.infos.read();
+        infos.get(id.into())?.load_stats.clone()
+    }
+
+    /// Returns a snapshot of all assets tracked by this server and the dependencies between them.
+    ///
+    /// See [`AssetDependencyGraph`] for more information.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.data.infos.read().dependency_graph()
+    }
+
     /// Returns the [`AssetServerMode`] this server is currently in.
     pub fn mode(&self) -> AssetServerMode {
         self.data.mode
@@ -30,7 +30,10 @@ pub mod prelude {
 }
 
 use bevy_app::prelude::*;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    world::{apply_async_world_queue, AsyncWorldQueue},
+};
 use core::marker::PhantomData;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -44,12 +47,16 @@ impl Plugin for TypeRegistrationPlugin {
     #[cfg_attr(not(feature = "bevy_reflect"), allow(unused_variables))]
     fn build(&self, app: &mut App) {
         #[cfg(feature = "bevy_reflect")]
-        app.register_type::<Name>();
+        app.register_type::<Name>()
+            .register_type::<bevy_ecs::entity_disabling::Disabled>();
     }
 }
 
 /// Setup of default task pools: [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool),
 /// [`ComputeTaskPool`](bevy_tasks::ComputeTaskPool), [`IoTaskPool`](bevy_tasks::IoTaskPool).
+///
+/// This also applies the [`AsyncWorldQueue`] in [`PreUpdate`], which is the sync point of
+/// async tasks spawned with [`World::spawn_task`].
 #[derive(Default)]
 pub struct TaskPoolPlugin {
     /// Options for the [`TaskPool`](bevy_tasks::TaskPool) created at application start.
@@ -57,12 +64,15 @@ pub struct TaskPoolPlugin {
 }
 
 impl Plugin for TaskPoolPlugin {
-    fn build(&self, _app: &mut App) {
+    fn build(&self, app: &mut App) {
         // Setup the default bevy task pools
         self.task_pool_options.create_default_pools();
 
+        app.init_resource::<AsyncWorldQueue>()
+            .add_systems(PreUpdate, apply_async_world_queue);
+
         #[cfg(not(target_arch = "wasm32"))]
-        _app.add_systems(Last, tick_global_task_pools);
+        app.add_systems(Last, tick_global_task_pools);
     }
 }
 /// A dummy type that is [`!Send`](Send), to force systems to run on the main thread.
@@ -159,4 +169,28 @@ mod tests {
         let frame_count = app.world().resource::<FrameCount>();
         assert_eq!(1, frame_count.0);
     }
+
+    #[test]
+    fn applies_async_world_queue() {
+        #[derive(Resource, Default)]
+        struct Loaded(bool);
+
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default())
+            .init_resource::<Loaded>();
+        app.world_mut().spawn_task(|world| async move {
+            world
+                .run(|world| world.resource_mut::<Loaded>().0 = true)
+                .await;
+        });
+
+        for _ in 0..10_000 {
+            app.update();
+            if app.world().resource::<Loaded>().0 {
+                return;
+            }
+            std::thread::yield_now();
+        }
+        panic!("the async task never ran");
+    }
 }
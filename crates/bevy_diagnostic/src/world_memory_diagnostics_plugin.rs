@@ -0,0 +1,239 @@
+use alloc::borrow::Cow;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time, Timer, TimerMode};
+use bevy_utils::Duration;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, RegisterDiagnostic};
+
+/// Adds diagnostics about the memory used to store entities and components in the main world.
+///
+/// The measurements are taken from [`World::memory_usage`]. Besides the totals, diagnostics can
+/// be enabled for every archetype and every component, which are registered under
+/// `world_memory/archetype/<index>/...` and `world_memory/component/<name>/bytes` as they appear.
+///
+/// Tables and sparse sets are measured exactly, but archetypes share them with each other, so the
+/// `used_bytes` of each archetype is an estimate computed from its entity count and row size.
+/// See [`ArchetypeMemoryUsage::used_bytes`](bevy_ecs::storage::ArchetypeMemoryUsage::used_bytes).
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct WorldMemoryDiagnosticsPlugin {
+    /// How often the world is measured. Measuring walks every table and archetype.
+    pub wait_duration: Duration,
+    /// Whether to report the entity count, used bytes and table fragmentation of each archetype.
+    pub per_archetype: bool,
+    /// Whether to report the allocated bytes of each component.
+    pub per_component: bool,
+}
+
+impl Default for WorldMemoryDiagnosticsPlugin {
+    fn default() -> Self {
+        WorldMemoryDiagnosticsPlugin {
+            wait_duration: Duration::from_secs(1),
+            per_archetype: false,
+            per_component: false,
+        }
+    }
+}
+
+/// State used by the [`WorldMemoryDiagnosticsPlugin`]
+#[derive(Resource)]
+struct WorldMemoryDiagnosticsState {
+    timer: Timer,
+    per_archetype: bool,
+    per_component: bool,
+}
+
+impl Plugin for WorldMemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ALLOCATED_BYTES).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::USED_BYTES).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_BYTES).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::SPARSE_SET_BYTES).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::FRAGMENTATION).with_suffix("%"))
+            .register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_COUNT))
+            .insert_resource(WorldMemoryDiagnosticsState {
+                timer: Timer::new(self.wait_duration, TimerMode::Repeating),
+                per_archetype: self.per_archetype,
+                per_component: self.per_component,
+            })
+            .add_systems(Update, Self::tick_timer)
+            .add_systems(Update, Self::diagnostic_system.after(Self::tick_timer));
+    }
+}
+
+impl WorldMemoryDiagnosticsPlugin {
+    pub const ALLOCATED_BYTES: DiagnosticPath =
+        DiagnosticPath::const_new("world_memory/allocated_bytes");
+    pub const USED_BYTES: DiagnosticPath = DiagnosticPath::const_new("world_memory/used_bytes");
+    pub const TABLE_BYTES: DiagnosticPath = DiagnosticPath::const_new("world_memory/table_bytes");
+    pub const SPARSE_SET_BYTES: DiagnosticPath =
+        DiagnosticPath::const_new("world_memory/sparse_set_bytes");
+    pub const FRAGMENTATION: DiagnosticPath =
+        DiagnosticPath::const_new("world_memory/fragmentation");
+    pub const ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("world_memory/archetype_count");
+    pub const TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("world_memory/table_count");
+
+    fn tick_timer(mut state: ResMut<WorldMemoryDiagnosticsState>, time: Option<Res<Time<Real>>>) {
+        if let Some(time) = time {
+            state.timer.tick(time.delta());
+        }
+    }
+
+    fn diagnostic_system(
+        world: &World,
+        state: Res<WorldMemoryDiagnosticsState>,
+        store: Res<DiagnosticsStore>,
+        mut diagnostics: Diagnostics,
+        mut commands: Commands,
+    ) {
+        // Without a clock, measure every frame.
+        if world.contains_resource::<Time<Real>>() && !state.timer.just_finished() {
+            return;
+        }
+
+        let usage = world.memory_usage();
+        diagnostics.add_measurement(&Self::ALLOCATED_BYTES, || usage.allocated_bytes() as f64);
+        diagnostics.add_measurement(&Self::USED_BYTES, || usage.used_bytes() as f64);
+        diagnostics.add_measurement(&Self::TABLE_BYTES, || usage.table_bytes() as f64);
+        diagnostics.add_measurement(&Self::SPARSE_SET_BYTES, || usage.sparse_set_bytes() as f64);
+        diagnostics.add_measurement(&Self::FRAGMENTATION, || usage.fragmentation() * 100.0);
+        diagnostics.add_measurement(&Self::ARCHETYPE_COUNT, || usage.archetypes.len() as f64);
+        diagnostics.add_measurement(&Self::TABLE_COUNT, || usage.tables.len() as f64);
+
+        // Diagnostics for archetypes and components are registered the first time they are seen,
+        // and start receiving measurements the next time the world is measured.
+        let mut measure = |path: DiagnosticPath, suffix: &'static str, value: f64| {
+            if store.get(&path).is_some() {
+                diagnostics.add_measurement(&path, || value);
+            } else {
+                commands.queue(move |world: &mut World| {
+                    world
+                        .resource_mut::<DiagnosticsStore>()
+                        .add(Diagnostic::new(path).with_suffix(suffix));
+                });
+            }
+        };
+
+        if state.per_archetype {
+            for (index, archetype) in usage.archetypes.iter().enumerate() {
+                let path = |name: &str| {
+                    DiagnosticPath::new(format!("world_memory/archetype/{index}/{name}"))
+                };
+                measure(path("entities"), "", archetype.entity_count as f64);
+                measure(path("used_bytes"), "B", archetype.used_bytes as f64);
+                measure(
+                    path("table_fragmentation"),
+                    "%",
+                    archetype.table_fragmentation * 100.0,
+                );
+            }
+        }
+
+        if state.per_component {
+            for (component_id, bytes) in usage.component_bytes() {
+                let name = world
+                    .components()
+                    .get_name(component_id)
+                    .map_or(Cow::Owned(component_id.index().to_string()), Cow::Borrowed);
+                measure(
+                    DiagnosticPath::new(format!("world_memory/component/{name}/bytes")),
+                    "B",
+                    bytes as f64,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Dense(#[allow(dead_code)] [u64; 4]);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Sparse(#[allow(dead_code)] u32);
+
+    #[derive(Resource)]
+    struct Counter(#[allow(dead_code)] usize);
+
+    fn value(app: &App, path: &DiagnosticPath) -> f64 {
+        app.world()
+            .resource::<DiagnosticsStore>()
+            .get(path)
+            .unwrap_or_else(|| panic!("{path} is not registered"))
+            .value()
+            .unwrap_or_else(|| panic!("{path} has no measurement"))
+    }
+
+    #[test]
+    fn measures_world_memory() {
+        let mut app = App::new();
+        app.add_plugins(WorldMemoryDiagnosticsPlugin {
+            per_archetype: true,
+            per_component: true,
+            ..Default::default()
+        })
+        .insert_resource(Counter(0));
+        app.world_mut()
+            .spawn_batch((0..10).map(|_| (Dense([0; 4]), Sparse(0))));
+
+        // Archetype and component diagnostics are registered by the first update.
+        app.update();
+        app.update();
+
+        let paths = [
+            WorldMemoryDiagnosticsPlugin::ALLOCATED_BYTES,
+            WorldMemoryDiagnosticsPlugin::USED_BYTES,
+            WorldMemoryDiagnosticsPlugin::TABLE_BYTES,
+            WorldMemoryDiagnosticsPlugin::SPARSE_SET_BYTES,
+            WorldMemoryDiagnosticsPlugin::ARCHETYPE_COUNT,
+            WorldMemoryDiagnosticsPlugin::TABLE_COUNT,
+        ];
+        let before: Vec<f64> = paths.iter().map(|path| value(&app, path)).collect();
+        assert!(before.iter().all(|&value| value > 0.0));
+        assert!(
+            value(&app, &WorldMemoryDiagnosticsPlugin::USED_BYTES)
+                <= value(&app, &WorldMemoryDiagnosticsPlugin::ALLOCATED_BYTES)
+        );
+
+        let dense_name = app
+            .world()
+            .components()
+            .get_name(app.world().component_id::<Dense>().unwrap())
+            .unwrap()
+            .to_string();
+        let dense_bytes = DiagnosticPath::new(format!("world_memory/component/{dense_name}/bytes"));
+        assert!(value(&app, &dense_bytes) >= (10 * size_of::<Dense>()) as f64);
+        let entities = app
+            .world()
+            .resource::<DiagnosticsStore>()
+            .iter()
+            .filter(|diagnostic| diagnostic.path().as_str().ends_with("/entities"))
+            .filter_map(Diagnostic::value)
+            .sum::<f64>();
+        assert_eq!(entities, 10.0);
+
+        app.world_mut()
+            .spawn_batch((0..100).map(|_| (Dense([0; 4]), Sparse(0))));
+        app.update();
+
+        let after: Vec<f64> = paths.iter().map(|path| value(&app, path)).collect();
+        assert!(before
+            .iter()
+            .zip(&after)
+            .all(|(before, after)| after >= before));
+        assert!(
+            value(&app, &WorldMemoryDiagnosticsPlugin::USED_BYTES) > before[1],
+            "used bytes should grow with the entity count"
+        );
+    }
+}
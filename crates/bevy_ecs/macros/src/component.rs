@@ -9,7 +9,8 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Paren},
-    DeriveInput, ExprClosure, ExprPath, Ident, LitStr, Path, Result,
+    Data, DataStruct, DeriveInput, ExprClosure, ExprPath, Fields, Ident, LitStr, Member, Path,
+    Result, Type,
 };
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
@@ -63,13 +64,78 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
-    let on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
-    let on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
+    let mut on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
+    let mut on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
     let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
 
+    if relationship.is_some() {
+        if on_insert.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_insert hooks are not supported as relationships already define an on_insert hook",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        on_insert = Some(
+            quote!(hooks.on_insert(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert);),
+        );
+
+        if on_replace.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_replace hooks are not supported as relationships already define an on_replace hook",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        on_replace = Some(
+            quote!(hooks.on_replace(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace);),
+        );
+    }
+
+    if relationship_target.is_some() {
+        if on_replace.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_replace hooks are not supported as RelationshipTarget already defines an on_replace hook",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        on_replace = Some(
+            quote!(hooks.on_replace(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace);),
+        );
+    }
+
+    // The target side is maintained entirely by hooks, so cloning it would desync the relationship.
+    let clone_handler = if relationship_target.is_some() {
+        quote! {
+            #bevy_ecs_path::component::ComponentCloneHandler::Ignore
+        }
+    } else {
+        quote! {
+            use #bevy_ecs_path::component::{ComponentCloneViaClone, ComponentCloneBase};
+            (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
+                .get_component_clone_handler()
+        }
+    };
+
     ast.generics
         .make_where_clause()
         .predicates
@@ -163,13 +229,23 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 #on_replace
                 #on_remove
             }
+
+            fn get_component_clone_handler() -> #bevy_ecs_path::component::ComponentCloneHandler {
+                #clone_handler
+            }
         }
+
+        #relationship
+
+        #relationship_target
     })
 }
 
 pub const COMPONENT: &str = "component";
 pub const STORAGE: &str = "storage";
 pub const REQUIRE: &str = "require";
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
 
 pub const ON_ADD: &str = "on_add";
 pub const ON_INSERT: &str = "on_insert";
@@ -183,6 +259,16 @@ struct Attrs {
     on_insert: Option<ExprPath>,
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
+    relationship: Option<Relationship>,
+    relationship_target: Option<RelationshipTarget>,
+}
+
+struct Relationship {
+    relationship_target: Type,
+}
+
+struct RelationshipTarget {
+    relationship: Type,
 }
 
 #[derive(Clone, Copy)]
@@ -213,6 +299,8 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         on_replace: None,
         on_remove: None,
         requires: None,
+        relationship: None,
+        relationship_target: None,
     };
 
     let mut require_paths = HashSet::new();
@@ -262,9 +350,52 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
             } else {
                 attrs.requires = Some(punctuated);
             }
+        } else if attr.path().is_ident(RELATIONSHIP) {
+            let mut relationship_target = None;
+            attr.parse_nested_meta(|nested| {
+                if nested.path.is_ident(RELATIONSHIP_TARGET) {
+                    relationship_target = Some(nested.value()?.parse::<Type>()?);
+                    Ok(())
+                } else {
+                    Err(nested.error("Unsupported attribute"))
+                }
+            })?;
+            let Some(relationship_target) = relationship_target else {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "Missing `relationship_target = ...` in `relationship` attribute",
+                ));
+            };
+            attrs.relationship = Some(Relationship {
+                relationship_target,
+            });
+        } else if attr.path().is_ident(RELATIONSHIP_TARGET) {
+            let mut relationship = None;
+            attr.parse_nested_meta(|nested| {
+                if nested.path.is_ident(RELATIONSHIP) {
+                    relationship = Some(nested.value()?.parse::<Type>()?);
+                    Ok(())
+                } else {
+                    Err(nested.error("Unsupported attribute"))
+                }
+            })?;
+            let Some(relationship) = relationship else {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "Missing `relationship = ...` in `relationship_target` attribute",
+                ));
+            };
+            attrs.relationship_target = Some(RelationshipTarget { relationship });
         }
     }
 
+    if attrs.relationship.is_some() && attrs.relationship_target.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "A component cannot be both a relationship and a relationship target",
+        ));
+    }
+
     Ok(attrs)
 }
 
@@ -302,3 +433,98 @@ fn hook_register_function_call(
 ) -> Option<TokenStream2> {
     function.map(|meta| quote! { hooks. #hook (#meta); })
 }
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship) = &attrs.relationship else {
+        return Ok(None);
+    };
+    let (_, field_member) = relationship_field(ast, RELATIONSHIP)?;
+    let relationship_target = &relationship.relationship_target;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#field_member
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self {
+                    #field_member: entity
+                }
+            }
+        }
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = &attrs.relationship_target else {
+        return Ok(None);
+    };
+    let (collection, field_member) = relationship_field(ast, RELATIONSHIP_TARGET)?;
+    let relationship = &relationship_target.relationship;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+            type Collection = #collection;
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#field_member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                &mut self.#field_member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Self::Collection) -> Self {
+                Self {
+                    #field_member: collection
+                }
+            }
+        }
+    }))
+}
+
+/// Returns the type and member of the single field of a relationship struct.
+fn relationship_field<'a>(ast: &'a DeriveInput, attr_name: &str) -> Result<(&'a Type, Member)> {
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("`{attr_name}` can only be derived for structs"),
+        ));
+    };
+
+    let field = match fields {
+        Fields::Named(fields) if fields.named.len() == 1 => fields.named.first(),
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+        _ => None,
+    };
+    let Some(field) = field else {
+        return Err(syn::Error::new(
+            fields.span(),
+            format!("`{attr_name}` can only be derived for structs with a single field"),
+        ));
+    };
+
+    let member = field.ident.clone().map_or(Member::from(0), Member::Named);
+    Ok((&field.ty, member))
+}
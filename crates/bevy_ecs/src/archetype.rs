@@ -459,6 +459,13 @@ impl Archetype {
         &self.entities
     }
 
+    /// Gets the number of entities the archetype can store without reallocating its list of
+    /// entities.
+    #[inline]
+    pub fn entity_capacity(&self) -> usize {
+        self.entities.capacity()
+    }
+
     /// Gets an iterator of all of the components stored in [`Table`]s.
     ///
     /// All of the IDs are unique.
@@ -5,7 +5,7 @@ use crate::{
     archetype::ArchetypeFlags,
     bundle::BundleInfo,
     change_detection::MAX_CHANGE_AGE,
-    entity::Entity,
+    entity::{Entity, EntityCloner},
     query::DebugCheckedUnwrap,
     storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
     system::{Local, Resource, SystemParam},
@@ -390,6 +390,15 @@ pub trait Component: Send + Sync + 'static {
         _inheritance_depth: u16,
     ) {
     }
+
+    /// Called when registering this component, allowing to override the function used to clone
+    /// this component (or to disable cloning altogether) when its entity is cloned.
+    ///
+    /// See the [handlers section of `EntityCloneBuilder`](crate::entity::EntityCloneBuilder#handlers)
+    /// to learn how this interacts with other ways of configuring component cloning.
+    fn get_component_clone_handler() -> ComponentCloneHandler {
+        ComponentCloneHandler::default_handler()
+    }
 }
 
 /// The storage used for a specific component type.
@@ -881,6 +890,7 @@ pub struct Components {
     components: Vec<ComponentInfo>,
     indices: TypeIdMap<ComponentId>,
     resource_indices: TypeIdMap<ComponentId>,
+    component_clone_handlers: ComponentCloneHandlers,
 }
 
 impl Components {
@@ -918,6 +928,8 @@ impl Components {
             let info = &mut self.components[id.index()];
             T::register_component_hooks(&mut info.hooks);
             info.required_components = required_components;
+            self.component_clone_handlers
+                .set_component_handler(id, T::get_component_clone_handler());
         }
         id
     }
@@ -995,6 +1007,19 @@ impl Components {
         unsafe { self.components.get_unchecked(id.0) }
     }
 
+    /// Returns the [`ComponentCloneHandlers`] used to clone components of this world's entities.
+    #[inline]
+    pub fn get_component_clone_handlers(&self) -> &ComponentCloneHandlers {
+        &self.component_clone_handlers
+    }
+
+    /// Returns a mutable reference to the [`ComponentCloneHandlers`] used to clone components of
+    /// this world's entities.
+    #[inline]
+    pub fn get_component_clone_handlers_mut(&mut self) -> &mut ComponentCloneHandlers {
+        &mut self.component_clone_handlers
+    }
+
     #[inline]
     pub(crate) fn get_hooks_mut(&mut self, id: ComponentId) -> Option<&mut ComponentHooks> {
         self.components.get_mut(id.0).map(|info| &mut info.hooks)
@@ -1834,3 +1859,198 @@ impl RequiredComponents {
         }
     }
 }
+
+/// Function type that can be used to clone an entity's component.
+///
+/// The function receives the [`EntityCloner`] performing the clone, which exposes the source and
+/// target entities as well as the [`ComponentId`] of the component being cloned.
+pub type ComponentCloneFn = fn(&mut DeferredWorld, &EntityCloner);
+
+/// Describes how a [`Component`] should be cloned when its entity is cloned.
+///
+/// See [`Component::get_component_clone_handler`] and [`EntityCloneBuilder`](crate::entity::EntityCloneBuilder).
+#[derive(Clone, Copy, Debug, Default)]
+pub enum ComponentCloneHandler {
+    /// Use the world's default [`ComponentCloneFn`], see [`ComponentCloneHandlers::get_default_handler`].
+    #[default]
+    Default,
+    /// Do not clone this component.
+    Ignore,
+    /// Clone this component using the given [`ComponentCloneFn`].
+    Custom(ComponentCloneFn),
+}
+
+impl ComponentCloneHandler {
+    /// Returns the handler that defers to the world's default [`ComponentCloneFn`].
+    pub fn default_handler() -> Self {
+        Self::Default
+    }
+
+    /// Returns the handler that clones the component using its [`Clone`] implementation.
+    pub fn clone_handler<C: Component + Clone>() -> Self {
+        Self::Custom(component_clone_via_clone::<C>)
+    }
+
+    /// Returns the handler that clones the component using its [`ReflectComponent`](crate::reflect::ReflectComponent)
+    /// registration in the [`AppTypeRegistry`](crate::reflect::AppTypeRegistry).
+    #[cfg(feature = "bevy_reflect")]
+    pub fn reflect_handler() -> Self {
+        Self::Custom(component_clone_via_reflect)
+    }
+}
+
+/// Stores the [`ComponentCloneFn`] used for each component of a [`World`], as well as the fallback
+/// used for components that do not specify one.
+#[derive(Debug)]
+pub struct ComponentCloneHandlers {
+    handlers: Vec<Option<ComponentCloneFn>>,
+    default_handler: ComponentCloneFn,
+}
+
+impl ComponentCloneHandlers {
+    /// Sets the default handler, used for every component that does not have a custom handler.
+    ///
+    /// By default, this is [`component_clone_via_reflect`] when the `bevy_reflect` feature is
+    /// enabled, and [`component_clone_ignore`] otherwise.
+    pub fn set_default_handler(&mut self, handler: ComponentCloneFn) {
+        self.default_handler = handler;
+    }
+
+    /// Returns the current default handler.
+    pub fn get_default_handler(&self) -> ComponentCloneFn {
+        self.default_handler
+    }
+
+    /// Sets the handler used to clone the component with the given `id`.
+    pub fn set_component_handler(&mut self, id: ComponentId, handler: ComponentCloneHandler) {
+        if id.0 >= self.handlers.len() {
+            self.handlers.resize(id.0 + 1, None);
+        }
+        self.handlers[id.0] = match handler {
+            ComponentCloneHandler::Default => None,
+            ComponentCloneHandler::Ignore => Some(component_clone_ignore),
+            ComponentCloneHandler::Custom(handler) => Some(handler),
+        };
+    }
+
+    /// Returns `true` if a handler other than the default one is registered for the component with the given `id`.
+    pub fn is_handler_registered(&self, id: ComponentId) -> bool {
+        self.handlers.get(id.0).is_some_and(Option::is_some)
+    }
+
+    /// Returns the handler that will be used to clone the component with the given `id`.
+    pub fn get_handler(&self, id: ComponentId) -> ComponentCloneFn {
+        self.handlers
+            .get(id.0)
+            .copied()
+            .flatten()
+            .unwrap_or(self.default_handler)
+    }
+}
+
+impl Default for ComponentCloneHandlers {
+    fn default() -> Self {
+        Self {
+            handlers: Vec::new(),
+            #[cfg(feature = "bevy_reflect")]
+            default_handler: component_clone_via_reflect,
+            #[cfg(not(feature = "bevy_reflect"))]
+            default_handler: component_clone_ignore,
+        }
+    }
+}
+
+/// A [`ComponentCloneFn`] that clones the component using its [`Clone`] implementation.
+///
+/// The clone is inserted on the target entity through [`Commands`](crate::system::Commands).
+pub fn component_clone_via_clone<C: Clone + Component>(
+    world: &mut DeferredWorld,
+    entity_cloner: &EntityCloner,
+) {
+    let Some(component) = world.get::<C>(entity_cloner.source()).cloned() else {
+        return;
+    };
+    world
+        .commands()
+        .entity(entity_cloner.target())
+        .insert(component);
+}
+
+/// A [`ComponentCloneFn`] that clones the component using reflection.
+///
+/// The component type must be registered in the [`AppTypeRegistry`](crate::reflect::AppTypeRegistry)
+/// with [`ReflectComponent`](crate::reflect::ReflectComponent) type data.
+/// Components that do not meet these requirements are silently skipped.
+#[cfg(feature = "bevy_reflect")]
+pub fn component_clone_via_reflect(world: &mut DeferredWorld, entity_cloner: &EntityCloner) {
+    let component_id = entity_cloner.component_id();
+    let source = entity_cloner.source();
+    let target = entity_cloner.target();
+    world.commands().queue(move |world: &mut World| {
+        let Some(type_id) = world
+            .components()
+            .get_info(component_id)
+            .and_then(ComponentInfo::type_id)
+        else {
+            return;
+        };
+        let Some(registry) = world.get_resource::<crate::reflect::AppTypeRegistry>() else {
+            return;
+        };
+        let registry = registry.clone();
+        let registry = registry.read();
+        let Some(reflect_component) =
+            registry.get_type_data::<crate::reflect::ReflectComponent>(type_id)
+        else {
+            return;
+        };
+        let Some(component) = world
+            .get_entity(source)
+            .ok()
+            .and_then(|source| reflect_component.reflect(source))
+            .map(bevy_reflect::PartialReflect::clone_value)
+        else {
+            return;
+        };
+        if let Ok(mut target) = world.get_entity_mut(target) {
+            reflect_component.insert(&mut target, &*component, &registry);
+        }
+    });
+}
+
+/// A [`ComponentCloneFn`] that does nothing, leaving the component uncloned.
+pub fn component_clone_ignore(_world: &mut DeferredWorld, _entity_cloner: &EntityCloner) {}
+
+/// Wrapper for components clone specialization using autoderef.
+#[doc(hidden)]
+pub struct ComponentCloneSpecializationWrapper<T>(PhantomData<T>);
+
+impl<T> Default for ComponentCloneSpecializationWrapper<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Base trait for components clone specialization using autoderef.
+#[doc(hidden)]
+pub trait ComponentCloneBase {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler;
+}
+
+impl<C: Component> ComponentCloneBase for ComponentCloneSpecializationWrapper<C> {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler {
+        ComponentCloneHandler::default_handler()
+    }
+}
+
+/// Specialized trait for components clone specialization using autoderef.
+#[doc(hidden)]
+pub trait ComponentCloneViaClone {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler;
+}
+
+impl<C: Clone + Component> ComponentCloneViaClone for &ComponentCloneSpecializationWrapper<C> {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler {
+        ComponentCloneHandler::clone_handler::<C>()
+    }
+}
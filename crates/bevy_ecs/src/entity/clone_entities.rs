@@ -0,0 +1,560 @@
+use alloc::sync::Arc;
+use bevy_utils::{HashMap, HashSet};
+use core::any::TypeId;
+use std::sync::Mutex;
+
+use crate::{
+    bundle::Bundle,
+    component::{component_clone_ignore, Component, ComponentCloneHandler, ComponentId},
+    entity::{Entity, EntityHashMap},
+    world::World,
+};
+
+/// A helper struct to clone an entity. Used internally by [`EntityCloneBuilder::clone_entity`] and by
+/// [`ComponentCloneFn`](crate::component::ComponentCloneFn)s to learn which component is being cloned.
+pub struct EntityCloner {
+    source: Entity,
+    target: Entity,
+    component_id: Option<ComponentId>,
+    filter_allows_components: bool,
+    filter: Arc<HashSet<ComponentId>>,
+    clone_handlers_overrides: Arc<HashMap<ComponentId, ComponentCloneHandler>>,
+    entity_map: Arc<Mutex<EntityHashMap<Entity>>>,
+}
+
+impl EntityCloner {
+    /// Clones and inserts components from the `source` entity into the `target` entity using the stored configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `source` entity does not exist.
+    pub fn clone_entity(&mut self, world: &mut World) {
+        let source_entity = world
+            .get_entity(self.source)
+            .expect("Source entity must exist");
+        let archetype = source_entity.archetype();
+
+        let mut components = Vec::with_capacity(archetype.component_count());
+        components.extend(
+            archetype
+                .components()
+                .filter(|id| self.is_cloning_allowed(id)),
+        );
+
+        self.entity_map
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(self.source, self.target);
+
+        for component in components {
+            let global_handlers = world.components().get_component_clone_handlers();
+            let handler = match self.clone_handlers_overrides.get(&component) {
+                None => global_handlers.get_handler(component),
+                Some(ComponentCloneHandler::Default) => global_handlers.get_default_handler(),
+                Some(ComponentCloneHandler::Ignore) => component_clone_ignore,
+                Some(ComponentCloneHandler::Custom(handler)) => *handler,
+            };
+            self.component_id = Some(component);
+            (handler)(&mut world.into(), self);
+        }
+
+        world.flush();
+    }
+
+    fn is_cloning_allowed(&self, component: &ComponentId) -> bool {
+        (self.filter_allows_components && self.filter.contains(component))
+            || (!self.filter_allows_components && !self.filter.contains(component))
+    }
+
+    /// Returns the current source entity.
+    pub fn source(&self) -> Entity {
+        self.source
+    }
+
+    /// Returns the current target entity.
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Returns the [`ComponentId`] of the component currently being cloned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a [`ComponentCloneFn`](crate::component::ComponentCloneFn).
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+            .expect("ComponentId must be set in clone_entity")
+    }
+
+    /// Returns the entity that `source` was cloned into as part of the current clone operation, if any.
+    ///
+    /// This includes entities cloned by [`EntityCloner`]s created with [`EntityCloner::with_source_and_target`].
+    pub fn mapped_entity(&self, source: Entity) -> Option<Entity> {
+        self.entity_map
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&source)
+            .copied()
+    }
+
+    /// Reuse existing [`EntityCloner`] configuration with new source and target.
+    ///
+    /// The returned cloner shares its entity map with `self`, so entities it clones will also be
+    /// remapped at the end of the original clone operation.
+    pub fn with_source_and_target(&self, source: Entity, target: Entity) -> EntityCloner {
+        EntityCloner {
+            source,
+            target,
+            component_id: None,
+            filter_allows_components: self.filter_allows_components,
+            filter: self.filter.clone(),
+            clone_handlers_overrides: self.clone_handlers_overrides.clone(),
+            entity_map: self.entity_map.clone(),
+        }
+    }
+}
+
+/// Builder struct to clone an entity. Allows configuring which components to clone, as well as how to clone them.
+/// After configuration is complete an entity can be cloned using [`Self::clone_entity`].
+///
+///```
+/// use bevy_ecs::prelude::*;
+/// use bevy_ecs::entity::EntityCloneBuilder;
+///
+/// #[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+/// struct A {
+///     field: usize,
+/// }
+///
+/// let mut world = World::default();
+///
+/// let component = A { field: 5 };
+///
+/// let entity = world.spawn(component.clone()).id();
+/// let entity_clone = world.spawn_empty().id();
+///
+/// EntityCloneBuilder::new(&mut world).clone_entity(entity, entity_clone);
+///
+/// assert!(world.get::<A>(entity_clone).is_some_and(|c| *c == component));
+///```
+///
+/// # Handlers
+///
+/// By default, components that implement [`Clone`] are cloned using it, while all other components
+/// are cloned using reflection (see [`component_clone_via_reflect`](crate::component::component_clone_via_reflect)).
+/// Components that can be cloned in neither way are skipped.
+///
+/// The handler used for a component is resolved in the following order, from highest to lowest priority:
+/// 1. Handlers overridden on this builder with [`Self::override_component_clone_handler`].
+/// 2. Handlers set on the world with [`ComponentCloneHandlers::set_component_handler`](crate::component::ComponentCloneHandlers::set_component_handler).
+/// 3. The handler returned by [`Component::get_component_clone_handler`].
+/// 4. The world's default handler, see [`ComponentCloneHandlers::set_default_handler`](crate::component::ComponentCloneHandlers::set_default_handler).
+///
+/// # Entity mapping
+///
+/// Once every component has been cloned, components with [`ReflectMapEntities`](crate::reflect::ReflectMapEntities)
+/// type data have their [`Entity`] references remapped: references to any entity cloned as part of
+/// this operation (including recursively cloned entities) point to the corresponding clone.
+/// Other references are left untouched.
+pub struct EntityCloneBuilder<'w> {
+    world: &'w mut World,
+    filter_allows_components: bool,
+    filter: HashSet<ComponentId>,
+    clone_handlers_overrides: HashMap<ComponentId, ComponentCloneHandler>,
+}
+
+impl<'w> EntityCloneBuilder<'w> {
+    /// Creates a new [`EntityCloneBuilder`] for world.
+    pub fn new(world: &'w mut World) -> Self {
+        Self {
+            world,
+            filter_allows_components: false,
+            filter: Default::default(),
+            clone_handlers_overrides: Default::default(),
+        }
+    }
+
+    /// Finishes configuring the builder and clones `source` entity to `target`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `source` entity does not exist.
+    pub fn clone_entity(self, source: Entity, target: Entity) {
+        let EntityCloneBuilder {
+            world,
+            filter_allows_components,
+            filter,
+            clone_handlers_overrides,
+        } = self;
+
+        let entity_map = Arc::new(Mutex::new(EntityHashMap::default()));
+        EntityCloner {
+            source,
+            target,
+            component_id: None,
+            filter_allows_components,
+            filter: Arc::new(filter),
+            clone_handlers_overrides: Arc::new(clone_handlers_overrides),
+            entity_map: entity_map.clone(),
+        }
+        .clone_entity(world);
+
+        #[cfg(feature = "bevy_reflect")]
+        {
+            let entity_map = entity_map
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            map_cloned_entities(world, &entity_map);
+        }
+    }
+
+    /// Adds all components of the bundle to the list of components to clone.
+    ///
+    /// Note that all components are allowed by default, to clone only explicitly allowed components make sure to call
+    /// [`deny_all`](`Self::deny_all`) before calling any of the `allow` methods.
+    pub fn allow<T: Bundle>(&mut self) -> &mut Self {
+        let ids = bundle_component_ids::<T>(self.world);
+        self.allow_by_ids(ids)
+    }
+
+    /// Extends the list of components to clone.
+    ///
+    /// Note that all components are allowed by default, to clone only explicitly allowed components make sure to call
+    /// [`deny_all`](`Self::deny_all`) before calling any of the `allow` methods.
+    pub fn allow_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.filter_allows_components {
+            self.filter.extend(ids);
+        } else {
+            ids.into_iter().for_each(|id| {
+                self.filter.remove(&id);
+            });
+        }
+        self
+    }
+
+    /// Extends the list of components to clone using [`TypeId`]s.
+    ///
+    /// Note that all components are allowed by default, to clone only explicitly allowed components make sure to call
+    /// [`deny_all`](`Self::deny_all`) before calling any of the `allow` methods.
+    pub fn allow_by_type_ids(&mut self, ids: impl IntoIterator<Item = TypeId>) -> &mut Self {
+        let ids = type_ids_to_component_ids(self.world, ids);
+        self.allow_by_ids(ids)
+    }
+
+    /// Resets the filter to allow all components to be cloned.
+    pub fn allow_all(&mut self) -> &mut Self {
+        self.filter_allows_components = false;
+        self.filter.clear();
+        self
+    }
+
+    /// Disallows all components of the bundle from being cloned.
+    pub fn deny<T: Bundle>(&mut self) -> &mut Self {
+        let ids = bundle_component_ids::<T>(self.world);
+        self.deny_by_ids(ids)
+    }
+
+    /// Extends the list of components that shouldn't be cloned.
+    pub fn deny_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.filter_allows_components {
+            ids.into_iter().for_each(|id| {
+                self.filter.remove(&id);
+            });
+        } else {
+            self.filter.extend(ids);
+        }
+        self
+    }
+
+    /// Extends the list of components that shouldn't be cloned using [`TypeId`]s.
+    pub fn deny_by_type_ids(&mut self, ids: impl IntoIterator<Item = TypeId>) -> &mut Self {
+        let ids = type_ids_to_component_ids(self.world, ids);
+        self.deny_by_ids(ids)
+    }
+
+    /// Sets the filter to deny all components.
+    pub fn deny_all(&mut self) -> &mut Self {
+        self.filter_allows_components = true;
+        self.filter.clear();
+        self
+    }
+
+    /// Overrides the [`ComponentCloneHandler`] for a component in this builder.
+    /// This handler will be used to clone the component instead of the global one defined by [`ComponentCloneHandlers`](crate::component::ComponentCloneHandlers)
+    ///
+    /// See [Handlers section of `EntityCloneBuilder`](EntityCloneBuilder#handlers) to understand how this affects handler priority.
+    pub fn override_component_clone_handler<T: Component>(
+        &mut self,
+        handler: ComponentCloneHandler,
+    ) -> &mut Self {
+        let id = self.world.register_component::<T>();
+        self.clone_handlers_overrides.insert(id, handler);
+        self
+    }
+
+    /// Removes a previously set override of [`ComponentCloneHandler`] for a component in this builder.
+    pub fn remove_component_clone_handler_override<T: Component>(&mut self) -> &mut Self {
+        if let Some(id) = self.world.components().component_id::<T>() {
+            self.clone_handlers_overrides.remove(&id);
+        }
+        self
+    }
+
+    /// Returns the [`World`] this builder clones entities in.
+    pub fn world(&self) -> &World {
+        self.world
+    }
+}
+
+fn bundle_component_ids<T: Bundle>(world: &World) -> Vec<ComponentId> {
+    let mut ids = Vec::new();
+    // Components that were never registered can't be on the source entity, so they can be skipped.
+    T::get_component_ids(world.components(), &mut |id| ids.extend(id));
+    ids
+}
+
+fn type_ids_to_component_ids(
+    world: &World,
+    ids: impl IntoIterator<Item = TypeId>,
+) -> Vec<ComponentId> {
+    ids.into_iter()
+        .filter_map(|type_id| world.components().get_id(type_id))
+        .collect()
+}
+
+/// Remaps the [`Entity`] references of all cloned components with [`ReflectMapEntities`](crate::reflect::ReflectMapEntities)
+/// type data, so that references to cloned entities point to their clones.
+#[cfg(feature = "bevy_reflect")]
+fn map_cloned_entities(world: &mut World, entity_map: &EntityHashMap<Entity>) {
+    use crate::reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities};
+
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let registry = registry.read();
+    let mut mapper = entity_map.clone();
+
+    for &target in entity_map.values() {
+        let Ok(mut target) = world.get_entity_mut(target) else {
+            continue;
+        };
+        let type_ids: Vec<_> = target
+            .archetype()
+            .components()
+            .filter_map(|id| target.world().components().get_info(id)?.type_id())
+            .collect();
+        for type_id in type_ids {
+            let (Some(reflect_component), Some(reflect_map_entities)) = (
+                registry.get_type_data::<ReflectComponent>(type_id),
+                registry.get_type_data::<ReflectMapEntities>(type_id),
+            ) else {
+                continue;
+            };
+            if let Some(mut component) = reflect_component.reflect_mut(&mut target) {
+                reflect_map_entities.map_entities(component.as_partial_reflect_mut(), &mut mapper);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::{Component, ComponentCloneHandler},
+        entity::{Entity, EntityCloneBuilder},
+        world::World,
+    };
+
+    #[test]
+    fn clone_entity_using_clone() {
+        #[derive(Component, Clone, PartialEq, Eq, Debug)]
+        struct A {
+            field: usize,
+        }
+
+        let mut world = World::default();
+
+        let component = A { field: 5 };
+
+        let e = world.spawn(component.clone()).id();
+        let e_clone = world.clone_entity(e);
+
+        assert!(world.get::<A>(e_clone).is_some_and(|c| *c == component));
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn clone_entity_using_reflect() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, PartialEq, Eq, Debug)]
+        #[reflect(Component)]
+        struct A {
+            field: usize,
+        }
+
+        let mut world = World::default();
+        world.init_resource::<AppTypeRegistry>();
+        let registry = world.get_resource::<AppTypeRegistry>().unwrap();
+        registry.write().register::<A>();
+
+        let component = A { field: 5 };
+
+        let e = world.spawn(component).id();
+        let e_clone = world.clone_entity(e);
+
+        assert!(world
+            .get::<A>(e_clone)
+            .is_some_and(|c| *c == A { field: 5 }));
+    }
+
+    #[test]
+    fn clone_entity_skips_uncloneable_components() {
+        #[derive(Component)]
+        struct NotClone;
+
+        #[derive(Component, Clone)]
+        struct A;
+
+        let mut world = World::default();
+
+        let e = world.spawn((A, NotClone)).id();
+        let e_clone = world.clone_entity(e);
+
+        assert!(world.get::<A>(e_clone).is_some());
+        assert!(world.get::<NotClone>(e_clone).is_none());
+    }
+
+    #[test]
+    fn clone_entity_with_allow_filter() {
+        #[derive(Component, Clone, PartialEq, Eq)]
+        struct A {
+            field: usize,
+        }
+
+        #[derive(Component, Clone)]
+        struct B;
+
+        let mut world = World::default();
+
+        let component = A { field: 5 };
+
+        let e = world.spawn((component.clone(), B)).id();
+        let e_clone = world.spawn_empty().id();
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder.deny_all();
+        builder.allow::<A>();
+        builder.clone_entity(e, e_clone);
+
+        assert!(world.get::<A>(e_clone).is_some_and(|c| *c == component));
+        assert!(world.get::<B>(e_clone).is_none());
+    }
+
+    #[test]
+    fn clone_entity_with_deny_filter() {
+        #[derive(Component, Clone, PartialEq, Eq)]
+        struct A {
+            field: usize,
+        }
+
+        #[derive(Component, Clone)]
+        struct B;
+
+        #[derive(Component, Clone)]
+        struct C;
+
+        let mut world = World::default();
+
+        let component = A { field: 5 };
+
+        let e = world.spawn((component.clone(), B, C)).id();
+        let e_clone = world.clone_entity_with(e, |builder| {
+            builder.deny::<B>();
+        });
+
+        assert!(world.get::<A>(e_clone).is_some_and(|c| *c == component));
+        assert!(world.get::<B>(e_clone).is_none());
+        assert!(world.get::<C>(e_clone).is_some());
+    }
+
+    #[test]
+    fn clone_entity_with_override_handler() {
+        #[derive(Component, Clone)]
+        struct A;
+
+        #[derive(Component, Clone)]
+        struct B;
+
+        let mut world = World::default();
+
+        let e = world.spawn((A, B)).id();
+        let e_clone = world.clone_entity_with(e, |builder| {
+            builder.override_component_clone_handler::<A>(ComponentCloneHandler::Ignore);
+        });
+
+        assert!(world.get::<A>(e_clone).is_none());
+        assert!(world.get::<B>(e_clone).is_some());
+
+        // Handlers set on the world apply to every clone.
+        let id = world.register_component::<B>();
+        world
+            .get_component_clone_handlers_mut()
+            .set_component_handler(id, ComponentCloneHandler::Ignore);
+        let e_clone = world.clone_entity(e);
+
+        assert!(world.get::<A>(e_clone).is_some());
+        assert!(world.get::<B>(e_clone).is_none());
+    }
+
+    #[test]
+    fn clone_entity_through_commands() {
+        #[derive(Component, Clone, PartialEq, Eq, Debug)]
+        struct A(usize);
+
+        let mut world = World::default();
+        let e = world.spawn(A(1)).id();
+
+        let mut commands = world.commands();
+        let e_clone = commands.entity(e).clone_entity().id();
+        world.flush();
+
+        assert_eq!(world.get::<A>(e_clone), Some(&A(1)));
+        assert_ne!(e, e_clone);
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn clone_entity_maps_entities() {
+        use crate::{
+            entity::{VisitEntities, VisitEntitiesMut},
+            reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+        };
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, Clone, VisitEntities, VisitEntitiesMut)]
+        #[reflect(Component, MapEntities)]
+        struct Link {
+            this: Entity,
+            other: Entity,
+        }
+
+        let mut world = World::default();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Link>();
+
+        let other = world.spawn_empty().id();
+        let e = world.spawn_empty().id();
+        world.entity_mut(e).insert(Link { this: e, other });
+
+        let e_clone = world.clone_entity(e);
+        let link = world.get::<Link>(e_clone).unwrap();
+
+        assert_eq!(link.this, e_clone);
+        assert_eq!(link.other, other);
+    }
+}
@@ -96,6 +96,13 @@ impl EntityMapper for &mut dyn EntityMapper {
     }
 }
 
+impl EntityMapper for EntityHashMap<Entity> {
+    /// Returns the corresponding mapped entity, or the original entity if it is absent.
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
 impl EntityMapper for SceneEntityMapper<'_> {
     /// Returns the corresponding mapped entity or reserves a new dead entity ID in the current world if it is absent.
     fn map_entity(&mut self, entity: Entity) -> Entity {
@@ -0,0 +1,187 @@
+//! Disabled entities do not show up in queries unless the query explicitly mentions them.
+//!
+//! If for example we have `Disabled` as an entity disabling component, when you add `Disabled`
+//! to an entity, the entity will only be visible to queries with a filter like
+//! [`With`]`<Disabled>` or query data like [`Has`]`<Disabled>`.
+//!
+//! ### Note
+//!
+//! Disabling an entity does not affect its relationship to other entities: a disabled parent
+//! keeps its children (which remain enabled), and disabling a child does not remove it from its
+//! parent's [`Children`](https://docs.rs/bevy/latest/bevy/hierarchy/struct.Children.html).
+//! Systems that walk a hierarchy through queries, such as transform propagation, will stop at
+//! disabled entities, so a disabled subtree keeps its last computed state until it is enabled again.
+//!
+//! Observers and hooks still run for disabled entities, since they are triggered by changes to
+//! the entity itself rather than by queries. Note that queries used *inside* an observer are
+//! subject to the same default filtering as any other query.
+//!
+//! [`With`]: crate::prelude::With
+//! [`Has`]: crate::prelude::Has
+
+use crate::{
+    self as bevy_ecs,
+    component::{ComponentId, Components, StorageType},
+    prelude::{Component, FromWorld, Resource, World},
+    query::FilteredAccess,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use {crate::reflect::ReflectComponent, bevy_reflect::Reflect};
+
+/// A marker component for disabled entities. See [the module docs] for more info.
+///
+/// Use [`EntityCommands::disable`] and [`EntityCommands::enable`] to toggle it.
+///
+/// [the module docs]: crate::entity_disabling
+/// [`EntityCommands::disable`]: crate::system::EntityCommands::disable
+/// [`EntityCommands::enable`]: crate::system::EntityCommands::enable
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct Disabled;
+
+/// The default filters for all queries made in the [`World`].
+///
+/// Currently, this is used to exclude entities with the [`Disabled`] component from queries
+/// unless the query mentions [`Disabled`] explicitly, such as by using `With<Disabled>`,
+/// `Has<Disabled>` or `Option<&Disabled>`.
+///
+/// This resource is initialized in the [`World`] upon creation, and changes to it only affect
+/// queries created afterwards.
+#[derive(Resource, Debug)]
+pub struct DefaultQueryFilters {
+    disabled: Option<ComponentId>,
+}
+
+impl FromWorld for DefaultQueryFilters {
+    fn from_world(world: &mut World) -> Self {
+        let mut filters = DefaultQueryFilters::empty();
+        let disabled_component_id = world.register_component::<Disabled>();
+        filters.set_disabled(disabled_component_id);
+        filters
+    }
+}
+
+impl DefaultQueryFilters {
+    /// Creates a new, completely empty [`DefaultQueryFilters`].
+    ///
+    /// This is provided as an escape hatch; in most cases you should initialize this using [`FromWorld`],
+    /// which is automatically called when creating a new [`World`].
+    #[must_use]
+    pub fn empty() -> Self {
+        DefaultQueryFilters { disabled: None }
+    }
+
+    /// Sets the [`ComponentId`] of the component used to mark disabled entities.
+    ///
+    /// The component must use [`StorageType::Table`].
+    pub fn set_disabled(&mut self, component_id: ComponentId) -> Option<ComponentId> {
+        self.disabled.replace(component_id)
+    }
+
+    /// Returns the [`ComponentId`] of the component used to mark disabled entities, if any.
+    pub fn get_disabled(&self) -> Option<ComponentId> {
+        self.disabled
+    }
+
+    /// Adds the default filters to the given [`FilteredAccess`], skipping any component
+    /// the access already refers to.
+    pub(crate) fn apply(&self, component_access: &mut FilteredAccess<ComponentId>) {
+        for component_id in self.disabled.iter() {
+            if !component_access.contains(*component_id) {
+                component_access.and_without(*component_id);
+            }
+        }
+    }
+
+    /// Returns `true` if applying these filters keeps a query dense.
+    pub(crate) fn is_dense(&self, components: &Components) -> bool {
+        self.disabled.map_or(true, |component_id| {
+            components
+                .get_info(component_id)
+                .is_some_and(|info| info.storage_type() == StorageType::Table)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{Has, With},
+        system::{Commands, RunSystemOnce},
+    };
+
+    #[derive(Component)]
+    struct A;
+
+    #[test]
+    fn disabled_entities_are_filtered_by_default() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        let mut query = world.query_filtered::<&A, With<Disabled>>();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        let mut query = world.query::<(&A, Has<Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        let mut query = world.query::<(&A, Option<&Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn disabled_entities_are_not_fetched() {
+        let mut world = World::new();
+        let entity = world.spawn((A, Disabled)).id();
+
+        let mut query = world.query::<&A>();
+        assert!(query.get(&world, entity).is_err());
+
+        let mut query = world.query::<(&A, Has<Disabled>)>();
+        assert!(query.get(&world, entity).is_ok());
+    }
+
+    #[test]
+    fn disable_and_enable_commands() {
+        let mut world = World::new();
+        let entity = world.spawn(A).id();
+
+        world
+            .run_system_once(move |mut commands: Commands| {
+                commands.entity(entity).disable();
+            })
+            .unwrap();
+        assert!(world.entity(entity).contains::<Disabled>());
+        assert_eq!(world.query::<&A>().iter(&world).count(), 0);
+
+        world
+            .run_system_once(move |mut commands: Commands| {
+                commands.entity(entity).enable();
+            })
+            .unwrap();
+        assert!(!world.entity(entity).contains::<Disabled>());
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn observers_run_for_disabled_entities() {
+        use crate::prelude::{OnRemove, ResMut, Trigger};
+
+        #[derive(Resource, Default)]
+        struct Removed(usize);
+
+        let mut world = World::new();
+        world.init_resource::<Removed>();
+        world.add_observer(|_: Trigger<OnRemove, A>, mut removed: ResMut<Removed>| {
+            removed.0 += 1;
+        });
+        let entity = world.spawn((A, Disabled)).id();
+        world.entity_mut(entity).remove::<A>();
+        assert_eq!(world.resource::<Removed>().0, 1);
+    }
+}
@@ -0,0 +1,318 @@
+//! Opt-in indexes for looking up entities by the value of one of their components.
+//!
+//! An index for a component `C` is created with [`World::register_component_index`]. From then on,
+//! the [`ComponentIndex<C>`] resource maps every value of `C` in the world to the entities that
+//! hold it. It is kept up to date by `on_insert` and `on_replace` [component hooks], so inserting,
+//! replacing, removing the component or despawning the entity is reflected immediately.
+//!
+//! Systems can use the [`Index<C>`] system parameter to look entities up by value in O(1).
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::index::Index;
+//! #[derive(Component, Clone, PartialEq, Eq, Hash)]
+//! struct NetworkId(u64);
+//!
+//! let mut world = World::new();
+//! world.register_component_index::<NetworkId>();
+//! let player = world.spawn(NetworkId(7)).id();
+//!
+//! fn find_player(index: Index<NetworkId>) {
+//!     let player = index.get_single(&NetworkId(7));
+//!     // ...
+//! }
+//! # world.run_system_once(find_player).unwrap();
+//! # use bevy_ecs::system::RunSystemOnce;
+//! ```
+//!
+//! # Mutation
+//!
+//! Hooks only run when a component is inserted or removed, not when it is mutated in place through
+//! `&mut C`. Indexed components should be changed by re-inserting them. If that isn't possible,
+//! add [`refresh_component_index::<C>`] to a schedule to pick up in-place changes.
+//!
+//! [component hooks]: crate::component::ComponentHooks
+
+use core::hash::Hash;
+
+use bevy_utils::HashMap;
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId},
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    query::Changed,
+    system::{Query, Res, ResMut, Resource, SystemParam},
+    world::{DeferredWorld, World},
+};
+
+/// A [`Resource`] mapping each value of the component `C` to the entities holding that value.
+///
+/// See the [module docs](crate::index) for more information.
+#[derive(Resource)]
+pub struct ComponentIndex<C: Component + Eq + Hash + Clone> {
+    entities_by_value: HashMap<C, EntityHashSet>,
+    values: EntityHashMap<C>,
+}
+
+impl<C: Component + Eq + Hash + Clone> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            entities_by_value: HashMap::default(),
+            values: EntityHashMap::default(),
+        }
+    }
+}
+
+impl<C: Component + Eq + Hash + Clone> ComponentIndex<C> {
+    /// Returns an iterator over the entities whose component equals `value`.
+    pub fn get<'a>(&'a self, value: &C) -> impl Iterator<Item = Entity> + 'a {
+        self.entities_by_value
+            .get(value)
+            .into_iter()
+            .flat_map(|entities| entities.iter().copied())
+    }
+
+    /// Returns the entity whose component equals `value`, if there is exactly one.
+    pub fn get_single(&self, value: &C) -> Option<Entity> {
+        let entities = self.entities_by_value.get(value)?;
+        if entities.len() == 1 {
+            entities.iter().next().copied()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if any entity has a component equal to `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.entities_by_value.contains_key(value)
+    }
+
+    /// Returns the number of entities whose component equals `value`.
+    pub fn count(&self, value: &C) -> usize {
+        self.entities_by_value
+            .get(value)
+            .map_or(0, EntityHashSet::len)
+    }
+
+    /// Returns the indexed value of the component on `entity`.
+    pub fn value(&self, entity: Entity) -> Option<&C> {
+        self.values.get(&entity)
+    }
+
+    /// Returns an iterator over all distinct values in the index.
+    pub fn values(&self) -> impl Iterator<Item = &C> {
+        self.entities_by_value.keys()
+    }
+
+    /// Returns the number of indexed entities.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no entities are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn insert(&mut self, entity: Entity, value: C) {
+        self.remove(entity);
+        self.entities_by_value
+            .entry(value.clone())
+            .or_default()
+            .insert(entity);
+        self.values.insert(entity, value);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        let Some(value) = self.values.remove(&entity) else {
+            return;
+        };
+        if let Some(entities) = self.entities_by_value.get_mut(&value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities_by_value.remove(&value);
+            }
+        }
+    }
+
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let Some(value) = world.get::<C>(entity).cloned() else {
+            return;
+        };
+        if let Some(mut index) = world.get_resource_mut::<Self>() {
+            index.insert(entity, value);
+        }
+    }
+
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        if let Some(mut index) = world.get_resource_mut::<Self>() {
+            index.remove(entity);
+        }
+    }
+}
+
+/// A [`SystemParam`] for looking up entities by the value of their component `C`.
+///
+/// This requires an index to have been registered with [`World::register_component_index`].
+/// See the [module docs](crate::index) for more information.
+#[derive(SystemParam)]
+pub struct Index<'w, C: Component + Eq + Hash + Clone> {
+    index: Res<'w, ComponentIndex<C>>,
+}
+
+impl<'w, C: Component + Eq + Hash + Clone> Index<'w, C> {
+    /// Returns an iterator over the entities whose component equals `value`.
+    pub fn get<'a>(&'a self, value: &C) -> impl Iterator<Item = Entity> + 'a {
+        self.index.get(value)
+    }
+
+    /// Returns the entity whose component equals `value`, if there is exactly one.
+    pub fn get_single(&self, value: &C) -> Option<Entity> {
+        self.index.get_single(value)
+    }
+
+    /// Returns `true` if any entity has a component equal to `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.index.contains(value)
+    }
+
+    /// Returns the underlying [`ComponentIndex`].
+    pub fn index(&self) -> &ComponentIndex<C> {
+        &self.index
+    }
+}
+
+impl World {
+    /// Creates a [`ComponentIndex`] for the component `C`, allowing entities to be looked up by
+    /// the value of `C` through the [`Index`] system parameter.
+    ///
+    /// Does nothing if an index for `C` already exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` already has `on_insert` or `on_replace` hooks, or if `C` is already in use
+    /// by any entity.
+    pub fn register_component_index<C: Component + Eq + Hash + Clone>(&mut self) {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return;
+        }
+        let hooks = self.register_component_hooks::<C>();
+        // Check both hooks up front so that a failed registration doesn't install only one of them.
+        if hooks.on_insert.is_some() || hooks.on_replace.is_some() {
+            panic!(
+                "Cannot index component `{}` because it already has `on_insert` or `on_replace` hooks",
+                core::any::type_name::<C>()
+            );
+        }
+        hooks
+            .on_insert(ComponentIndex::<C>::on_insert)
+            .on_replace(ComponentIndex::<C>::on_replace);
+        self.init_resource::<ComponentIndex<C>>();
+    }
+}
+
+/// A system that updates the [`ComponentIndex<C>`] for components that were mutated in place.
+///
+/// This is only needed if `C` is ever mutated through `&mut C` rather than re-inserted.
+pub fn refresh_component_index<C: Component + Eq + Hash + Clone>(
+    mut index: ResMut<ComponentIndex<C>>,
+    changed: Query<(Entity, &C), Changed<C>>,
+) {
+    for (entity, value) in &changed {
+        if index.value(entity) != Some(value) {
+            index.insert(entity, value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::RunSystemOnce;
+
+    #[derive(Component, Clone, PartialEq, Eq, Hash, Debug)]
+    struct Cell(i32, i32);
+
+    #[test]
+    fn index_tracks_inserts_and_removals() {
+        let mut world = World::new();
+        world.register_component_index::<Cell>();
+
+        let a = world.spawn(Cell(0, 0)).id();
+        let b = world.spawn(Cell(0, 0)).id();
+        let c = world.spawn(Cell(1, 0)).id();
+
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert_eq!(index.count(&Cell(0, 0)), 2);
+        assert_eq!(index.get_single(&Cell(1, 0)), Some(c));
+        assert_eq!(index.get_single(&Cell(0, 0)), None);
+
+        world.entity_mut(a).remove::<Cell>();
+        world.despawn(c);
+
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert_eq!(index.get_single(&Cell(0, 0)), Some(b));
+        assert!(!index.contains(&Cell(1, 0)));
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn index_tracks_replacement() {
+        let mut world = World::new();
+        world.register_component_index::<Cell>();
+
+        let entity = world.spawn(Cell(0, 0)).id();
+        world.entity_mut(entity).insert(Cell(2, 3));
+
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert!(!index.contains(&Cell(0, 0)));
+        assert_eq!(index.get_single(&Cell(2, 3)), Some(entity));
+        assert_eq!(index.value(entity), Some(&Cell(2, 3)));
+    }
+
+    #[test]
+    fn index_system_param() {
+        let mut world = World::new();
+        world.register_component_index::<Cell>();
+        let entity = world.spawn(Cell(4, 4)).id();
+
+        let found = world
+            .run_system_once(|index: Index<Cell>| index.get_single(&Cell(4, 4)))
+            .unwrap();
+        assert_eq!(found, Some(entity));
+    }
+
+    #[test]
+    fn conflicting_hooks_leave_component_untouched() {
+        let mut world = World::new();
+        world
+            .register_component_hooks::<Cell>()
+            .on_replace(|_, _, _| {});
+
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            world.register_component_index::<Cell>();
+        }));
+        assert!(result.is_err());
+
+        let hooks = world.register_component_hooks::<Cell>();
+        assert!(hooks.on_insert.is_none());
+        assert!(!world.contains_resource::<ComponentIndex<Cell>>());
+    }
+
+    #[test]
+    fn refresh_picks_up_mutation() {
+        let mut world = World::new();
+        world.register_component_index::<Cell>();
+        let entity = world.spawn(Cell(0, 0)).id();
+
+        world.get_mut::<Cell>(entity).unwrap().0 = 5;
+        world
+            .run_system_once(refresh_component_index::<Cell>)
+            .unwrap();
+
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert!(!index.contains(&Cell(0, 0)));
+        assert_eq!(index.get_single(&Cell(5, 0)), Some(entity));
+    }
+}
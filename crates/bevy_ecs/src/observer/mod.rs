@@ -17,8 +17,9 @@ use crate::{
     world::{DeferredWorld, *},
 };
 use bevy_ptr::Ptr;
-use bevy_utils::HashMap;
+use bevy_utils::{tracing::warn, HashMap};
 use core::{
+    cmp::Reverse,
     fmt::Debug,
     marker::PhantomData,
     ops::{Deref, DerefMut},
@@ -164,6 +165,9 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// The priority of the observer, see [`Observer::with_priority`].
+    priority: i32,
 }
 
 impl ObserverDescriptor {
@@ -188,11 +192,30 @@ impl ObserverDescriptor {
         self
     }
 
+    /// Set the priority of the observer, see [`Observer::with_priority`].
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the priority of the observer, see [`Observer::with_priority`].
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
     pub(crate) fn merge(&mut self, descriptor: &ObserverDescriptor) {
         self.events.extend(descriptor.events.iter().copied());
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        if self.priority == 0 {
+            self.priority = descriptor.priority;
+        } else if descriptor.priority != 0 && descriptor.priority != self.priority {
+            warn!(
+                "Observer descriptors with different priorities ({} and {}) were merged, keeping {}.",
+                self.priority, descriptor.priority, self.priority
+            );
+        }
     }
 }
 
@@ -216,8 +239,51 @@ impl ObserverTrigger {
     }
 }
 
+/// An [`ObserverRunner`] in the observer cache, along with the priority used to order it.
+#[derive(Clone, Copy, Debug)]
+struct CachedObserver {
+    runner: ObserverRunner,
+    priority: i32,
+}
+
 // Map between an observer entity and its runner
-type ObserverMap = EntityHashMap<ObserverRunner>;
+#[derive(Default, Debug)]
+struct ObserverMap {
+    observers: EntityHashMap<CachedObserver>,
+    // Observer entities in descending priority, then in registration order
+    order: Vec<Entity>,
+}
+
+impl ObserverMap {
+    fn insert(&mut self, observer: Entity, cached: CachedObserver) {
+        if let Some(previous) = self.observers.insert(observer, cached) {
+            if previous.priority == cached.priority {
+                return;
+            }
+            self.order.retain(|&entity| entity != observer);
+        }
+        let index = self
+            .order
+            .partition_point(|entity| self.observers[entity].priority >= cached.priority);
+        self.order.insert(index, observer);
+    }
+
+    fn remove(&mut self, observer: &Entity) {
+        if self.observers.remove(observer).is_some() {
+            self.order.retain(|entity| entity != observer);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (Entity, CachedObserver)> + '_ {
+        self.order
+            .iter()
+            .map(|&observer| (observer, self.observers[&observer]))
+    }
+}
 
 /// Collection of [`ObserverRunner`] for [`Observer`] registered to a particular trigger targeted at a specific component.
 #[derive(Default, Debug)]
@@ -237,6 +303,8 @@ pub struct CachedObservers {
     component_observers: HashMap<ComponentId, CachedComponentObservers>,
     // Observers listening for this trigger fired at a specific entity
     entity_observers: EntityHashMap<ObserverMap>,
+    // Number of registered observers with a non-zero priority
+    prioritized: usize,
 }
 
 /// Metadata for observers. Stores a cache mapping trigger ids to the registered observers.
@@ -273,6 +341,10 @@ impl Observers {
     }
 
     /// This will run the observers of the given `event_type`, targeting the given `entity` and `components`.
+    ///
+    /// Observers run in descending [priority](Observer::with_priority). Observers with the same
+    /// priority run global observers first, then entity observers, then component observers in the
+    /// order of `components`, each in the order they were registered.
     pub(crate) fn invoke<T>(
         mut world: DeferredWorld,
         event_type: ComponentId,
@@ -294,9 +366,7 @@ impl Observers {
             (world.into_deferred(), observers)
         };
 
-        let trigger_for_components = components.clone();
-
-        let mut trigger_observer = |(&observer, runner): (&Entity, &ObserverRunner)| {
+        let mut trigger_observer = |observer: Entity, runner: ObserverRunner| {
             (runner)(
                 world.reborrow(),
                 ObserverTrigger {
@@ -309,31 +379,72 @@ impl Observers {
                 propagate,
             );
         };
-        // Trigger observers listening for any kind of this trigger
-        observers.map.iter().for_each(&mut trigger_observer);
 
-        // Trigger entity observers listening for this kind of trigger
-        if entity != Entity::PLACEHOLDER {
-            if let Some(map) = observers.entity_observers.get(&entity) {
-                map.iter().for_each(&mut trigger_observer);
+        let entity_observers = (entity != Entity::PLACEHOLDER)
+            .then(|| observers.entity_observers.get(&entity))
+            .flatten();
+        let component_observers = components.clone().filter_map(|id| {
+            let component_observers = observers.component_observers.get(&id)?;
+            let entity_map = (entity != Entity::PLACEHOLDER)
+                .then(|| component_observers.entity_map.get(&entity))
+                .flatten();
+            Some((&component_observers.map, entity_map))
+        });
+
+        if observers.prioritized == 0 {
+            // Every observer has the same priority, so the order of each group already is the
+            // registration order.
+
+            // Trigger observers listening for any kind of this trigger
+            for (observer, cached) in observers.map.iter() {
+                trigger_observer(observer, cached.runner);
             }
-        }
 
-        // Trigger observers listening to this trigger targeting a specific component
-        trigger_for_components.for_each(|id| {
-            if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .map
-                    .iter()
-                    .for_each(&mut trigger_observer);
+            // Trigger entity observers listening for this kind of trigger
+            for (observer, cached) in entity_observers.into_iter().flat_map(ObserverMap::iter) {
+                trigger_observer(observer, cached.runner);
+            }
 
-                if entity != Entity::PLACEHOLDER {
-                    if let Some(map) = component_observers.entity_map.get(&entity) {
-                        map.iter().for_each(&mut trigger_observer);
-                    }
+            // Trigger observers listening to this trigger targeting a specific component
+            for (map, entity_map) in component_observers {
+                for (observer, cached) in map
+                    .iter()
+                    .chain(entity_map.into_iter().flat_map(ObserverMap::iter))
+                {
+                    trigger_observer(observer, cached.runner);
                 }
             }
-        });
+            return;
+        }
+
+        let mut matched = SmallVec::<[(Entity, u8, CachedObserver); 8]>::new();
+        matched.extend(
+            observers
+                .map
+                .iter()
+                .map(|(observer, cached)| (observer, 0, cached)),
+        );
+        matched.extend(
+            entity_observers
+                .into_iter()
+                .flat_map(ObserverMap::iter)
+                .map(|(observer, cached)| (observer, 1, cached)),
+        );
+        for (map, entity_map) in component_observers {
+            matched.extend(
+                map.iter()
+                    .chain(entity_map.into_iter().flat_map(ObserverMap::iter))
+                    .map(|(observer, cached)| (observer, 2, cached)),
+            );
+        }
+
+        // The sort is stable, so observers with equal priority keep the order they would have
+        // without priorities.
+        matched.sort_by_key(|&(_, group, cached)| (Reverse(cached.priority), group));
+
+        for (observer, _, cached) in matched {
+            trigger_observer(observer, cached.runner);
+        }
     }
 
     pub(crate) fn is_archetype_cached(event_type: ComponentId) -> Option<ArchetypeFlags> {
@@ -461,17 +572,24 @@ impl World {
             (&*observer_state, &mut self.archetypes, &mut self.observers)
         };
         let descriptor = &observer_state.descriptor;
+        let cached = CachedObserver {
+            runner: observer_state.runner,
+            priority: descriptor.priority,
+        };
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            if descriptor.priority != 0 {
+                cache.prioritized += 1;
+            }
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
-                cache.map.insert(observer_entity, observer_state.runner);
+                cache.map.insert(observer_entity, cached);
             } else if descriptor.components.is_empty() {
                 // Observer is not targeting any components so register it as an entity observer
                 for &watched_entity in &observer_state.descriptor.entities {
                     let map = cache.entity_observers.entry(watched_entity).or_default();
-                    map.insert(observer_entity, observer_state.runner);
+                    map.insert(observer_entity, cached);
                 }
             } else {
                 // Register observer for each watched component
@@ -488,12 +606,12 @@ impl World {
                             });
                     if descriptor.entities.is_empty() {
                         // Register for all triggers targeting the component
-                        observers.map.insert(observer_entity, observer_state.runner);
+                        observers.map.insert(observer_entity, cached);
                     } else {
                         // Register for each watched entity
                         for &watched_entity in &descriptor.entities {
                             let map = observers.entity_map.entry(watched_entity).or_default();
-                            map.insert(observer_entity, observer_state.runner);
+                            map.insert(observer_entity, cached);
                         }
                     }
                 }
@@ -508,6 +626,9 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            if descriptor.priority != 0 {
+                cache.prioritized -= 1;
+            }
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.remove(&entity);
             } else if descriptor.components.is_empty() {
@@ -815,6 +936,93 @@ mod tests {
         assert_eq!(world.entities().len(), 3);
     }
 
+    #[test]
+    fn observer_priority() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let entity = world.spawn_empty().id();
+        world.add_observer(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("global"));
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("last"))
+                .with_priority(-1),
+        );
+        world
+            .entity_mut(entity)
+            .observe(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("entity"));
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("first"))
+                .with_entity(entity)
+                .with_priority(10),
+        );
+        world.flush();
+
+        world.trigger_targets(EventA, entity);
+        assert_eq!(
+            vec!["first", "global", "entity", "last"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_registration_order() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let entity = world.spawn_empty().id();
+        world
+            .entity_mut(entity)
+            .observe(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("entity"));
+        for name in ["first", "second", "third"] {
+            world.add_observer(move |_: Trigger<EventA>, mut res: ResMut<Order>| {
+                res.observed(name);
+            });
+        }
+        world.flush();
+
+        world.trigger_targets(EventA, entity);
+        assert_eq!(
+            vec!["first", "second", "third", "entity"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_priority_component_targets() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.add_observer(|_: Trigger<OnAdd, (A, B)>, mut res: ResMut<Order>| {
+            res.observed("add_ab");
+        });
+        world.add_observer(|_: Trigger<OnAdd, B>, mut res: ResMut<Order>| res.observed("add_b"));
+        world.spawn(
+            Observer::new(|_: Trigger<OnAdd, A>, mut res: ResMut<Order>| res.observed("add_a"))
+                .with_priority(1),
+        );
+        world.flush();
+
+        world.spawn((A, B)).flush();
+        assert_eq!(
+            vec!["add_a", "add_ab", "add_b"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_descriptor_merge_keeps_priority() {
+        let mut descriptor = ObserverDescriptor::default();
+        descriptor.merge(&ObserverDescriptor::default().with_priority(5));
+        assert_eq!(descriptor.priority(), 5);
+
+        descriptor.merge(&ObserverDescriptor::default());
+        assert_eq!(descriptor.priority(), 5);
+
+        // conflicting priorities keep the first one
+        descriptor.merge(&ObserverDescriptor::default().with_priority(-3));
+        assert_eq!(descriptor.priority(), 5);
+    }
+
     #[test]
     fn observer_multiple_events() {
         let mut world = World::new();
@@ -58,6 +58,12 @@ impl ObserverState {
         self.descriptor.components.extend(components);
         self
     }
+
+    /// Set the priority of the [`Observer`]. See [`Observer::with_priority`] for details.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.descriptor.priority = priority;
+        self
+    }
 }
 
 impl Component for ObserverState {
@@ -259,6 +265,36 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 ///
 /// You can call [`Observer::watch_entity`] more than once, which allows you to watch multiple entities with the same [`Observer`].
 ///
+/// # Ordering
+///
+/// All observers that match a trigger run one after the other, in descending
+/// [priority](Observer::with_priority). Ties are broken deterministically, as described in
+/// [`Observer::with_priority`]. This makes it possible to, for example, validate an event before
+/// applying its effects:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::default();
+/// #[derive(Event)]
+/// struct Damage {
+///     amount: u32,
+/// }
+///
+/// world.add_observer(|trigger: Trigger<Damage>| {
+///     println!("Applying {} damage", trigger.event().amount);
+/// });
+/// // Runs first, even though it was added last.
+/// world.spawn(
+///     Observer::new(|mut trigger: Trigger<Damage>| {
+///         let damage = trigger.event_mut();
+///         damage.amount = damage.amount.min(100);
+///     })
+///     .with_priority(10),
+/// );
+/// world.flush();
+/// world.trigger(Damage { amount: 1000 });
+/// ```
+///
 /// When first added, [`Observer`] will also create an [`ObserverState`] component, which registers the observer with the [`World`] and
 /// serves as the "source of truth" of the observer.
 ///
@@ -310,6 +346,19 @@ impl Observer {
         self.descriptor.events.push(event);
         self
     }
+
+    /// Set the priority of the [`Observer`], which defaults to `0`.
+    ///
+    /// When an [`Event`] is triggered, the observers it runs are ordered by descending priority.
+    /// Observers with the same priority run global observers first, then observers watching the
+    /// targeted entity, then observers watching the targeted components. Within each of these
+    /// groups, observers run in the order they were registered, which is the order in which their
+    /// [`Observer`] components were added.
+    /// Note that if this is called _after_ an [`Observer`] is spawned, it will produce no effects.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.descriptor.priority = priority;
+        self
+    }
 }
 
 impl Component for Observer {
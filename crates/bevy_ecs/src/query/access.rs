@@ -976,6 +976,24 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.required.grow_and_insert(index.sparse_set_index());
     }
 
+    /// Returns `true` if this access explicitly refers to the component given by `index`,
+    /// either by accessing it, by depending on its presence (such as [`Has<T>`]),
+    /// or by filtering on it with `With` or `Without`.
+    ///
+    /// Blanket accesses like those of `EntityRef` do not count as referring to a component.
+    ///
+    /// [`Has<T>`]: crate::query::Has
+    pub fn contains(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        (!self.access.component_read_and_writes_inverted
+            && self.access.component_read_and_writes.contains(index))
+            || self.access.archetypal.contains(index)
+            || self
+                .filter_sets
+                .iter()
+                .any(|f| f.with.contains(index) || f.without.contains(index))
+    }
+
     /// Adds a `With` filter: corresponds to a conjunction (AND) operation.
     ///
     /// Suppose we begin with `Or<(With<A>, With<B>)>`, which is represented by an array of two `AccessFilter` instances.
@@ -0,0 +1,491 @@
+use core::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+#[cfg(feature = "track_change_detection")]
+use core::panic::Location;
+
+use bevy_utils::all_tuples;
+
+use crate::{
+    change_detection::Mut,
+    component::{Component, ComponentId, Tick},
+    entity::Entity,
+    query::{DebugCheckedUnwrap, QueryData},
+    storage::Table,
+    world::Ref,
+};
+
+/// A [`QueryData`] that can be fetched for all entities of a [`Table`] at once, as contiguous
+/// slices.
+///
+/// This is used by [`Query::iter_chunks`](crate::system::Query::iter_chunks) and
+/// [`Query::iter_chunks_mut`](crate::system::Query::iter_chunks_mut). It is implemented for:
+///
+/// - [`Entity`], fetched as `&[Entity]`.
+/// - `&T`, fetched as `&[T]`.
+/// - [`Ref<T>`], fetched as a [`ContiguousRef<T>`] exposing the change ticks of each value.
+/// - `&mut T`, fetched as a [`ContiguousMut<T>`], which marks all values as changed when it is
+///   mutably dereferenced.
+/// - Tuples of the above.
+///
+/// Components must be stored in tables. Queries over [`SparseSet`] components can't be iterated
+/// in chunks.
+///
+/// # Safety
+///
+/// `fetch_chunk` must only access the data accessed by the [`WorldQuery`] implementation of
+/// `Self`, and must return the values of all entities in the table, in the order of
+/// [`Table::entities`].
+///
+/// [`SparseSet`]: crate::component::StorageType::SparseSet
+/// [`WorldQuery`]: crate::query::WorldQuery
+pub unsafe trait ContiguousQueryData: QueryData {
+    /// The slices fetched for all entities in a table.
+    type Chunk<'w>;
+
+    /// Fetches the slices for all entities in `table`.
+    ///
+    /// # Safety
+    ///
+    /// - `table` must be matched by a query with this data, and the caller must have the access
+    ///   to it registered by the [`WorldQuery`](crate::query::WorldQuery) implementation.
+    /// - `state` must be the state of the query.
+    /// - No other references to the accessed data in `table` may exist while the chunk is alive.
+    unsafe fn fetch_chunk<'w>(
+        state: &Self::State,
+        table: &'w Table,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Chunk<'w>;
+}
+
+// SAFETY: `Entity` doesn't access any component data.
+unsafe impl ContiguousQueryData for Entity {
+    type Chunk<'w> = &'w [Entity];
+
+    unsafe fn fetch_chunk<'w>(
+        _state: &(),
+        table: &'w Table,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) -> &'w [Entity] {
+        table.entities()
+    }
+}
+
+// SAFETY: Only reads the column of `T`, which `&T` registers read access to.
+unsafe impl<T: Component> ContiguousQueryData for &T {
+    type Chunk<'w> = &'w [T];
+
+    unsafe fn fetch_chunk<'w>(
+        &component_id: &ComponentId,
+        table: &'w Table,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) -> &'w [T] {
+        // SAFETY: The caller ensures that the table matches the query, so it has a column for `T`.
+        let values = unsafe { table.get_data_slice_for::<T>(component_id) }.debug_checked_unwrap();
+        // SAFETY: The caller ensures that there are no mutable references to the column.
+        unsafe { cells_as_slice(values) }
+    }
+}
+
+// SAFETY: Only reads the column and ticks of `T`, which `Ref<T>` registers read access to.
+unsafe impl<'__w, T: Component> ContiguousQueryData for Ref<'__w, T> {
+    type Chunk<'w> = ContiguousRef<'w, T>;
+
+    unsafe fn fetch_chunk<'w>(
+        &component_id: &ComponentId,
+        table: &'w Table,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> ContiguousRef<'w, T> {
+        // SAFETY: The caller ensures that the table matches the query and that there are no
+        // mutable references to the column.
+        unsafe {
+            ContiguousRef {
+                values: cells_as_slice(
+                    table
+                        .get_data_slice_for::<T>(component_id)
+                        .debug_checked_unwrap(),
+                ),
+                added: cells_as_slice(
+                    table
+                        .get_added_ticks_slice_for(component_id)
+                        .debug_checked_unwrap(),
+                ),
+                changed: cells_as_slice(
+                    table
+                        .get_changed_ticks_slice_for(component_id)
+                        .debug_checked_unwrap(),
+                ),
+                last_run,
+                this_run,
+            }
+        }
+    }
+}
+
+// SAFETY: Only accesses the column and ticks of `T`, which `&mut T` registers write access to.
+unsafe impl<T: Component> ContiguousQueryData for &mut T {
+    type Chunk<'w> = ContiguousMut<'w, T>;
+
+    unsafe fn fetch_chunk<'w>(
+        &component_id: &ComponentId,
+        table: &'w Table,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> ContiguousMut<'w, T> {
+        // SAFETY: The caller ensures that the table matches the query and that there are no
+        // other references to the column.
+        unsafe {
+            ContiguousMut {
+                values: cells_as_mut_slice(
+                    table
+                        .get_data_slice_for::<T>(component_id)
+                        .debug_checked_unwrap(),
+                ),
+                added: cells_as_mut_slice(
+                    table
+                        .get_added_ticks_slice_for(component_id)
+                        .debug_checked_unwrap(),
+                ),
+                changed: cells_as_mut_slice(
+                    table
+                        .get_changed_ticks_slice_for(component_id)
+                        .debug_checked_unwrap(),
+                ),
+                #[cfg(feature = "track_change_detection")]
+                changed_by: cells_as_mut_slice(
+                    table
+                        .get_changed_by_slice_for(component_id)
+                        .debug_checked_unwrap(),
+                ),
+                last_run,
+                this_run,
+            }
+        }
+    }
+}
+
+macro_rules! impl_tuple_contiguous_query_data {
+    ($(#[$meta:meta])* $(($name: ident, $state: ident)),*) => {
+        #[allow(non_snake_case)]
+        #[allow(clippy::unused_unit)]
+        $(#[$meta])*
+        // SAFETY: defers to the `fetch_chunk` of each element.
+        unsafe impl<$($name: ContiguousQueryData),*> ContiguousQueryData for ($($name,)*) {
+            type Chunk<'w> = ($($name::Chunk<'w>,)*);
+
+            #[allow(unused_variables)]
+            unsafe fn fetch_chunk<'w>(
+                state: &Self::State,
+                table: &'w Table,
+                last_run: Tick,
+                this_run: Tick,
+            ) -> Self::Chunk<'w> {
+                let ($($state,)*) = state;
+                // SAFETY: The invariants are upheld by the caller.
+                ($(unsafe { $name::fetch_chunk($state, table, last_run, this_run) },)*)
+            }
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_tuple_contiguous_query_data,
+    0,
+    15,
+    F,
+    S
+);
+
+/// Shared access to the values of a component in a table, along with their change ticks.
+///
+/// This is the [`ContiguousQueryData::Chunk`] of [`Ref<T>`], and dereferences to `[T]`.
+pub struct ContiguousRef<'w, T> {
+    values: &'w [T],
+    added: &'w [Tick],
+    changed: &'w [Tick],
+    last_run: Tick,
+    this_run: Tick,
+}
+
+impl<'w, T> ContiguousRef<'w, T> {
+    /// Returns the values as a slice, with the lifetime of the query.
+    pub fn into_inner(self) -> &'w [T] {
+        self.values
+    }
+
+    /// Returns the tick at which each value was added.
+    pub fn added_ticks(&self) -> &'w [Tick] {
+        self.added
+    }
+
+    /// Returns the tick at which each value was last changed.
+    pub fn changed_ticks(&self) -> &'w [Tick] {
+        self.changed
+    }
+
+    /// Returns `true` if the value at `index` was added after the system last ran.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn is_added(&self, index: usize) -> bool {
+        self.added[index].is_newer_than(self.last_run, self.this_run)
+    }
+
+    /// Returns `true` if the value at `index` was added or mutably dereferenced after the system
+    /// last ran.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn is_changed(&self, index: usize) -> bool {
+        self.changed[index].is_newer_than(self.last_run, self.this_run)
+    }
+}
+
+impl<T> Deref for ContiguousRef<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.values
+    }
+}
+
+/// Unique access to the values of a component in a table, along with their change ticks.
+///
+/// This is the [`ContiguousQueryData::Chunk`] of `&mut T`. It dereferences to `[T]`, and
+/// mutably dereferencing it marks all values as changed. Use [`iter_mut`](Self::iter_mut) to
+/// only mark the values that are actually written to.
+pub struct ContiguousMut<'w, T> {
+    values: &'w mut [T],
+    added: &'w mut [Tick],
+    changed: &'w mut [Tick],
+    #[cfg(feature = "track_change_detection")]
+    changed_by: &'w mut [&'static Location<'static>],
+    last_run: Tick,
+    this_run: Tick,
+}
+
+impl<'w, T> ContiguousMut<'w, T> {
+    /// Returns the tick at which each value was added.
+    pub fn added_ticks(&self) -> &[Tick] {
+        self.added
+    }
+
+    /// Returns the tick at which each value was last changed.
+    pub fn changed_ticks(&self) -> &[Tick] {
+        self.changed
+    }
+
+    /// Returns `true` if the value at `index` was added after the system last ran.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn is_added(&self, index: usize) -> bool {
+        self.added[index].is_newer_than(self.last_run, self.this_run)
+    }
+
+    /// Returns `true` if the value at `index` was added or mutably dereferenced after the system
+    /// last ran.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn is_changed(&self, index: usize) -> bool {
+        self.changed[index].is_newer_than(self.last_run, self.this_run)
+    }
+
+    /// Marks all values as changed.
+    #[cfg_attr(feature = "track_change_detection", track_caller)]
+    pub fn set_changed(&mut self) {
+        self.changed.fill(self.this_run);
+        #[cfg(feature = "track_change_detection")]
+        self.changed_by.fill(Location::caller());
+    }
+
+    /// Returns the values as a mutable slice without marking them as changed.
+    pub fn bypass_change_detection(&mut self) -> &mut [T] {
+        self.values
+    }
+
+    /// Returns an iterator of change-detection enabled smart pointers to each value, so that only
+    /// the values that are mutably dereferenced are marked as changed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = Mut<'_, T>> {
+        let values = self.values.iter_mut().zip(self.added.iter_mut());
+        #[cfg(feature = "track_change_detection")]
+        let changed = self.changed.iter_mut().zip(self.changed_by.iter_mut());
+        #[cfg(not(feature = "track_change_detection"))]
+        let changed = self.changed.iter_mut();
+        let (last_run, this_run) = (self.last_run, self.this_run);
+        values.zip(changed).map(move |((value, added), changed)| {
+            #[cfg(feature = "track_change_detection")]
+            let (changed, caller) = changed;
+            Mut::new(
+                value,
+                added,
+                changed,
+                last_run,
+                this_run,
+                #[cfg(feature = "track_change_detection")]
+                caller,
+            )
+        })
+    }
+}
+
+impl<T> Deref for ContiguousMut<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.values
+    }
+}
+
+impl<T> DerefMut for ContiguousMut<'_, T> {
+    #[cfg_attr(feature = "track_change_detection", track_caller)]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.set_changed();
+        self.values
+    }
+}
+
+/// # Safety
+///
+/// There must be no mutable references to the values while the returned slice is alive.
+unsafe fn cells_as_slice<T>(cells: &[UnsafeCell<T>]) -> &[T] {
+    // SAFETY: `UnsafeCell<T>` has the same layout as `T`, and the caller ensures there is no
+    // mutable aliasing.
+    unsafe { slice::from_raw_parts(cells.as_ptr().cast::<T>(), cells.len()) }
+}
+
+/// # Safety
+///
+/// There must be no other references to the values while the returned slice is alive.
+#[allow(clippy::mut_from_ref)]
+unsafe fn cells_as_mut_slice<T>(cells: &[UnsafeCell<T>]) -> &mut [T] {
+    // SAFETY: `UnsafeCell<T>` has the same layout as `T`, writing through it is allowed, and the
+    // caller ensures there is no aliasing.
+    unsafe { slice::from_raw_parts_mut(UnsafeCell::raw_get(cells.as_ptr()), cells.len()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::Entity,
+        query::{Changed, With},
+        world::{Ref, World},
+    };
+
+    #[derive(Component, Debug, PartialEq)]
+    struct A(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct B(u32);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Sparse;
+
+    #[test]
+    fn chunks_per_table() {
+        let mut world = World::new();
+        let e1 = world.spawn(A(1)).id();
+        let e2 = world.spawn(A(2)).id();
+        let e3 = world.spawn((A(3), B(0))).id();
+
+        let mut query = world.query::<(Entity, &A)>();
+        let mut chunks: Vec<_> = query
+            .iter_chunks(&world)
+            .map(|(entities, values)| {
+                assert_eq!(entities.len(), values.len());
+                entities
+                    .iter()
+                    .copied()
+                    .zip(values.iter().map(|a| a.0))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        chunks.sort();
+        assert_eq!(chunks, vec![vec![(e1, 1), (e2, 2)], vec![(e3, 3)]]);
+
+        let mut query = world.query_filtered::<&A, With<B>>();
+        let chunks: Vec<_> = query.iter_chunks(&world).collect();
+        assert_eq!(chunks, vec![&[A(3)][..]]);
+    }
+
+    #[test]
+    fn mutable_chunks_detect_changes() {
+        let mut world = World::new();
+        world.spawn_batch((0..4).map(A));
+        world.spawn((A(10), B(0)));
+        let mut changed = world.query_filtered::<&A, Changed<A>>();
+        changed.iter(&world).for_each(drop);
+        world.clear_trackers();
+
+        // Writing through the slice marks the whole table as changed.
+        let mut query = world.query_filtered::<&mut A, With<B>>();
+        for mut values in query.iter_chunks_mut(&mut world) {
+            values[0].0 += 1;
+        }
+        let mut values: Vec<_> = changed.iter(&world).map(|a| a.0).collect();
+        values.sort();
+        assert_eq!(values, vec![11]);
+        world.clear_trackers();
+
+        // Only items that are written through `iter_mut` are marked as changed.
+        let mut query = world.query::<&mut A>();
+        for mut values in query.iter_chunks_mut(&mut world) {
+            for mut value in values.iter_mut() {
+                if value.0 % 2 == 0 {
+                    value.0 += 100;
+                }
+            }
+        }
+        let mut values: Vec<_> = changed.iter(&world).map(|a| a.0).collect();
+        values.sort();
+        assert_eq!(values, vec![100, 102]);
+        world.clear_trackers();
+
+        // Bypassing change detection doesn't mark anything.
+        for mut values in query.iter_chunks_mut(&mut world) {
+            values.bypass_change_detection()[0].0 = 0;
+        }
+        assert_eq!(changed.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn ref_chunks_expose_ticks() {
+        let mut world = World::new();
+        world.spawn(A(0));
+        world.clear_trackers();
+        world.spawn(A(1));
+
+        let mut query = world.query::<Ref<A>>();
+        let chunk = query.iter_chunks(&world).next().unwrap();
+        assert_eq!(chunk.len(), 2);
+        assert_eq!(chunk.added_ticks().len(), 2);
+        assert!(!chunk.is_added(0));
+        assert!(chunk.is_added(1));
+        assert!(chunk.is_changed(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sparse_components_panic() {
+        let mut world = World::new();
+        world.spawn((A(0), Sparse));
+        let mut query = world.query_filtered::<&A, With<Sparse>>();
+        query.iter_chunks(&world).for_each(drop);
+    }
+}
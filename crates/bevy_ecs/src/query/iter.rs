@@ -2,7 +2,7 @@ use crate::{
     archetype::{Archetype, ArchetypeEntity, Archetypes},
     component::Tick,
     entity::{Entities, Entity},
-    query::{ArchetypeFilter, DebugCheckedUnwrap, QueryState, StorageId},
+    query::{ArchetypeFilter, ContiguousQueryData, DebugCheckedUnwrap, QueryState, StorageId},
     storage::{Table, TableRow, Tables},
     world::unsafe_world_cell::UnsafeWorldCell,
 };
@@ -1702,6 +1702,94 @@ impl<'w, 's, D: QueryData, F: QueryFilter, const K: usize> Debug
     }
 }
 
+/// An [`Iterator`] over the matched tables of a [`Query`](crate::system::Query), yielding the
+/// slices of each table at once.
+///
+/// This struct is created by the [`Query::iter_chunks`](crate::system::Query::iter_chunks) and
+/// [`Query::iter_chunks_mut`](crate::system::Query::iter_chunks_mut) methods. Each item is the
+/// [`ContiguousQueryData::Chunk`] of `D` for one table. Empty tables are skipped.
+pub struct QueryChunkIter<'w, 's, D: ContiguousQueryData, F: ArchetypeFilter> {
+    tables: &'w Tables,
+    storage_id_iter: core::slice::Iter<'s, StorageId>,
+    query_state: &'s QueryState<D, F>,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+impl<'w, 's, D: ContiguousQueryData, F: ArchetypeFilter> QueryChunkIter<'w, 's, D, F> {
+    /// # Panics
+    ///
+    /// Panics if the query isn't dense, which is the case if it accesses a
+    /// [`SparseSet`](crate::component::StorageType::SparseSet) component.
+    ///
+    /// # Safety
+    /// - `world` must have permission to access any of the components registered in `query_state`.
+    /// - `world` must be the same one used to initialize `query_state`.
+    pub(crate) unsafe fn new(
+        world: UnsafeWorldCell<'w>,
+        query_state: &'s QueryState<D, F>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self {
+        assert!(
+            query_state.is_dense,
+            "Chunk iteration requires all components accessed by the query to be stored in tables, \
+            but {} accesses components stored in sparse sets.",
+            core::any::type_name::<QueryState<D, F>>()
+        );
+        QueryChunkIter {
+            // SAFETY: We only access table data that has been registered in `query_state`.
+            tables: unsafe { &world.storages().tables },
+            storage_id_iter: query_state.matched_storage_ids.iter(),
+            query_state,
+            last_run,
+            this_run,
+        }
+    }
+}
+
+impl<'w, 's, D: ContiguousQueryData, F: ArchetypeFilter> Iterator for QueryChunkIter<'w, 's, D, F> {
+    type Item = D::Chunk<'w>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // SAFETY: The query is dense, so the storage ids are table ids.
+            let table_id = unsafe { self.storage_id_iter.next()?.table_id };
+            // SAFETY: Matched table ids are valid.
+            let table = unsafe { self.tables.get(table_id).debug_checked_unwrap() };
+            if table.is_empty() {
+                continue;
+            }
+            // SAFETY:
+            // - The table is matched by the query, which has access to the fetched components.
+            // - Each table is only yielded once, so the chunks don't alias.
+            return Some(unsafe {
+                D::fetch_chunk(
+                    &self.query_state.fetch_state,
+                    table,
+                    self.last_run,
+                    self.this_run,
+                )
+            });
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.storage_id_iter.len()))
+    }
+}
+
+impl<'w, 's, D: ContiguousQueryData, F: ArchetypeFilter> FusedIterator
+    for QueryChunkIter<'w, 's, D, F>
+{
+}
+
+impl<'w, 's, D: ContiguousQueryData, F: ArchetypeFilter> Debug for QueryChunkIter<'w, 's, D, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueryChunkIter").finish()
+    }
+}
+
 struct QueryIterationCursor<'w, 's, D: QueryData, F: QueryFilter> {
     // whether the query iteration is dense or not. Mirrors QueryState's `is_dense` field.
     is_dense: bool,
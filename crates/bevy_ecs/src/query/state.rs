@@ -3,9 +3,11 @@ use crate::{
     batching::BatchingStrategy,
     component::{ComponentId, Tick},
     entity::Entity,
+    entity_disabling::DefaultQueryFilters,
     prelude::FromWorld,
     query::{
-        Access, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter, QueryParIter,
+        Access, ArchetypeFilter, ContiguousQueryData, DebugCheckedUnwrap, FilteredAccess,
+        QueryChunkIter, QueryCombinationIter, QueryIter, QueryParIter,
     },
     storage::{SparseSetIndex, TableId},
     world::{unsafe_world_cell::UnsafeWorldCell, World, WorldId},
@@ -202,7 +204,12 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 
         // For queries without dynamic filters the dense-ness of the query is equal to the dense-ness
         // of its static type parameters.
-        let is_dense = D::IS_DENSE && F::IS_DENSE;
+        let mut is_dense = D::IS_DENSE && F::IS_DENSE;
+
+        if let Some(default_filters) = world.get_resource::<DefaultQueryFilters>() {
+            default_filters.apply(&mut component_access);
+            is_dense &= default_filters.is_dense(world.components());
+        }
 
         Self {
             world_id: world.id(),
@@ -229,15 +236,24 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
+        let mut component_access = builder.access().clone();
+
+        // For dynamic queries the dense-ness is given by the query builder.
+        let mut is_dense = builder.is_dense();
+
+        if let Some(default_filters) = builder.world().get_resource::<DefaultQueryFilters>() {
+            default_filters.apply(&mut component_access);
+            is_dense &= default_filters.is_dense(builder.world().components());
+        }
+
         let mut state = Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
-            // For dynamic queries the dense-ness is given by the query builder.
-            is_dense: builder.is_dense(),
+            is_dense,
             fetch_state,
             filter_state,
-            component_access: builder.access().clone(),
+            component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             #[cfg(feature = "trace")]
@@ -1094,6 +1110,67 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the matched tables for the given [`World`], yielding the
+    /// slices of all entities in each table at once.
+    ///
+    /// See [`Query::iter_chunks`](crate::system::Query::iter_chunks) for details.
+    ///
+    /// This can only be called for read-only queries, see [`Self::iter_chunks_mut`] for
+    /// write-queries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query accesses components stored in sparse sets.
+    #[inline]
+    pub fn iter_chunks<'w, 's>(
+        &'s mut self,
+        world: &'w World,
+    ) -> QueryChunkIter<'w, 's, D::ReadOnly, F>
+    where
+        D::ReadOnly: ContiguousQueryData,
+        F: ArchetypeFilter,
+    {
+        self.update_archetypes(world);
+        // SAFETY: query is read only
+        unsafe {
+            self.as_readonly().iter_chunks_unchecked_manual(
+                world.as_unsafe_world_cell_readonly(),
+                world.last_change_tick(),
+                world.read_change_tick(),
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the matched tables for the given [`World`], yielding the
+    /// slices of all entities in each table at once.
+    ///
+    /// See [`Query::iter_chunks_mut`](crate::system::Query::iter_chunks_mut) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query accesses components stored in sparse sets.
+    #[inline]
+    pub fn iter_chunks_mut<'w, 's>(
+        &'s mut self,
+        world: &'w mut World,
+    ) -> QueryChunkIter<'w, 's, D, F>
+    where
+        D: ContiguousQueryData,
+        F: ArchetypeFilter,
+    {
+        self.update_archetypes(world);
+        let change_tick = world.change_tick();
+        let last_change_tick = world.last_change_tick();
+        // SAFETY: query has unique world access
+        unsafe {
+            self.iter_chunks_unchecked_manual(
+                world.as_unsafe_world_cell(),
+                last_change_tick,
+                change_tick,
+            )
+        }
+    }
+
     /// Returns an [`Iterator`] over the query results for the given [`World`] without updating the query's archetypes.
     /// Archetypes must be manually updated before by using [`Self::update_archetypes`].
     ///
@@ -1335,6 +1412,33 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         QueryIter::new(world, self, last_run, this_run)
     }
 
+    /// Returns an [`Iterator`] over the matched tables for the given [`World`], where the last
+    /// change and the current change tick are given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query accesses components stored in sparse sets.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    #[inline]
+    pub(crate) unsafe fn iter_chunks_unchecked_manual<'w, 's>(
+        &'s self,
+        world: UnsafeWorldCell<'w>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> QueryChunkIter<'w, 's, D, F>
+    where
+        D: ContiguousQueryData,
+        F: ArchetypeFilter,
+    {
+        QueryChunkIter::new(world, self, last_run, this_run)
+    }
+
     /// Returns an [`Iterator`] for the given [`World`] and list of [`Entity`]'s, where the last change and
     /// the current change tick are given.
     ///
@@ -2,7 +2,7 @@ use crate::{
     entity::Entity,
     prelude::Mut,
     reflect::{AppTypeRegistry, ReflectBundle, ReflectComponent},
-    system::{EntityCommands, Resource},
+    system::{CommandError, CommandErrorHandler, CommandFailed, EntityCommands, Resource},
     world::{Command, World},
 };
 use alloc::borrow::Cow;
@@ -18,7 +18,7 @@ pub trait ReflectCommandExt {
     ///
     /// # Panics
     ///
-    /// - If the entity doesn't exist.
+    /// - If the entity doesn't exist, unless the [`CommandErrorHandler`] is configured otherwise.
     /// - If [`AppTypeRegistry`] does not have the reflection data for the given
     ///     [`Component`](crate::component::Component) or [`Bundle`](crate::bundle::Bundle).
     /// - If the component or bundle data is invalid. See [`PartialReflect::apply`] for further details.
@@ -222,7 +222,17 @@ fn insert_reflect(
         .expect("component should represent a type.");
     let type_path = type_info.type_path();
     let Ok(mut entity) = world.get_entity_mut(entity) else {
-        panic!("error[B0003]: Could not insert a reflected component (of type {type_path}) for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003");
+        CommandErrorHandler::handle(
+            world,
+            CommandFailed::new(
+                CommandError::no_such_entity(
+                    entity,
+                    format!("insert a reflected component (of type {type_path})"),
+                ),
+                None,
+            ),
+        );
+        return;
     };
     let Some(type_registration) = type_registry.get(type_info.type_id()) else {
         panic!("`{type_path}` should be registered in type registry via `App::register_type<{type_path}>`");
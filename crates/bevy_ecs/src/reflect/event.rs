@@ -0,0 +1,248 @@
+//! Definitions for [`Event`] reflection.
+//! This allows sending, triggering and reading events of types that are only known at runtime.
+//!
+//! This module exports two types: [`ReflectEventFns`] and [`ReflectEvent`].
+//!
+//! Same as [`super::component`], but for [`Event`]s.
+
+use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect, TypePath, TypeRegistry};
+
+use crate::{
+    entity::Entity,
+    event::{Event, Events},
+    world::World,
+};
+
+use super::from_reflect_with_fallback;
+
+/// A struct used to operate on reflected [`Event`] of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectEvent(ReflectEventFns);
+
+/// The raw function pointers needed to make up a [`ReflectEvent`].
+///
+/// This is used when creating custom implementations of [`ReflectEvent`] with
+/// [`ReflectEvent::new()`].
+///
+/// > **Note:**
+/// > Creating custom implementations of [`ReflectEvent`] is an advanced feature that most users
+/// > will not need.
+/// > Usually a [`ReflectEvent`] is created for a type by deriving [`Reflect`]
+/// > and adding the `#[reflect(Event)]` attribute.
+/// > After adding the event to the [`TypeRegistry`],
+/// > its [`ReflectEvent`] can then be retrieved when needed.
+#[derive(Clone)]
+pub struct ReflectEventFns {
+    /// Function pointer implementing [`ReflectEvent::send()`].
+    pub send: fn(&mut World, &dyn PartialReflect, &TypeRegistry) -> bool,
+    /// Function pointer implementing [`ReflectEvent::trigger()`].
+    pub trigger: fn(&mut World, &dyn PartialReflect, &[Entity], &TypeRegistry),
+    /// Function pointer implementing [`ReflectEvent::read()`].
+    pub read: for<'w> fn(&'w World, &mut Option<usize>) -> Option<Vec<&'w dyn Reflect>>,
+}
+
+impl ReflectEventFns {
+    /// Get the default set of [`ReflectEventFns`] for a specific event type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: Event + FromReflect + TypePath>() -> Self {
+        <ReflectEvent as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectEvent {
+    /// Sends a reflected [`Event`] into its [`Events`] resource, like
+    /// [`send_event()`](World::send_event).
+    ///
+    /// Returns `false` if the [`Events`] resource of this event type doesn't exist, which usually
+    /// means that the event hasn't been added to the app.
+    pub fn send(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> bool {
+        (self.0.send)(world, event, registry)
+    }
+
+    /// Triggers a reflected [`Event`] for the observers watching the given `targets`, like
+    /// [`trigger_targets()`](World::trigger_targets). If `targets` is empty, only the global
+    /// observers are triggered, like [`trigger()`](World::trigger).
+    pub fn trigger(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        targets: &[Entity],
+        registry: &TypeRegistry,
+    ) {
+        (self.0.trigger)(world, event, targets, registry);
+    }
+
+    /// Reads the events of this type that were sent since the last call with the same `cursor`,
+    /// as reflected references.
+    ///
+    /// A `cursor` of [`None`] skips all events that have been sent so far, so that only the
+    /// events sent after the first call are read. Returns [`None`] if the [`Events`] resource of
+    /// this event type doesn't exist.
+    pub fn read<'w>(
+        &self,
+        world: &'w World,
+        cursor: &mut Option<usize>,
+    ) -> Option<Vec<&'w dyn Reflect>> {
+        (self.0.read)(world, cursor)
+    }
+
+    /// Create a custom implementation of [`ReflectEvent`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`] and add the `#[reflect(Event)]` attribute
+    /// to generate a [`ReflectEvent`] implementation automatically.
+    ///
+    /// See [`ReflectEventFns`] for more information.
+    pub fn new(fns: ReflectEventFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectEvent`.
+    ///
+    /// This is useful when you want to keep track locally of an individual
+    /// function pointer.
+    ///
+    /// Calling [`TypeRegistry::get`] followed by
+    /// [`TypeRegistration::data::<ReflectEvent>`] can be costly if done several
+    /// times per frame. Consider cloning [`ReflectEvent`] and keeping it
+    /// between frames, cloning a `ReflectEvent` is very cheap.
+    ///
+    /// If you only need a subset of the methods on `ReflectEvent`,
+    /// use `fn_pointers` to get the underlying [`ReflectEventFns`]
+    /// and copy the subset of function pointers you care about.
+    ///
+    /// [`TypeRegistration::data::<ReflectEvent>`]: bevy_reflect::TypeRegistration::data
+    pub fn fn_pointers(&self) -> &ReflectEventFns {
+        &self.0
+    }
+}
+
+impl<E: Event + FromReflect + TypePath> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent(ReflectEventFns {
+            send: |world, reflected_event, registry| {
+                if !world.contains_resource::<Events<E>>() {
+                    return false;
+                }
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.resource_mut::<Events<E>>().send(event);
+                true
+            },
+            trigger: |world, reflected_event, targets, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                if targets.is_empty() {
+                    world.trigger(event);
+                } else {
+                    world.trigger_targets(event, targets.to_vec());
+                }
+            },
+            read: |world, cursor| {
+                let events = world.get_resource::<Events<E>>()?;
+                // Event ids are consecutive, and the newest event has the id `event_count - 1`.
+                let event_count = events.oldest_event_count() + events.len();
+                let start = cursor
+                    .unwrap_or(event_count)
+                    .max(events.oldest_event_count());
+                *cursor = Some(event_count);
+                Some(
+                    (start..event_count)
+                        .filter_map(|id| events.get_event(id))
+                        .map(|(event, _)| event as &dyn Reflect)
+                        .collect(),
+                )
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_ecs,
+        observer::Trigger,
+        reflect::AppTypeRegistry,
+        system::{ResMut, Resource},
+    };
+    use bevy_reflect::{DynamicTupleStruct, Reflect};
+
+    #[derive(Event, Reflect, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Damage(u32);
+
+    #[derive(Resource, Default)]
+    struct Received(Vec<(u32, Entity)>);
+
+    fn setup() -> (World, ReflectEvent) {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Damage>();
+        let reflect_event = registry
+            .read()
+            .get_type_data::<ReflectEvent>(core::any::TypeId::of::<Damage>())
+            .unwrap()
+            .clone();
+        world.insert_resource(registry);
+        (world, reflect_event)
+    }
+
+    #[test]
+    fn send_and_read_reflected_events() {
+        let (mut world, reflect_event) = setup();
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        assert!(!reflect_event.send(&mut world, &Damage(1), &registry));
+
+        world.init_resource::<Events<Damage>>();
+        world.send_event(Damage(1));
+        let mut cursor = None;
+        assert!(reflect_event.read(&world, &mut cursor).unwrap().is_empty());
+
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.insert(2u32);
+        assert!(reflect_event.send(&mut world, &dynamic, &registry));
+        assert!(reflect_event.send(&mut world, &Damage(3), &registry));
+        let read = reflect_event.read(&world, &mut cursor).unwrap();
+        let read: Vec<_> = read
+            .iter()
+            .map(|event| event.downcast_ref::<Damage>().unwrap())
+            .collect();
+        assert_eq!(read, [&Damage(2), &Damage(3)]);
+        assert!(reflect_event.read(&world, &mut cursor).unwrap().is_empty());
+    }
+
+    #[test]
+    fn trigger_reflected_events() {
+        let (mut world, reflect_event) = setup();
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        world.init_resource::<Received>();
+        world.add_observer(|trigger: Trigger<Damage>, mut received: ResMut<Received>| {
+            received.0.push((trigger.event().0, trigger.entity()));
+        });
+
+        let target = world.spawn_empty().id();
+        reflect_event.trigger(&mut world, &Damage(1), &[], &registry);
+        reflect_event.trigger(&mut world, &Damage(2), &[target], &registry);
+        world.flush();
+        assert_eq!(
+            world.resource::<Received>().0,
+            [(1, Entity::PLACEHOLDER), (2, target)]
+        );
+    }
+}
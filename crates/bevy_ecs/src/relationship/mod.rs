@@ -0,0 +1,428 @@
+//! This module provides functionality to link entities to each other using specialized components called "relationships". See the [`Relationship`] trait for more info.
+
+mod related_methods;
+mod relationship_source_collection;
+
+pub use relationship_source_collection::*;
+
+use crate::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    traversal::Traversal,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::warn;
+
+/// A [`Component`] on a "source" [`Entity`] that references another target [`Entity`], creating a "relationship" between them. Every [`Relationship`]
+/// has a corresponding [`RelationshipTarget`] type (and vice-versa), which exists on the "target" entity of a relationship and contains the list of all
+/// "source" entities that relate to the given "target".
+///
+/// The [`Relationship`] component is the "source of truth" and the [`RelationshipTarget`] component reflects that source of truth. When a [`Relationship`]
+/// component is inserted on an [`Entity`], the "source" entity is automatically added to the target entity's [`RelationshipTarget`] collection, and the
+/// [`RelationshipTarget`] component is inserted on the target (through [`Commands`](crate::system::Commands)) if it does not already exist.
+/// This is done via "component hooks".
+///
+/// When a [`Relationship`] component is removed (or its source entity is despawned), the source entity is removed from the target's collection, and the
+/// [`RelationshipTarget`] component is removed once it becomes empty. When a [`RelationshipTarget`] component is removed (or its target entity is despawned),
+/// the [`Relationship`] component is removed from every source entity.
+///
+/// A [`Relationship`] and [`RelationshipTarget`] can be declared with the [`Component`] derive:
+///
+/// ```
+/// # use bevy_ecs::{component::Component, entity::Entity};
+/// #[derive(Component)]
+/// #[relationship(relationship_target = Inventory)]
+/// pub struct HeldBy(pub Entity);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = HeldBy)]
+/// pub struct Inventory(Vec<Entity>);
+/// ```
+///
+/// The derive generates the trait implementations and registers the component hooks that keep both sides in sync.
+///
+/// # Mutation
+///
+/// Component hooks are not run when a component is mutated in place. To change the target of a [`Relationship`],
+/// insert a new value of the component instead of mutating it through [`Mut`](crate::change_detection::Mut).
+/// The [`RelationshipTarget`] collection must never be mutated directly.
+///
+/// # Traversal
+///
+/// `&R` implements [`Traversal`] for every [`Relationship`] `R`, which means relationships can be used to
+/// [propagate](crate::observer::Trigger::propagate) events from a source entity to its target.
+pub trait Relationship: Component + Sized {
+    /// The [`Component`] added to the "target" entities of this [`Relationship`], which contains the list of all "source"
+    /// entities that relate to the "target".
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] ID of the related entity.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] from the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if target_entity == entity {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+        if let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+            if let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            {
+                relationship_target.collection_mut_risky().add(entity);
+            } else {
+                // The target component is inserted through a command, so it is re-checked when the command is applied
+                // in case several sources were related to this target (or the relationship was removed) in the meantime.
+                world.commands().queue(move |world: &mut World| {
+                    if world.get::<Self>(entity).map(Self::get) != Some(target_entity) {
+                        return;
+                    }
+                    let Ok(mut target) = world.get_entity_mut(target_entity) else {
+                        return;
+                    };
+                    if let Some(mut relationship_target) =
+                        target.get_mut::<Self::RelationshipTarget>()
+                    {
+                        relationship_target.collection_mut_risky().add(entity);
+                    } else {
+                        let mut collection: <Self::RelationshipTarget as RelationshipTarget>::Collection =
+                            RelationshipSourceCollection::with_capacity(1);
+                        collection.add(entity);
+                        target.insert(Self::RelationshipTarget::from_collection_risky(collection));
+                    }
+                });
+            }
+        } else {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+        }
+    }
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+            return;
+        };
+        let Some(mut relationship_target) = target_entity_mut.get_mut::<Self::RelationshipTarget>()
+        else {
+            return;
+        };
+        relationship_target.collection_mut_risky().remove(entity);
+        if relationship_target.is_empty() {
+            world.commands().queue(move |world: &mut World| {
+                let Ok(mut target) = world.get_entity_mut(target_entity) else {
+                    return;
+                };
+                // This check exists in case the target has been related to again since this command was queued.
+                if target
+                    .get::<Self::RelationshipTarget>()
+                    .is_some_and(RelationshipTarget::is_empty)
+                {
+                    target.remove::<Self::RelationshipTarget>();
+                }
+            });
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the associated `Relationship` type.
+/// See the [`Relationship`] documentation for more information.
+pub trait RelationshipTarget: Component + Sized {
+    /// The [`Relationship`] that populates this [`RelationshipTarget`] collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// The collection type that stores the "source" entities for this [`RelationshipTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`RelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+
+    /// Returns a mutable reference to the stored [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called outside of the [`Relationship`] implementation because it can invalidate
+    /// the relationship's source of truth.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`RelationshipTarget`] from the given [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called outside of the [`Relationship`] implementation because it can invalidate
+    /// the relationship's source of truth.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    ///
+    /// This removes the [`Relationship`] from every "source" entity that still relates to this entity.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        world.commands().queue(move |world: &mut World| {
+            for source_entity in sources {
+                let Ok(mut source) = world.get_entity_mut(source_entity) else {
+                    continue;
+                };
+                // The source may have been related to another entity since this command was queued.
+                if source.get::<Self::Relationship>().map(Relationship::get) == Some(entity) {
+                    source.remove::<Self::Relationship>();
+                }
+            }
+        });
+    }
+
+    /// Iterates the entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.collection().iter()
+    }
+
+    /// Returns the number of entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this entity collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+}
+
+/// Relationships can be used to propagate [events](crate::event::Event) from their source entity to their target.
+///
+/// Traversing a relationship graph may loop if the relationships contain a cycle.
+impl<R: Relationship> Traversal for &R {
+    fn traverse(item: Self::Item<'_>) -> Option<Entity> {
+        Some(item.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::{Entity, EntityHashSet},
+        event::Event,
+        observer::Trigger,
+        relationship::{Relationship, RelationshipTarget},
+        system::{Commands, ResMut, Resource},
+        world::World,
+    };
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Inventory)]
+    struct HeldBy(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = HeldBy)]
+    struct Inventory(Vec<Entity>);
+
+    #[test]
+    fn relationship_target_is_maintained() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let a = world.spawn(HeldBy(owner)).id();
+        let b = world.spawn(HeldBy(owner)).id();
+        world.flush();
+
+        let inventory = world.get::<Inventory>(owner).unwrap();
+        assert_eq!(inventory.iter().collect::<Vec<_>>(), vec![a, b]);
+
+        world.entity_mut(a).remove::<HeldBy>();
+        world.flush();
+        let inventory = world.get::<Inventory>(owner).unwrap();
+        assert_eq!(inventory.iter().collect::<Vec<_>>(), vec![b]);
+
+        world.despawn(b);
+        world.flush();
+        assert!(world.get::<Inventory>(owner).is_none());
+    }
+
+    #[test]
+    fn relationship_can_be_replaced() {
+        let mut world = World::new();
+        let owner_a = world.spawn_empty().id();
+        let owner_b = world.spawn_empty().id();
+        let item = world.spawn(HeldBy(owner_a)).id();
+        world.flush();
+
+        world.entity_mut(item).insert(HeldBy(owner_b));
+        world.flush();
+
+        assert!(world.get::<Inventory>(owner_a).is_none());
+        assert_eq!(world.get::<Inventory>(owner_b).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn relationship_batched_before_flush() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        world.entity_mut(a).insert(HeldBy(owner));
+        world.entity_mut(b).insert(HeldBy(owner));
+        // `a` is unrelated before the target collection has been inserted.
+        world.entity_mut(a).remove::<HeldBy>();
+        world.flush();
+
+        let inventory = world.get::<Inventory>(owner).unwrap();
+        assert_eq!(inventory.iter().collect::<Vec<_>>(), vec![b]);
+    }
+
+    #[test]
+    fn despawning_target_removes_relationships() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let a = world.spawn(HeldBy(owner)).id();
+        let b = world.spawn(HeldBy(owner)).id();
+        world.flush();
+
+        world.despawn(owner);
+        world.flush();
+
+        assert!(world.get::<HeldBy>(a).is_none());
+        assert!(world.get::<HeldBy>(b).is_none());
+    }
+
+    #[test]
+    fn self_relationship_is_removed() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(HeldBy(a));
+        world.flush();
+
+        assert!(world.get::<HeldBy>(a).is_none());
+        assert!(world.get::<Inventory>(a).is_none());
+    }
+
+    #[test]
+    fn relationship_with_missing_target_is_removed() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        world.despawn(target);
+        let a = world.spawn(HeldBy(target)).id();
+        world.flush();
+
+        assert!(world.get::<HeldBy>(a).is_none());
+    }
+
+    #[test]
+    fn relationship_named_fields() {
+        #[derive(Component)]
+        #[relationship(relationship_target = Targets)]
+        struct Targeting {
+            target: Entity,
+        }
+
+        #[derive(Component)]
+        #[relationship_target(relationship = Targeting)]
+        struct Targets {
+            targeted_by: EntityHashSet,
+        }
+
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let turret = world.spawn(Targeting { target }).id();
+        world.flush();
+
+        let targets = world.get::<Targets>(target).unwrap();
+        assert!(targets.targeted_by.contains(&turret));
+        assert_eq!(world.get::<Targeting>(turret).unwrap().get(), target);
+    }
+
+    #[test]
+    fn relationship_traversal() {
+        #[derive(Component)]
+        struct Hit;
+
+        impl Event for Hit {
+            type Traversal = &'static HeldBy;
+
+            const AUTO_PROPAGATE: bool = true;
+        }
+
+        #[derive(Resource, Default)]
+        struct Hits(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Hits>();
+        let owner = world
+            .spawn_empty()
+            .observe(|trigger: Trigger<Hit>, mut hits: ResMut<Hits>| {
+                hits.0.push(trigger.entity());
+            })
+            .id();
+        let item = world
+            .spawn(HeldBy(owner))
+            .observe(|trigger: Trigger<Hit>, mut hits: ResMut<Hits>| {
+                hits.0.push(trigger.entity());
+            })
+            .id();
+        world.flush();
+
+        world.commands().trigger_targets(Hit, item);
+        world.flush();
+
+        assert_eq!(world.resource::<Hits>().0, vec![item, owner]);
+    }
+
+    #[test]
+    fn related_methods() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        world.entity_mut(owner).add_related::<HeldBy>(&[a, b]);
+        world.flush();
+        assert_eq!(world.get::<Inventory>(owner).unwrap().len(), 2);
+
+        let mut commands = world.commands();
+        commands.entity(owner).despawn_related::<Inventory>();
+        world.flush();
+
+        assert!(world.get_entity(a).is_err());
+        assert!(world.get_entity(b).is_err());
+        assert!(world.get_entity(owner).is_ok());
+        assert!(world.get::<Inventory>(owner).is_none());
+    }
+
+    #[test]
+    fn related_methods_with_commands() {
+        fn system(mut commands: Commands, owner: Entity) -> Entity {
+            let item = commands.spawn_empty().id();
+            commands.entity(owner).add_related::<HeldBy>(&[item]);
+            item
+        }
+
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let item = system(world.commands(), owner);
+        world.flush();
+
+        assert_eq!(
+            world
+                .get::<Inventory>(owner)
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![item]
+        );
+    }
+}
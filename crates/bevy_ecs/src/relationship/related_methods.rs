@@ -0,0 +1,60 @@
+use crate::{
+    entity::Entity,
+    relationship::{Relationship, RelationshipTarget},
+    system::EntityCommands,
+    world::{EntityWorldMut, World},
+};
+
+impl<'w> EntityWorldMut<'w> {
+    /// Relates the given entities to this entity with the relation `R`.
+    ///
+    /// See [`Relationship`] for more information.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        self.world_scope(|world| {
+            for related in related {
+                world.entity_mut(*related).insert(R::from(id));
+            }
+        });
+        self
+    }
+
+    /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
+    /// This entity will not be despawned.
+    pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
+        if let Some(sources) = self.take::<S>() {
+            self.world_scope(|world| {
+                for entity in sources.iter() {
+                    if let Ok(entity_mut) = world.get_entity_mut(entity) {
+                        entity_mut.despawn();
+                    }
+                }
+            });
+        }
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Relates the given entities to this entity with the relation `R`.
+    ///
+    /// See [`Relationship`] for more information.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        let related = related.to_vec();
+        self.commands().queue(move |world: &mut World| {
+            for related in related {
+                world.entity_mut(related).insert(R::from(id));
+            }
+        });
+        self
+    }
+
+    /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
+    /// This entity will not be despawned.
+    pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.despawn_related::<S>();
+        })
+    }
+}
@@ -0,0 +1,326 @@
+use alloc::borrow::Cow;
+use core::fmt::Write;
+
+use crate::schedule::NodeId;
+
+/// The format used by [`ScheduleGraphDescription::export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ScheduleGraphFormat {
+    /// A [Graphviz](https://graphviz.org/) DOT graph.
+    #[default]
+    Dot,
+    /// A JSON object listing all nodes and edges.
+    ///
+    /// Requires the `serialize` feature.
+    #[cfg(feature = "serialize")]
+    Json,
+}
+
+impl ScheduleGraphFormat {
+    /// The file extension usually used for this format, without a leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ScheduleGraphFormat::Dot => "dot",
+            #[cfg(feature = "serialize")]
+            ScheduleGraphFormat::Json => "json",
+        }
+    }
+}
+
+/// A snapshot of the systems, system sets and edges of a built [`Schedule`](super::Schedule).
+///
+/// Created by [`Schedule::describe_graph`](super::Schedule::describe_graph). Nodes and edges are
+/// sorted, so that the exported text of an unchanged schedule is stable and can be diffed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ScheduleGraphDescription {
+    /// The label of the schedule.
+    pub label: String,
+    /// The systems and system sets in the schedule.
+    pub nodes: Vec<ScheduleNodeDescription>,
+    /// Edges from system sets to the systems and sets they contain.
+    #[cfg_attr(feature = "serialize", serde(serialize_with = "serialize_edges"))]
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// Edges from systems and sets to the systems and sets that must run after them.
+    #[cfg_attr(feature = "serialize", serde(serialize_with = "serialize_edges"))]
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// Pairs of systems with conflicting data access and no ordering between them.
+    pub ambiguities: Vec<ScheduleAmbiguityDescription>,
+}
+
+/// A system or system set in a [`ScheduleGraphDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ScheduleNodeDescription {
+    /// The id of the node in the [`ScheduleGraph`](super::ScheduleGraph).
+    #[cfg_attr(feature = "serialize", serde(serialize_with = "serialize_node_id"))]
+    pub id: NodeId,
+    /// The name of the system or set.
+    pub name: String,
+    /// The names of the run conditions of the system or set.
+    pub conditions: Vec<String>,
+}
+
+/// An ambiguity between two systems in a [`ScheduleGraphDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ScheduleAmbiguityDescription {
+    /// The first system.
+    #[cfg_attr(feature = "serialize", serde(serialize_with = "serialize_node_id"))]
+    pub first: NodeId,
+    /// The second system.
+    #[cfg_attr(feature = "serialize", serde(serialize_with = "serialize_node_id"))]
+    pub second: NodeId,
+    /// The names of the components and resources both systems access.
+    ///
+    /// If this is empty, the systems conflict on [`World`](crate::world::World) access.
+    pub conflicts: Vec<String>,
+}
+
+impl ScheduleGraphDescription {
+    /// Renders this description in the given `format`.
+    pub fn export(&self, format: ScheduleGraphFormat) -> String {
+        match format {
+            ScheduleGraphFormat::Dot => self.to_dot(),
+            #[cfg(feature = "serialize")]
+            ScheduleGraphFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Renders this description as a [Graphviz](https://graphviz.org/) DOT graph.
+    ///
+    /// Systems are drawn as boxes and sets as dashed ellipses. Hierarchy edges are gray and
+    /// dashed, dependency edges are solid, and ambiguities are red dotted lines labelled with
+    /// the conflicting data.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph {} {{", dot_string(&self.label)).unwrap();
+        writeln!(dot, "\tnode [fontname=\"Helvetica\"];").unwrap();
+        for node in &self.nodes {
+            let mut label = node.name.clone();
+            if !node.conditions.is_empty() {
+                write!(label, "\nrun if: {}", node.conditions.join(", ")).unwrap();
+            }
+            let shape = if node.id.is_system() {
+                "shape=box"
+            } else {
+                "shape=ellipse, style=dashed"
+            };
+            writeln!(
+                dot,
+                "\t{} [label={}, {shape}];",
+                node_key(node.id),
+                dot_string(&label)
+            )
+            .unwrap();
+        }
+        for (set, member) in &self.hierarchy {
+            writeln!(
+                dot,
+                "\t{} -> {} [style=dashed, color=gray];",
+                node_key(*set),
+                node_key(*member)
+            )
+            .unwrap();
+        }
+        for (before, after) in &self.dependencies {
+            writeln!(dot, "\t{} -> {};", node_key(*before), node_key(*after)).unwrap();
+        }
+        for ambiguity in &self.ambiguities {
+            let label = if ambiguity.conflicts.is_empty() {
+                Cow::Borrowed("World")
+            } else {
+                Cow::Owned(ambiguity.conflicts.join(", "))
+            };
+            writeln!(
+                dot,
+                "\t{} -> {} [dir=none, style=dotted, color=red, label={}];",
+                node_key(ambiguity.first),
+                node_key(ambiguity.second),
+                dot_string(&label)
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders this description as a JSON object.
+    ///
+    /// The object has a `label` string and `nodes`, `hierarchy`, `dependencies` and `ambiguities`
+    /// arrays. Nodes are referred to by their `id`, such as `"system_3"` or `"set_0"`, and edges
+    /// are written as `[from, to]` pairs of ids.
+    #[cfg(feature = "serialize")]
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self)
+            .expect("schedule graph descriptions are always valid JSON");
+        json.push('\n');
+        json
+    }
+}
+
+#[cfg(feature = "serialize")]
+fn serialize_node_id<S: serde::Serializer>(id: &NodeId, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&node_key(*id))
+}
+
+#[cfg(feature = "serialize")]
+fn serialize_edges<S: serde::Serializer>(
+    edges: &[(NodeId, NodeId)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(
+        edges
+            .iter()
+            .map(|(from, to)| [node_key(*from), node_key(*to)]),
+    )
+}
+
+fn node_key(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system_{index}"),
+        NodeId::Set(index) => format!("set_{index}"),
+    }
+}
+
+fn dot_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_ecs,
+        schedule::{IntoSystemConfigs, IntoSystemSetConfigs, Schedule, ScheduleLabel, SystemSet},
+        system::{Res, ResMut, Resource},
+        world::World,
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    fn increment(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    fn read(_counter: Res<Counter>) {}
+
+    fn write_again(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    fn enabled() -> bool {
+        true
+    }
+
+    fn build() -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.configure_sets(Physics.run_if(enabled));
+        schedule.add_systems((increment, read.after(increment)).in_set(Physics));
+        schedule.add_systems(write_again);
+        schedule.initialize(&mut world).unwrap();
+        (world, schedule)
+    }
+
+    #[test]
+    fn describe_built_schedule() {
+        let (world, schedule) = build();
+        let description = schedule.describe_graph(world.components());
+
+        assert_eq!(description.label, "TestSchedule");
+        let names: Vec<_> = description
+            .nodes
+            .iter()
+            .filter(|node| node.id.is_system())
+            .map(|node| node.name.as_str())
+            .collect();
+        assert_eq!(names.len(), 3);
+        assert!(names.iter().any(|name| name.ends_with("increment")));
+
+        let physics = description
+            .nodes
+            .iter()
+            .find(|node| node.name == "Physics")
+            .unwrap();
+        assert_eq!(physics.conditions.len(), 1);
+        assert!(physics.conditions[0].ends_with("enabled"));
+        assert!(description
+            .hierarchy
+            .iter()
+            .any(|(set, _)| *set == physics.id));
+
+        // `write_again` is ambiguous with both systems in `Physics`.
+        assert_eq!(description.ambiguities.len(), 2);
+        assert!(description
+            .ambiguities
+            .iter()
+            .all(|ambiguity| ambiguity.conflicts[0].ends_with("Counter")));
+    }
+
+    #[test]
+    fn export_is_stable() {
+        let (world, schedule) = build();
+        let first = schedule.describe_graph(world.components());
+        let (world, schedule) = build();
+        let second = schedule.describe_graph(world.components());
+
+        assert_eq!(first.to_dot(), second.to_dot());
+
+        let dot = first.export(ScheduleGraphFormat::Dot);
+        assert!(dot.starts_with("digraph \"TestSchedule\" {"));
+        assert!(dot.contains("run if: "));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn export_json() {
+        let (world, schedule) = build();
+        let description = schedule.describe_graph(world.components());
+        let (world, schedule) = build();
+        assert_eq!(
+            description.to_json(),
+            schedule.describe_graph(world.components()).to_json()
+        );
+
+        let json = description.export(ScheduleGraphFormat::Json);
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(json["label"], "TestSchedule");
+        let physics = json["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|node| node["name"] == "Physics")
+            .unwrap();
+        assert!(physics["id"].as_str().unwrap().starts_with("set_"));
+        assert!(json["hierarchy"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|edge| edge[0] == physics["id"]));
+    }
+
+    #[test]
+    fn escape_strings() {
+        assert_eq!(dot_string("a \"b\"\nc"), "\"a \\\"b\\\"\\nc\"");
+    }
+}
@@ -3,6 +3,7 @@
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod graph_utils;
 #[allow(clippy::module_inception)]
 mod schedule;
@@ -10,7 +11,7 @@ mod set;
 mod stepping;
 
 use self::graph_utils::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{condition::*, config::*, executor::*, export::*, schedule::*, set::*};
 
 pub use self::graph_utils::NodeId;
 
@@ -140,7 +141,7 @@ mod tests {
 
             world.insert_resource(SystemOrder::default());
 
-            assert_eq!(world.resource::<SystemOrder>().0, vec![]);
+            assert!(world.resource::<SystemOrder>().0.is_empty());
 
             // modify the schedule after it's been initialized and test ordering with sets
             schedule.configure_sets(TestSet::A.after(named_system));
@@ -259,7 +260,7 @@ mod tests {
             );
 
             schedule.run(&mut world);
-            assert_eq!(world.resource::<SystemOrder>().0, vec![]);
+            assert!(world.resource::<SystemOrder>().0.is_empty());
 
             world.resource_mut::<RunConditionBool>().0 = true;
             schedule.run(&mut world);
@@ -305,7 +306,7 @@ mod tests {
             );
 
             schedule.run(&mut world);
-            assert_eq!(world.resource::<SystemOrder>().0, vec![]);
+            assert!(world.resource::<SystemOrder>().0.is_empty());
 
             world.resource_mut::<RunConditionBool>().0 = true;
             schedule.run(&mut world);
@@ -507,6 +507,164 @@ impl Schedule {
             self.executable.systems.len()
         }
     }
+
+    /// Describes the systems, system sets, hierarchy, dependencies, run conditions and ambiguities
+    /// of this schedule, so they can be exported to DOT or JSON.
+    ///
+    /// Edges and ambiguities reflect the last time the schedule was built, so this should be
+    /// called after [`Schedule::initialize`] or [`Schedule::run`]. Ambiguities are always
+    /// included, regardless of [`ScheduleBuildSettings::ambiguity_detection`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleGraphFormat;
+    /// # #[derive(bevy_ecs::schedule::ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// # struct Update;
+    /// fn a() {}
+    /// fn b() {}
+    ///
+    /// let mut world = World::new();
+    /// let mut schedule = Schedule::new(Update);
+    /// schedule.add_systems((a, b).chain());
+    /// schedule.initialize(&mut world).unwrap();
+    ///
+    /// let dot = schedule
+    ///     .describe_graph(world.components())
+    ///     .export(ScheduleGraphFormat::Dot);
+    /// ```
+    pub fn describe_graph(&self, components: &Components) -> ScheduleGraphDescription {
+        let graph = &self.graph;
+
+        // Built systems and their conditions are moved into the executable schedule.
+        let mut systems: HashMap<NodeId, (&BoxedSystem, &[BoxedCondition])> = graph
+            .systems
+            .iter()
+            .zip(&graph.system_conditions)
+            .enumerate()
+            .filter_map(|(i, (node, conditions))| {
+                Some((NodeId::System(i), (node.get()?, conditions.as_slice())))
+            })
+            .collect();
+        systems.extend(
+            self.executable
+                .system_ids
+                .iter()
+                .zip(&self.executable.systems)
+                .zip(&self.executable.system_conditions)
+                .map(|((&id, system), conditions)| (id, (system, conditions.as_slice()))),
+        );
+        let mut set_conditions: HashMap<NodeId, &[BoxedCondition]> = graph
+            .system_set_conditions
+            .iter()
+            .enumerate()
+            .map(|(i, conditions)| (NodeId::Set(i), conditions.as_slice()))
+            .collect();
+        for (&id, conditions) in self
+            .executable
+            .set_ids
+            .iter()
+            .zip(&self.executable.set_conditions)
+        {
+            set_conditions.insert(id, conditions.as_slice());
+        }
+
+        fn node_name(
+            graph: &ScheduleGraph,
+            systems: &HashMap<NodeId, (&BoxedSystem, &[BoxedCondition])>,
+            id: NodeId,
+        ) -> String {
+            match id {
+                NodeId::System(_) => systems
+                    .get(&id)
+                    .map(|(system, _)| system.name().to_string())
+                    .unwrap_or_default(),
+                NodeId::Set(index) => {
+                    let set = &graph.system_sets[index];
+                    if !set.is_anonymous() {
+                        return set.name();
+                    }
+                    let members: Vec<_> = graph
+                        .hierarchy
+                        .graph
+                        .neighbors_directed(id, Outgoing)
+                        .map(|member| node_name(graph, systems, member))
+                        .collect();
+                    format!("({})", members.join(", "))
+                }
+            }
+        }
+
+        let condition_names = |conditions: &[BoxedCondition]| {
+            conditions
+                .iter()
+                .map(|condition| condition.name().to_string())
+                .collect()
+        };
+
+        let mut nodes: Vec<_> = systems
+            .iter()
+            .map(|(&id, (_, conditions))| ScheduleNodeDescription {
+                id,
+                name: node_name(graph, &systems, id),
+                conditions: condition_names(conditions),
+            })
+            .chain((0..graph.system_sets.len()).map(|i| {
+                let id = NodeId::Set(i);
+                ScheduleNodeDescription {
+                    id,
+                    name: node_name(graph, &systems, id),
+                    conditions: set_conditions
+                        .get(&id)
+                        .map(|conditions| condition_names(conditions))
+                        .unwrap_or_default(),
+                }
+            }))
+            .collect();
+        nodes.sort_by_key(|node| node.id);
+
+        let mut hierarchy: Vec<_> = graph
+            .hierarchy
+            .graph
+            .all_edges()
+            .map(|(set, member, _)| (set, member))
+            .collect();
+        hierarchy.sort();
+        let mut dependencies: Vec<_> = graph
+            .dependency
+            .graph
+            .all_edges()
+            .map(|(before, after, _)| (before, after))
+            .collect();
+        dependencies.sort();
+
+        let mut ambiguities: Vec<_> = graph
+            .conflicting_systems
+            .iter()
+            .map(|(a, b, conflicts)| {
+                let (first, second) = if a <= b { (*a, *b) } else { (*b, *a) };
+                let mut conflicts: Vec<_> = conflicts
+                    .iter()
+                    .filter_map(|id| components.get_name(*id))
+                    .map(ToString::to_string)
+                    .collect();
+                conflicts.sort();
+                ScheduleAmbiguityDescription {
+                    first,
+                    second,
+                    conflicts,
+                }
+            })
+            .collect();
+        ambiguities.sort_by_key(|ambiguity| (ambiguity.first, ambiguity.second));
+
+        ScheduleGraphDescription {
+            label: format!("{:?}", self.label),
+            nodes,
+            hierarchy,
+            dependencies,
+            ambiguities,
+        }
+    }
 }
 
 /// A directed acyclic graph structure.
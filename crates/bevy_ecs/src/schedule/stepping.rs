@@ -224,6 +224,11 @@ impl Stepping {
         self
     }
 
+    /// Check if stepping can be enabled, which requires the `bevy_debug_stepping` feature
+    pub const fn is_available() -> bool {
+        cfg!(feature = "bevy_debug_stepping")
+    }
+
     /// Check if stepping is enabled
     pub fn is_enabled(&self) -> bool {
         self.action != Action::RunAll
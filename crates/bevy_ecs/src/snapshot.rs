@@ -0,0 +1,739 @@
+//! Cheap snapshots of a subset of the [`World`], for rollback and similar use cases.
+//!
+//! A [`SnapshotRegistry`] lists the components and resources that should be captured. Calling
+//! [`SnapshotRegistry::capture`] copies their current values into a [`Snapshot`], which stores one
+//! compact column per component type instead of one allocation per entity.
+//! [`Snapshot::restore`] writes those values back into the world in place: entities that were
+//! spawned since the snapshot are despawned, entities that were despawned are spawned again and
+//! components and resources are only re-inserted if their value actually differs. The returned
+//! [`SnapshotRestoreReport`] describes what changed.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::snapshot::SnapshotRegistry;
+//! #[derive(Component, Clone, PartialEq)]
+//! struct Position(f32);
+//!
+//! #[derive(Resource, Clone, PartialEq)]
+//! struct Tick(u32);
+//!
+//! let mut registry = SnapshotRegistry::default();
+//! registry
+//!     .register_component::<Position>()
+//!     .register_resource::<Tick>();
+//!
+//! let mut world = World::new();
+//! world.insert_resource(Tick(0));
+//! let player = world.spawn(Position(0.0)).id();
+//! let snapshot = registry.capture(&world);
+//!
+//! // Predict a few ticks ahead...
+//! world.resource_mut::<Tick>().0 += 3;
+//! world.get_mut::<Position>(player).unwrap().0 = 3.0;
+//! world.spawn(Position(10.0));
+//!
+//! // ...and roll back on misprediction.
+//! let report = snapshot.restore(&mut world);
+//! assert!(report.changed.contains(&player));
+//! assert_eq!(report.despawned.len(), 1);
+//! assert_eq!(world.resource::<Tick>().0, 0);
+//! ```
+//!
+//! # Tracked entities
+//!
+//! Only entities that have at least one registered component are part of a snapshot. Restoring
+//! despawns every entity that currently has a registered component but isn't in the snapshot,
+//! regardless of its other components.
+//!
+//! Restoring goes through the regular insert, remove and despawn paths, so hooks and observers
+//! run as usual. Entities that were despawned are spawned again with a new id, which is recorded
+//! in [`SnapshotRestoreReport::entity_map`]. Use [`Snapshot::restore_with_entity_map`] to keep
+//! that mapping across restores. Entity references stored inside restored components are not
+//! remapped.
+
+use alloc::sync::Arc;
+use core::{any::TypeId, fmt::Debug};
+
+use crate::{
+    self as bevy_ecs,
+    archetype::ArchetypeEntity,
+    component::{Component, ComponentId},
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    system::Resource,
+    world::World,
+};
+
+/// The components and resources that are recorded in a [`Snapshot`].
+///
+/// This can be stored in the world as a [`Resource`], but doesn't have to be.
+/// See the [module docs](crate::snapshot) for more information.
+#[derive(Resource, Default, Clone)]
+pub struct SnapshotRegistry {
+    components: Vec<(TypeId, Arc<dyn SnapshotComponent>)>,
+    resources: Vec<(TypeId, Arc<dyn SnapshotResource>)>,
+}
+
+impl Debug for SnapshotRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SnapshotRegistry")
+            .field("components", &self.components.len())
+            .field("resources", &self.resources.len())
+            .finish()
+    }
+}
+
+impl SnapshotRegistry {
+    /// Records the component `C` in snapshots, copying it with [`Clone`].
+    ///
+    /// Restoring only re-inserts the component on entities where it isn't equal to the recorded
+    /// value. Registering the same component twice does nothing.
+    pub fn register_component<C: Component + Clone + PartialEq>(&mut self) -> &mut Self {
+        self.add_component(TypeId::of::<C>(), Arc::new(TypedComponent::<C>::default()))
+    }
+
+    /// Records the resource `R` in snapshots, copying it with [`Clone`].
+    ///
+    /// Restoring only re-inserts the resource if it isn't equal to the recorded value, and removes
+    /// it if it didn't exist when the snapshot was captured. Registering the same resource twice
+    /// does nothing.
+    pub fn register_resource<R: Resource + Clone + PartialEq>(&mut self) -> &mut Self {
+        let type_id = TypeId::of::<R>();
+        if !self.resources.iter().any(|(id, _)| *id == type_id) {
+            self.resources
+                .push((type_id, Arc::new(TypedResource::<R>::default())));
+        }
+        self
+    }
+
+    /// Records the component with the given [`TypeId`] in snapshots, copying it through reflection.
+    ///
+    /// This is useful for components that don't implement [`Clone`]. The component must be
+    /// registered with [`ReflectComponent`](crate::reflect::ReflectComponent) in the world's
+    /// [`AppTypeRegistry`](crate::reflect::AppTypeRegistry) when capturing and restoring, and is
+    /// skipped otherwise. Values are compared with
+    /// [`reflect_partial_eq`](bevy_reflect::PartialReflect::reflect_partial_eq) when restoring.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_reflect_component(&mut self, type_id: TypeId) -> &mut Self {
+        self.add_component(type_id, Arc::new(ReflectedComponent(type_id)))
+    }
+
+    fn add_component(
+        &mut self,
+        type_id: TypeId,
+        component: Arc<dyn SnapshotComponent>,
+    ) -> &mut Self {
+        if !self.components.iter().any(|(id, _)| *id == type_id) {
+            self.components.push((type_id, component));
+        }
+        self
+    }
+
+    /// Returns `true` if no components or resources are registered.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.resources.is_empty()
+    }
+
+    /// Captures the current value of every registered component and resource in `world`.
+    pub fn capture(&self, world: &World) -> Snapshot {
+        let columns: Vec<_> = self
+            .components
+            .iter()
+            .filter_map(|(_, component)| component.capture(world))
+            .collect();
+
+        let mut seen = EntityHashSet::default();
+        let mut entities = Vec::new();
+        for column in &columns {
+            for &entity in column.entities() {
+                if seen.insert(entity) {
+                    entities.push(entity);
+                }
+            }
+        }
+
+        Snapshot {
+            entities,
+            columns,
+            resources: self
+                .resources
+                .iter()
+                .map(|(_, resource)| resource.capture(world))
+                .collect(),
+        }
+    }
+}
+
+/// The recorded state of the components and resources in a [`SnapshotRegistry`].
+///
+/// Created by [`SnapshotRegistry::capture`]. See the [module docs](crate::snapshot) for more
+/// information.
+pub struct Snapshot {
+    entities: Vec<Entity>,
+    columns: Vec<Box<dyn ComponentColumn>>,
+    resources: Vec<Box<dyn ResourceSlot>>,
+}
+
+impl Debug for Snapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("entities", &self.entities)
+            .field("components", &self.columns.len())
+            .field("resources", &self.resources.len())
+            .finish()
+    }
+}
+
+impl Snapshot {
+    /// Returns the entities recorded in this snapshot.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    /// Returns `true` if `entity` is recorded in this snapshot.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains(&entity)
+    }
+
+    /// Writes the recorded components and resources back into `world`.
+    ///
+    /// See the [module docs](crate::snapshot) for how entities are spawned and despawned.
+    pub fn restore(&self, world: &mut World) -> SnapshotRestoreReport {
+        self.restore_with_entity_map(world, &mut EntityHashMap::default())
+    }
+
+    /// Writes the recorded components and resources back into `world`, using and updating
+    /// `entity_map` to find the entities that recorded entities were spawned as.
+    ///
+    /// Passing the same map to every restore of a snapshot means that entities spawned by
+    /// one restore are reused by the next, instead of being despawned and spawned again.
+    pub fn restore_with_entity_map(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> SnapshotRestoreReport {
+        let mut report = SnapshotRestoreReport::default();
+
+        // Spawn entities that were despawned after the snapshot was captured.
+        for &entity in &self.entities {
+            let target = entity_map.get(&entity).copied().unwrap_or(entity);
+            if world.get_entity_mut(target).is_err() {
+                let spawned = world.spawn_empty().id();
+                entity_map.insert(entity, spawned);
+                report.spawned.insert(spawned);
+            }
+        }
+        for &entity in &self.entities {
+            if let Some(&target) = entity_map.get(&entity) {
+                report.entity_map.insert(entity, target);
+            }
+        }
+        let recorded: EntityHashSet = self
+            .entities
+            .iter()
+            .map(|&entity| report.map_entity(entity))
+            .collect();
+
+        // Despawn tracked entities that were spawned after the snapshot was captured.
+        let mut tracked = EntityHashSet::default();
+        for column in &self.columns {
+            if let Some(id) = column.component_id(world) {
+                collect_entities_with(world, id, &mut tracked);
+            }
+        }
+        for entity in tracked {
+            if !recorded.contains(&entity) && world.despawn(entity) {
+                report.despawned.insert(entity);
+            }
+        }
+
+        for column in &self.columns {
+            column.restore(world, &recorded, &mut report);
+        }
+
+        for resource in &self.resources {
+            if let Some(id) = resource.restore(world) {
+                report.changed_resources.push(id);
+            }
+        }
+
+        report
+    }
+}
+
+/// What [`Snapshot::restore`] changed in the world.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SnapshotRestoreReport {
+    /// Entities that were spawned because they existed when the snapshot was captured.
+    pub spawned: EntityHashSet,
+    /// Entities that were despawned because they didn't exist when the snapshot was captured.
+    pub despawned: EntityHashSet,
+    /// Entities that had registered components inserted or removed, including spawned ones.
+    pub changed: EntityHashSet,
+    /// Recorded entities that live under a different id in the world, mapped to that id.
+    pub entity_map: EntityHashMap<Entity>,
+    /// The resources that were inserted, replaced or removed.
+    pub changed_resources: Vec<ComponentId>,
+}
+
+impl SnapshotRestoreReport {
+    /// Returns `true` if restoring didn't change anything.
+    pub fn is_empty(&self) -> bool {
+        self.spawned.is_empty()
+            && self.despawned.is_empty()
+            && self.changed.is_empty()
+            && self.changed_resources.is_empty()
+    }
+
+    /// Returns the entity that `entity` from the snapshot corresponds to in the world.
+    pub fn map_entity(&self, entity: Entity) -> Entity {
+        self.entity_map.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+fn collect_entities_with(world: &World, id: ComponentId, entities: &mut EntityHashSet) {
+    for archetype in world.archetypes().iter() {
+        if archetype.contains(id) {
+            entities.extend(archetype.entities().iter().map(ArchetypeEntity::id));
+        }
+    }
+}
+
+/// Captures one kind of component into a [`ComponentColumn`].
+trait SnapshotComponent: Send + Sync {
+    fn capture(&self, world: &World) -> Option<Box<dyn ComponentColumn>>;
+}
+
+/// The recorded values of one kind of component.
+trait ComponentColumn: Send + Sync {
+    fn entities(&self) -> &[Entity];
+
+    fn component_id(&self, world: &World) -> Option<ComponentId>;
+
+    fn restore(
+        &self,
+        world: &mut World,
+        recorded: &EntityHashSet,
+        report: &mut SnapshotRestoreReport,
+    );
+}
+
+/// Captures one kind of resource into a [`ResourceSlot`].
+trait SnapshotResource: Send + Sync {
+    fn capture(&self, world: &World) -> Box<dyn ResourceSlot>;
+}
+
+/// The recorded value of one kind of resource.
+trait ResourceSlot: Send + Sync {
+    /// Restores the resource, returning its id if it changed.
+    fn restore(&self, world: &mut World) -> Option<ComponentId>;
+}
+
+struct TypedComponent<C>(core::marker::PhantomData<fn() -> C>);
+
+impl<C> Default for TypedComponent<C> {
+    fn default() -> Self {
+        Self(core::marker::PhantomData)
+    }
+}
+
+impl<C: Component + Clone + PartialEq> SnapshotComponent for TypedComponent<C> {
+    fn capture(&self, world: &World) -> Option<Box<dyn ComponentColumn>> {
+        let mut column = TypedColumn::<C> {
+            entities: Vec::new(),
+            values: Vec::new(),
+        };
+        if let Some(id) = world.component_id::<C>() {
+            for archetype in world.archetypes().iter() {
+                if !archetype.contains(id) {
+                    continue;
+                }
+                for archetype_entity in archetype.entities() {
+                    let entity = archetype_entity.id();
+                    if let Some(value) = world.get::<C>(entity) {
+                        column.entities.push(entity);
+                        column.values.push(value.clone());
+                    }
+                }
+            }
+        }
+        Some(Box::new(column))
+    }
+}
+
+struct TypedColumn<C> {
+    entities: Vec<Entity>,
+    values: Vec<C>,
+}
+
+impl<C: Component + Clone + PartialEq> ComponentColumn for TypedColumn<C> {
+    fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    fn component_id(&self, world: &World) -> Option<ComponentId> {
+        world.component_id::<C>()
+    }
+
+    fn restore(
+        &self,
+        world: &mut World,
+        recorded: &EntityHashSet,
+        report: &mut SnapshotRestoreReport,
+    ) {
+        if let Some(id) = world.component_id::<C>() {
+            let mut current = EntityHashSet::default();
+            collect_entities_with(world, id, &mut current);
+            let has_value: EntityHashSet = self
+                .entities
+                .iter()
+                .map(|&entity| report.map_entity(entity))
+                .collect();
+            for entity in current {
+                if recorded.contains(&entity) && !has_value.contains(&entity) {
+                    world.entity_mut(entity).remove::<C>();
+                    report.changed.insert(entity);
+                }
+            }
+        }
+
+        for (&entity, value) in self.entities.iter().zip(&self.values) {
+            let entity = report.map_entity(entity);
+            if world.get::<C>(entity) != Some(value) {
+                world.entity_mut(entity).insert(value.clone());
+                report.changed.insert(entity);
+            }
+        }
+    }
+}
+
+struct TypedResource<R>(core::marker::PhantomData<fn() -> R>);
+
+impl<R> Default for TypedResource<R> {
+    fn default() -> Self {
+        Self(core::marker::PhantomData)
+    }
+}
+
+impl<R: Resource + Clone + PartialEq> SnapshotResource for TypedResource<R> {
+    fn capture(&self, world: &World) -> Box<dyn ResourceSlot> {
+        Box::new(TypedResourceSlot(world.get_resource::<R>().cloned()))
+    }
+}
+
+struct TypedResourceSlot<R>(Option<R>);
+
+impl<R: Resource + Clone + PartialEq> ResourceSlot for TypedResourceSlot<R> {
+    fn restore(&self, world: &mut World) -> Option<ComponentId> {
+        match (world.get_resource::<R>(), &self.0) {
+            (Some(current), Some(value)) if current == value => return None,
+            (_, Some(value)) => world.insert_resource(value.clone()),
+            (Some(_), None) => {
+                world.remove_resource::<R>();
+            }
+            (None, None) => return None,
+        }
+        world.components().resource_id::<R>()
+    }
+}
+
+#[cfg(feature = "bevy_reflect")]
+struct ReflectedComponent(TypeId);
+
+#[cfg(feature = "bevy_reflect")]
+impl ReflectedComponent {
+    fn reflect_component(
+        world: &World,
+        type_id: TypeId,
+    ) -> Option<(
+        crate::reflect::AppTypeRegistry,
+        crate::reflect::ReflectComponent,
+    )> {
+        let registry = world.get_resource::<crate::reflect::AppTypeRegistry>()?;
+        let reflect_component = registry
+            .read()
+            .get_type_data::<crate::reflect::ReflectComponent>(type_id)?
+            .clone();
+        Some((registry.clone(), reflect_component))
+    }
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl SnapshotComponent for ReflectedComponent {
+    fn capture(&self, world: &World) -> Option<Box<dyn ComponentColumn>> {
+        let (_, reflect_component) = Self::reflect_component(world, self.0)?;
+        let mut column = ReflectedColumn {
+            type_id: self.0,
+            entities: Vec::new(),
+            values: Vec::new(),
+        };
+        if let Some(id) = world.components().get_id(self.0) {
+            for archetype in world.archetypes().iter() {
+                if !archetype.contains(id) {
+                    continue;
+                }
+                for archetype_entity in archetype.entities() {
+                    let entity = archetype_entity.id();
+                    if let Some(value) = reflect_component.reflect(world.entity(entity)) {
+                        column.entities.push(entity);
+                        column.values.push(value.clone_value());
+                    }
+                }
+            }
+        }
+        Some(Box::new(column))
+    }
+}
+
+#[cfg(feature = "bevy_reflect")]
+struct ReflectedColumn {
+    type_id: TypeId,
+    entities: Vec<Entity>,
+    values: Vec<Box<dyn bevy_reflect::PartialReflect>>,
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl ComponentColumn for ReflectedColumn {
+    fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    fn component_id(&self, world: &World) -> Option<ComponentId> {
+        world.components().get_id(self.type_id)
+    }
+
+    fn restore(
+        &self,
+        world: &mut World,
+        recorded: &EntityHashSet,
+        report: &mut SnapshotRestoreReport,
+    ) {
+        let Some((registry, reflect_component)) =
+            ReflectedComponent::reflect_component(world, self.type_id)
+        else {
+            return;
+        };
+
+        if let Some(id) = self.component_id(world) {
+            let mut current = EntityHashSet::default();
+            collect_entities_with(world, id, &mut current);
+            let has_value: EntityHashSet = self
+                .entities
+                .iter()
+                .map(|&entity| report.map_entity(entity))
+                .collect();
+            for entity in current {
+                if recorded.contains(&entity) && !has_value.contains(&entity) {
+                    reflect_component.remove(&mut world.entity_mut(entity));
+                    report.changed.insert(entity);
+                }
+            }
+        }
+
+        let registry = registry.read();
+        for (&entity, value) in self.entities.iter().zip(&self.values) {
+            let entity = report.map_entity(entity);
+            let unchanged = reflect_component
+                .reflect(world.entity(entity))
+                .and_then(|current| current.reflect_partial_eq(value.as_ref()))
+                .unwrap_or(false);
+            if !unchanged {
+                reflect_component.apply_or_insert(
+                    &mut world.entity_mut(entity),
+                    value.as_ref(),
+                    &registry,
+                );
+                report.changed.insert(entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::DeferredWorld;
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Position(i32);
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Velocity(i32);
+
+    #[derive(Component)]
+    struct Untracked;
+
+    #[derive(Resource, Clone, PartialEq, Debug)]
+    struct Tick(u32);
+
+    fn registry() -> SnapshotRegistry {
+        let mut registry = SnapshotRegistry::default();
+        registry
+            .register_component::<Position>()
+            .register_component::<Velocity>()
+            .register_resource::<Tick>();
+        registry
+    }
+
+    #[test]
+    fn restore_values() {
+        let mut world = World::new();
+        world.insert_resource(Tick(1));
+        let a = world.spawn((Position(0), Velocity(1))).id();
+        let b = world.spawn(Position(5)).id();
+
+        let snapshot = registry().capture(&world);
+        assert_eq!(snapshot.entities(), &[a, b]);
+
+        world.get_mut::<Position>(a).unwrap().0 = 1;
+        world.entity_mut(b).insert(Velocity(2));
+        world.resource_mut::<Tick>().0 = 2;
+
+        let report = snapshot.restore(&mut world);
+        assert_eq!(world.get::<Position>(a), Some(&Position(0)));
+        assert_eq!(world.get::<Velocity>(b), None);
+        assert_eq!(world.resource::<Tick>(), &Tick(1));
+        assert_eq!(report.changed, [a, b].into_iter().collect());
+        assert_eq!(
+            report.changed_resources,
+            vec![world.components().resource_id::<Tick>().unwrap()]
+        );
+
+        // Restoring again changes nothing.
+        assert!(snapshot.restore(&mut world).is_empty());
+    }
+
+    #[test]
+    fn restore_spawns_and_despawns() {
+        let mut world = World::new();
+        let a = world.spawn((Position(0), Untracked)).id();
+        let untracked = world.spawn(Untracked).id();
+
+        let snapshot = registry().capture(&world);
+        assert!(!snapshot.contains(untracked));
+
+        world.despawn(a);
+        let b = world.spawn(Position(1)).id();
+
+        let report = snapshot.restore(&mut world);
+        let restored = report.map_entity(a);
+        assert_ne!(restored, a);
+        assert_eq!(report.despawned, [b].into_iter().collect());
+        assert_eq!(report.spawned, [restored].into_iter().collect());
+        assert!(!world.entities().contains(b));
+        assert!(world.entities().contains(untracked));
+        assert_eq!(world.get::<Position>(restored), Some(&Position(0)));
+        // Components that aren't registered are not restored.
+        assert!(world.get::<Untracked>(restored).is_none());
+    }
+
+    #[test]
+    fn restore_with_entity_map_reuses_spawned_entities() {
+        let mut world = World::new();
+        let a = world.spawn(Position(0)).id();
+        let snapshot = registry().capture(&world);
+        world.despawn(a);
+
+        let mut entity_map = EntityHashMap::default();
+        let report = snapshot.restore_with_entity_map(&mut world, &mut entity_map);
+        let restored = report.map_entity(a);
+        assert_eq!(entity_map.get(&a), Some(&restored));
+
+        world.get_mut::<Position>(restored).unwrap().0 = 4;
+        let report = snapshot.restore_with_entity_map(&mut world, &mut entity_map);
+        assert!(report.spawned.is_empty());
+        assert!(report.despawned.is_empty());
+        assert_eq!(report.changed, [restored].into_iter().collect());
+        assert_eq!(world.get::<Position>(restored), Some(&Position(0)));
+    }
+
+    #[test]
+    fn restore_remaps_reused_entities() {
+        let mut world = World::new();
+        let a = world.spawn(Position(0)).id();
+        let snapshot = registry().capture(&world);
+
+        world.despawn(a);
+        // Reuses the index of `a` with a new generation, but isn't tracked.
+        let reused = world.spawn(Untracked).id();
+        assert_eq!(reused.index(), a.index());
+
+        let report = snapshot.restore(&mut world);
+        let restored = report.map_entity(a);
+        assert_ne!(restored, a);
+        assert_eq!(world.get::<Position>(restored), Some(&Position(0)));
+        assert!(world.entities().contains(reused));
+    }
+
+    #[test]
+    fn restore_removes_missing_resources() {
+        let mut world = World::new();
+        let snapshot = registry().capture(&world);
+        world.insert_resource(Tick(3));
+
+        let report = snapshot.restore(&mut world);
+        assert!(!world.contains_resource::<Tick>());
+        assert_eq!(report.changed_resources.len(), 1);
+    }
+
+    #[test]
+    fn restore_runs_hooks() {
+        #[derive(Resource, Default)]
+        struct Inserted(u32);
+
+        #[derive(Component, Clone, PartialEq)]
+        #[component(on_insert = count_inserts)]
+        struct Hooked(u32);
+
+        fn count_inserts(mut world: DeferredWorld, _: Entity, _: ComponentId) {
+            world.resource_mut::<Inserted>().0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Inserted>();
+        let mut registry = SnapshotRegistry::default();
+        registry.register_component::<Hooked>();
+
+        let entity = world.spawn(Hooked(0)).id();
+        world.spawn(Hooked(1));
+        let snapshot = registry.capture(&world);
+        world.entity_mut(entity).insert(Hooked(2));
+        assert_eq!(world.resource::<Inserted>().0, 3);
+
+        snapshot.restore(&mut world);
+        // Only the changed entity is re-inserted.
+        assert_eq!(world.resource::<Inserted>().0, 4);
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn restore_reflected_components() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component, PartialEq)]
+        struct Health(u32);
+
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Health>();
+        world.insert_resource(type_registry);
+
+        let mut registry = SnapshotRegistry::default();
+        registry.register_reflect_component(TypeId::of::<Health>());
+
+        let a = world.spawn(Health(10)).id();
+        let b = world.spawn(Health(10)).id();
+        let snapshot = registry.capture(&world);
+
+        world.get_mut::<Health>(a).unwrap().0 = 3;
+        world.entity_mut(b).remove::<Health>();
+        let c = world.spawn(Health(1)).id();
+
+        let report = snapshot.restore(&mut world);
+        assert_eq!(world.get::<Health>(a), Some(&Health(10)));
+        assert_eq!(world.get::<Health>(b), Some(&Health(10)));
+        assert_eq!(report.changed, [a, b].into_iter().collect());
+        assert_eq!(report.despawned, [c].into_iter().collect());
+    }
+}
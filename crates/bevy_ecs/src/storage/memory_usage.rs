@@ -0,0 +1,389 @@
+use core::{cell::UnsafeCell, mem::size_of};
+
+use bevy_utils::HashMap;
+
+use crate::{
+    archetype::{ArchetypeEntity, ArchetypeId},
+    component::{ComponentId, Tick},
+    entity::Entity,
+    storage::TableId,
+    world::World,
+};
+
+/// The number of bytes used for change detection for every stored component value.
+const TICK_BYTES_PER_ROW: usize = {
+    let bytes = 2 * size_of::<UnsafeCell<Tick>>();
+    #[cfg(feature = "track_change_detection")]
+    let bytes = bytes + size_of::<&'static core::panic::Location<'static>>();
+    bytes
+};
+
+/// The memory used by a single [`ThinColumn`](super::ThinColumn) or the dense storage of a
+/// [`ComponentSparseSet`](super::ComponentSparseSet), including change detection ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnMemoryUsage {
+    /// The component stored in the column.
+    pub component_id: ComponentId,
+    /// The size of a single component value, in bytes.
+    pub item_size: usize,
+    /// The number of component values stored in the column.
+    pub len: usize,
+    /// The number of component values the column can store without reallocating.
+    pub capacity: usize,
+}
+
+impl ColumnMemoryUsage {
+    /// The number of bytes stored for each component value, including change detection ticks.
+    pub fn row_size(&self) -> usize {
+        self.item_size + TICK_BYTES_PER_ROW
+    }
+
+    /// The number of bytes allocated by the column.
+    pub fn allocated_bytes(&self) -> usize {
+        self.capacity * self.row_size()
+    }
+
+    /// The number of allocated bytes that hold component values.
+    pub fn used_bytes(&self) -> usize {
+        self.len * self.row_size()
+    }
+}
+
+/// The memory used by a [`Table`](super::Table).
+///
+/// Returned by [`Table::memory_usage`](super::Table::memory_usage).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableMemoryUsage {
+    /// The number of entities stored in the table.
+    pub entity_count: usize,
+    /// The number of entities the table can store without reallocating.
+    pub entity_capacity: usize,
+    /// The memory used by each column of the table.
+    pub columns: Vec<ColumnMemoryUsage>,
+}
+
+impl TableMemoryUsage {
+    /// The number of bytes allocated by the table, including its list of entities.
+    pub fn allocated_bytes(&self) -> usize {
+        self.entity_capacity * size_of::<Entity>()
+            + self
+                .columns
+                .iter()
+                .map(ColumnMemoryUsage::allocated_bytes)
+                .sum::<usize>()
+    }
+
+    /// The number of allocated bytes that hold entities and their components.
+    pub fn used_bytes(&self) -> usize {
+        self.entity_count * size_of::<Entity>()
+            + self
+                .columns
+                .iter()
+                .map(ColumnMemoryUsage::used_bytes)
+                .sum::<usize>()
+    }
+
+    /// The fraction of allocated bytes that are unused, from `0.0` to `1.0`.
+    pub fn fragmentation(&self) -> f64 {
+        fragmentation(self.used_bytes(), self.allocated_bytes())
+    }
+}
+
+/// The memory used by a [`ComponentSparseSet`](super::ComponentSparseSet).
+///
+/// Returned by [`SparseSets::memory_usage`](super::SparseSets::memory_usage).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SparseSetMemoryUsage {
+    /// The memory used by the dense storage of component values.
+    pub dense: ColumnMemoryUsage,
+    /// The number of bytes allocated for the list of entities, parallel to the dense storage.
+    pub entities_bytes: usize,
+    /// The number of bytes allocated for the sparse array that maps entities to dense rows.
+    ///
+    /// This grows with the highest entity index that ever held the component.
+    pub sparse_bytes: usize,
+}
+
+impl SparseSetMemoryUsage {
+    /// The number of bytes allocated by the sparse set.
+    pub fn allocated_bytes(&self) -> usize {
+        self.dense.allocated_bytes() + self.entities_bytes + self.sparse_bytes
+    }
+
+    /// The number of allocated bytes that hold component values.
+    pub fn used_bytes(&self) -> usize {
+        self.dense.used_bytes()
+    }
+
+    /// The fraction of allocated bytes that don't hold component values, from `0.0` to `1.0`.
+    pub fn fragmentation(&self) -> f64 {
+        fragmentation(self.used_bytes(), self.allocated_bytes())
+    }
+}
+
+/// The memory attributed to an [`Archetype`](crate::archetype::Archetype).
+///
+/// Archetypes don't own their component data: their entities share a [`Table`](super::Table)
+/// with the entities of other archetypes that only differ in sparse set components. The memory
+/// reported here is that of the rows of their own entities.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArchetypeMemoryUsage {
+    /// The table storing the table components of the archetype.
+    pub table_id: TableId,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The number of entities the archetype can store without reallocating its list of entities.
+    pub entity_capacity: usize,
+    /// The number of components in the archetype.
+    pub component_count: usize,
+    /// The number of bytes holding the components of the entities in the archetype, in both its
+    /// table and sparse sets.
+    ///
+    /// This is an estimate: it is the entity count times the size of a row of components and change
+    /// ticks, and doesn't account for the sparse arrays that index the sparse sets.
+    pub used_bytes: usize,
+    /// The fraction of the archetype's table that is unused, from `0.0` to `1.0`.
+    pub table_fragmentation: f64,
+}
+
+impl ArchetypeMemoryUsage {
+    /// The number of bytes allocated for the archetype's own list of entities.
+    pub fn entities_bytes(&self) -> usize {
+        self.entity_capacity * size_of::<ArchetypeEntity>()
+    }
+}
+
+/// A report of the memory used to store the entities and components of a [`World`].
+///
+/// Returned by [`World::memory_usage`]. Resources are not included.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WorldMemoryUsage {
+    /// The memory attributed to each archetype, indexed by [`ArchetypeId`].
+    pub archetypes: Vec<ArchetypeMemoryUsage>,
+    /// The memory used by each table, indexed by [`TableId`].
+    pub tables: Vec<TableMemoryUsage>,
+    /// The memory used by each sparse set.
+    pub sparse_sets: Vec<SparseSetMemoryUsage>,
+}
+
+impl WorldMemoryUsage {
+    /// Returns the memory attributed to the given archetype.
+    pub fn archetype(&self, id: ArchetypeId) -> Option<&ArchetypeMemoryUsage> {
+        self.archetypes.get(id.index())
+    }
+
+    /// Returns the memory used by the given table.
+    pub fn table(&self, id: TableId) -> Option<&TableMemoryUsage> {
+        self.tables.get(id.as_usize())
+    }
+
+    /// The number of bytes allocated by all tables.
+    pub fn table_bytes(&self) -> usize {
+        self.tables
+            .iter()
+            .map(TableMemoryUsage::allocated_bytes)
+            .sum()
+    }
+
+    /// The number of bytes allocated by all sparse sets.
+    pub fn sparse_set_bytes(&self) -> usize {
+        self.sparse_sets
+            .iter()
+            .map(SparseSetMemoryUsage::allocated_bytes)
+            .sum()
+    }
+
+    /// The number of bytes allocated by all archetypes for their lists of entities.
+    pub fn archetype_bytes(&self) -> usize {
+        self.archetypes
+            .iter()
+            .map(ArchetypeMemoryUsage::entities_bytes)
+            .sum()
+    }
+
+    /// The total number of bytes allocated to store entities and components.
+    pub fn allocated_bytes(&self) -> usize {
+        self.table_bytes() + self.sparse_set_bytes() + self.archetype_bytes()
+    }
+
+    /// The number of allocated bytes in tables and sparse sets that hold components.
+    pub fn used_bytes(&self) -> usize {
+        self.tables
+            .iter()
+            .map(TableMemoryUsage::used_bytes)
+            .sum::<usize>()
+            + self
+                .sparse_sets
+                .iter()
+                .map(SparseSetMemoryUsage::used_bytes)
+                .sum::<usize>()
+    }
+
+    /// The fraction of the bytes allocated by tables and sparse sets that are unused, from `0.0`
+    /// to `1.0`.
+    pub fn fragmentation(&self) -> f64 {
+        fragmentation(
+            self.used_bytes(),
+            self.table_bytes() + self.sparse_set_bytes(),
+        )
+    }
+
+    /// Returns the number of bytes allocated for each component, across all tables and sparse
+    /// sets.
+    pub fn component_bytes(&self) -> HashMap<ComponentId, usize> {
+        let mut bytes = HashMap::default();
+        let columns = self
+            .tables
+            .iter()
+            .flat_map(|table| table.columns.iter())
+            .chain(self.sparse_sets.iter().map(|sparse_set| &sparse_set.dense));
+        for column in columns {
+            *bytes.entry(column.component_id).or_default() += column.allocated_bytes();
+        }
+        bytes
+    }
+}
+
+fn fragmentation(used: usize, allocated: usize) -> f64 {
+    if allocated == 0 {
+        0.0
+    } else {
+        1.0 - used as f64 / allocated as f64
+    }
+}
+
+impl World {
+    /// Reports how much memory is used to store the entities and components of this world,
+    /// broken down by archetype, table and sparse set.
+    ///
+    /// This walks every table and archetype, so it shouldn't be called every frame in large worlds.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn_batch((0..100).map(|i| Position(i as f32, 0.0)));
+    ///
+    /// let usage = world.memory_usage();
+    /// let position = world.component_id::<Position>().unwrap();
+    /// assert!(usage.component_bytes()[&position] >= 100 * core::mem::size_of::<Position>());
+    /// ```
+    pub fn memory_usage(&self) -> WorldMemoryUsage {
+        let tables: Vec<_> = self
+            .storages()
+            .tables
+            .iter()
+            .map(super::Table::memory_usage)
+            .collect();
+
+        let sparse_sets: Vec<_> = self.storages().sparse_sets.memory_usage().collect();
+        let sparse_row_sizes: HashMap<_, _> = sparse_sets
+            .iter()
+            .map(|sparse_set| {
+                (
+                    sparse_set.dense.component_id,
+                    sparse_set.dense.row_size() + size_of::<Entity>(),
+                )
+            })
+            .collect();
+
+        let archetypes = self
+            .archetypes()
+            .iter()
+            .map(|archetype| {
+                let table = &tables[archetype.table_id().as_usize()];
+                let entity_count = archetype.len();
+                let table_row_size: usize = table
+                    .columns
+                    .iter()
+                    .map(ColumnMemoryUsage::row_size)
+                    .sum::<usize>()
+                    + size_of::<Entity>();
+                let sparse_row_size: usize = archetype
+                    .sparse_set_components()
+                    .filter_map(|id| sparse_row_sizes.get(&id))
+                    .sum();
+                ArchetypeMemoryUsage {
+                    table_id: archetype.table_id(),
+                    entity_count,
+                    entity_capacity: archetype.entity_capacity(),
+                    component_count: archetype.component_count(),
+                    used_bytes: entity_count * (table_row_size + sparse_row_size),
+                    table_fragmentation: table.fragmentation(),
+                }
+            })
+            .collect();
+
+        WorldMemoryUsage {
+            archetypes,
+            tables,
+            sparse_sets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, component::Component, world::World};
+
+    #[derive(Component)]
+    struct Table(#[allow(dead_code)] [u8; 16]);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Sparse(#[allow(dead_code)] u64);
+
+    #[test]
+    fn reports_tables_and_sparse_sets() {
+        let mut world = World::new();
+        let entities: Vec<_> = (0..10)
+            .map(|_| world.spawn((Table([0; 16]), Sparse(0))).id())
+            .collect();
+
+        let usage = world.memory_usage();
+        let table_id = world.component_id::<Table>().unwrap();
+        let sparse_id = world.component_id::<Sparse>().unwrap();
+
+        let archetype = world.entity(entities[0]).archetype().id();
+        let archetype_usage = usage.archetype(archetype).unwrap();
+        assert_eq!(archetype_usage.entity_count, 10);
+        assert_eq!(archetype_usage.component_count, 2);
+
+        let table = usage.table(archetype_usage.table_id).unwrap();
+        assert_eq!(table.entity_count, 10);
+        assert!(table.entity_capacity >= 10);
+        assert_eq!(table.columns.len(), 1);
+        assert_eq!(table.columns[0].component_id, table_id);
+        assert_eq!(table.columns[0].item_size, 16);
+
+        let sparse = usage
+            .sparse_sets
+            .iter()
+            .find(|sparse_set| sparse_set.dense.component_id == sparse_id)
+            .unwrap();
+        assert_eq!(sparse.dense.len, 10);
+        assert!(sparse.sparse_bytes > 0);
+
+        let bytes = usage.component_bytes();
+        assert_eq!(bytes[&table_id], table.columns[0].allocated_bytes());
+        assert!(archetype_usage.used_bytes >= 10 * (16 + 8));
+        assert!(usage.allocated_bytes() >= usage.used_bytes());
+    }
+
+    #[test]
+    fn reports_fragmentation() {
+        let mut world = World::new();
+        let entities: Vec<_> = (0..64).map(|_| world.spawn(Table([0; 16])).id()).collect();
+        let table = world.entity(entities[0]).archetype().table_id();
+        let before = world.memory_usage().table(table).unwrap().fragmentation();
+
+        for entity in &entities[..48] {
+            world.despawn(*entity);
+        }
+        let after = world.memory_usage().table(table).unwrap().fragmentation();
+        assert!(after > before);
+        assert!(after > 0.5 && after < 1.0);
+    }
+}
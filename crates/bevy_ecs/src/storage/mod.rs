@@ -22,11 +22,13 @@
 
 mod blob_array;
 mod blob_vec;
+mod memory_usage;
 mod resource;
 mod sparse_set;
 mod table;
 mod thin_array_ptr;
 
+pub use memory_usage::*;
 pub use resource::*;
 pub use sparse_set::*;
 pub use table::*;
@@ -2,12 +2,12 @@ use crate::{
     change_detection::MaybeUnsafeCellLocation,
     component::{ComponentId, ComponentInfo, ComponentTicks, Tick, TickCells},
     entity::Entity,
-    storage::{Column, TableRow},
+    storage::{Column, ColumnMemoryUsage, SparseSetMemoryUsage, TableRow},
 };
 use bevy_ptr::{OwningPtr, Ptr};
 #[cfg(feature = "track_change_detection")]
 use core::panic::Location;
-use core::{cell::UnsafeCell, hash::Hash, marker::PhantomData};
+use core::{cell::UnsafeCell, hash::Hash, marker::PhantomData, mem::size_of};
 use nonmax::NonMaxUsize;
 
 type EntityIndex = u32;
@@ -161,6 +161,24 @@ impl ComponentSparseSet {
         self.dense.len() == 0
     }
 
+    /// Reports the memory used by this sparse set, which stores the component `component_id`.
+    fn memory_usage(&self, component_id: ComponentId) -> SparseSetMemoryUsage {
+        fn allocated_bytes<T>(vec: &Vec<T>) -> usize {
+            vec.capacity() * size_of::<T>()
+        }
+
+        SparseSetMemoryUsage {
+            dense: ColumnMemoryUsage {
+                component_id,
+                item_size: self.dense.item_layout().size(),
+                len: self.dense.len(),
+                capacity: self.dense.capacity(),
+            },
+            entities_bytes: allocated_bytes(&self.entities),
+            sparse_bytes: allocated_bytes(&self.sparse.values),
+        }
+    }
+
     /// Inserts the `entity` key and component `value` pair into this sparse
     /// set.
     ///
@@ -618,6 +636,13 @@ impl SparseSets {
         self.sets.iter().map(|(id, data)| (*id, data))
     }
 
+    /// Reports the memory used by each sparse set.
+    pub fn memory_usage(&self) -> impl Iterator<Item = SparseSetMemoryUsage> + '_ {
+        self.sets
+            .iter()
+            .map(|(&component_id, sparse_set)| sparse_set.memory_usage(component_id))
+    }
+
     /// Gets a reference to the [`ComponentSparseSet`] of a [`ComponentId`].
     #[inline]
     pub fn get(&self, component_id: ComponentId) -> Option<&ComponentSparseSet> {
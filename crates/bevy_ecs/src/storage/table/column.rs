@@ -397,6 +397,12 @@ impl Column {
         self.data.len()
     }
 
+    /// Gets the number of elements the column can store without reallocating.
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.added_ticks.capacity()
+    }
+
     /// Checks if the column is empty. Returns `true` if there are no elements, `false` otherwise.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -3,7 +3,9 @@ use crate::{
     component::{ComponentId, ComponentInfo, ComponentTicks, Components, Tick},
     entity::Entity,
     query::DebugCheckedUnwrap,
-    storage::{blob_vec::BlobVec, ImmutableSparseSet, SparseSet},
+    storage::{
+        blob_vec::BlobVec, ColumnMemoryUsage, ImmutableSparseSet, SparseSet, TableMemoryUsage,
+    },
 };
 use bevy_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
 use bevy_utils::HashMap;
@@ -606,6 +608,26 @@ impl Table {
         self.entities.capacity()
     }
 
+    /// Reports the memory used by this table and its columns.
+    pub fn memory_usage(&self) -> TableMemoryUsage {
+        let len = self.entity_count();
+        let capacity = self.capacity();
+        TableMemoryUsage {
+            entity_count: len,
+            entity_capacity: capacity,
+            columns: self
+                .columns
+                .iter()
+                .map(|(&component_id, column)| ColumnMemoryUsage {
+                    component_id,
+                    item_size: column.data.layout().size(),
+                    len,
+                    capacity,
+                })
+                .collect(),
+        }
+    }
+
     /// Checks if the [`Table`] is empty or not.
     ///
     /// Returns `true` if the table contains no entities, `false` otherwise.
@@ -0,0 +1,360 @@
+//! Error types and handling for [`Command`]s that fail when applied.
+//!
+//! [`Command`]: crate::world::Command
+
+use alloc::borrow::Cow;
+use core::panic::Location;
+
+use derive_more::derive::{Display, Error};
+
+use crate::{
+    self as bevy_ecs,
+    entity::Entity,
+    event::{Event, Events},
+    system::Resource,
+    world::World,
+};
+use bevy_utils::tracing::{debug, error, info, trace, warn, Level};
+
+/// An error that occurred while applying a [`Command`](crate::world::Command).
+///
+/// Commands that can fail return `Result<(), CommandError>` instead of panicking.
+/// When such a command fails, the error is passed to the world's [`CommandErrorHandler`].
+#[derive(Error, Display, Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The entity targeted by the command does not exist.
+    #[display("error[B0003]: Could not {action} for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003")]
+    NoSuchEntity {
+        /// The entity that was targeted.
+        entity: Entity,
+        /// A short description of what the command attempted to do.
+        action: Cow<'static, str>,
+    },
+    /// A custom error returned by a user-defined command.
+    #[display("{_0}")]
+    #[error(ignore)]
+    Other(Cow<'static, str>),
+}
+
+impl CommandError {
+    /// Creates a [`CommandError::NoSuchEntity`] for the given entity and attempted action.
+    pub fn no_such_entity(entity: Entity, action: impl Into<Cow<'static, str>>) -> Self {
+        Self::NoSuchEntity {
+            entity,
+            action: action.into(),
+        }
+    }
+}
+
+impl From<&'static str> for CommandError {
+    fn from(message: &'static str) -> Self {
+        Self::Other(Cow::Borrowed(message))
+    }
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self::Other(Cow::Owned(message))
+    }
+}
+
+/// A [`Command`](crate::world::Command) that failed to apply, along with where it was queued from if known.
+///
+/// This is what a [`CommandErrorHandler`] receives, and is sent as an [`Event`] by
+/// [`CommandErrorHandler::Collect`].
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct CommandFailed {
+    /// The error returned by the command.
+    pub error: CommandError,
+    /// The location the command was queued from, if it was tracked.
+    pub caller: Option<&'static Location<'static>>,
+    /// How severe the failure is.
+    ///
+    /// Commands that are expected to fail sometimes, such as [`EntityCommands::try_insert`] on an
+    /// entity that may have been despawned, report their failures at [`Level::DEBUG`].
+    /// Only failures at [`Level::ERROR`] make [`CommandErrorHandler::Panic`] panic.
+    ///
+    /// [`EntityCommands::try_insert`]: crate::system::EntityCommands::try_insert
+    pub level: Level,
+}
+
+impl CommandFailed {
+    /// Creates a [`CommandFailed`] at [`Level::ERROR`].
+    pub fn new(error: CommandError, caller: Option<&'static Location<'static>>) -> Self {
+        Self {
+            error,
+            caller,
+            level: Level::ERROR,
+        }
+    }
+
+    /// Sets the [`level`](Self::level) of this failure.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+}
+
+impl core::fmt::Display for CommandFailed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.caller {
+            Some(caller) => write!(f, "{caller}: {}", self.error),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+/// Decides what happens when a [`Command`](crate::world::Command) fails.
+///
+/// Insert this resource into the [`World`] to change how failed commands are handled.
+/// If the resource is missing, [`CommandErrorHandler::Panic`] is used.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{CommandErrorHandler, CommandFailed};
+/// #
+/// # let mut world = World::new();
+/// // Log failures instead of panicking, so a race with a despawn doesn't take down the server.
+/// world.insert_resource(CommandErrorHandler::Log);
+///
+/// // Or collect them so that systems can react to them.
+/// world.insert_resource(CommandErrorHandler::Collect);
+/// world.init_resource::<Events<CommandFailed>>();
+///
+/// fn report_failed_commands(mut failures: EventReader<CommandFailed>) {
+///     for failure in failures.read() {
+///         println!("{failure}");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(report_failed_commands);
+/// ```
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub enum CommandErrorHandler {
+    /// Panic with the error message. This is the default.
+    ///
+    /// Failures below [`Level::ERROR`] are logged instead.
+    #[default]
+    Panic,
+    /// Log the error at its [level](CommandFailed::level) and continue.
+    Log,
+    /// Send the failure as a [`CommandFailed`] event and continue.
+    ///
+    /// The [`Events<CommandFailed>`] resource is inserted if it doesn't exist yet. Apps register it
+    /// as an event by default, so that old failures are cleared regularly.
+    Collect,
+    /// Silently ignore the error.
+    Ignore,
+    /// Call the given function.
+    Custom(fn(&mut World, CommandFailed)),
+}
+
+impl CommandErrorHandler {
+    /// Passes a failed command to the [`CommandErrorHandler`] of the given `world`.
+    ///
+    /// This is called automatically for commands queued with [`Commands::queue_fallible`],
+    /// but can also be used by custom [`Command`](crate::world::Command) implementations.
+    ///
+    /// [`Commands::queue_fallible`]: crate::system::Commands::queue_fallible
+    ///
+    /// If the failure has no caller, the location the [`EntityCommand`] being applied was queued from is used.
+    ///
+    /// [`EntityCommand`]: crate::system::EntityCommand
+    pub fn handle(world: &mut World, mut failed: CommandFailed) {
+        if failed.caller.is_none() {
+            failed.caller = world.command_caller;
+        }
+        let handler = world
+            .get_resource::<CommandErrorHandler>()
+            .copied()
+            .unwrap_or_default();
+        match handler {
+            CommandErrorHandler::Panic if failed.level == Level::ERROR => panic!("{failed}"),
+            CommandErrorHandler::Panic | CommandErrorHandler::Log => log(&failed),
+            CommandErrorHandler::Collect => {
+                world
+                    .get_resource_or_insert_with(Events::<CommandFailed>::default)
+                    .send(failed);
+            }
+            CommandErrorHandler::Ignore => {}
+            CommandErrorHandler::Custom(handler) => handler(world, failed),
+        }
+    }
+}
+
+fn log(failed: &CommandFailed) {
+    match failed.level {
+        Level::ERROR => error!("{failed}"),
+        Level::WARN => warn!("{failed}"),
+        Level::INFO => info!("{failed}"),
+        Level::DEBUG => debug!("{failed}"),
+        _ => trace!("{failed}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::Component,
+        event::Events,
+        system::{Commands, RunSystemOnce},
+    };
+
+    #[derive(Component)]
+    struct A;
+
+    fn insert_into_despawned(mut commands: Commands) {
+        let entity = commands.spawn_empty().id();
+        commands.entity(entity).despawn();
+        commands.entity(entity).insert(A);
+    }
+
+    #[test]
+    #[should_panic]
+    fn failed_commands_panic_by_default() {
+        let mut world = World::new();
+        world.run_system_once(insert_into_despawned).unwrap();
+    }
+
+    #[test]
+    fn failed_commands_can_be_logged() {
+        let mut world = World::new();
+        world.insert_resource(CommandErrorHandler::Log);
+        world.run_system_once(insert_into_despawned).unwrap();
+    }
+
+    #[test]
+    fn failed_commands_can_be_collected() {
+        let mut world = World::new();
+        world.insert_resource(CommandErrorHandler::Collect);
+        world.run_system_once(insert_into_despawned).unwrap();
+
+        let events = world.resource::<Events<CommandFailed>>();
+        let failures: Vec<_> = events.iter_current_update_events().collect();
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(
+            failures[0].error,
+            CommandError::NoSuchEntity { .. }
+        ));
+        assert!(failures[0].caller.is_some());
+    }
+
+    #[test]
+    fn fallible_commands_are_handled() {
+        #[derive(Resource, Default)]
+        struct Failures(Vec<CommandError>);
+
+        let mut world = World::new();
+        world.init_resource::<Failures>();
+        world.insert_resource(CommandErrorHandler::Custom(|world, failed| {
+            world.resource_mut::<Failures>().0.push(failed.error);
+        }));
+
+        world
+            .run_system_once(|mut commands: Commands| {
+                commands.queue_fallible(|_: &mut World| -> Result<(), CommandError> {
+                    Err("something went wrong".into())
+                });
+                commands.queue_fallible(|_: &mut World| -> Result<(), CommandError> { Ok(()) });
+                commands.queue(|world: &mut World| {
+                    world.spawn_empty();
+                });
+            })
+            .unwrap();
+
+        assert_eq!(
+            world.resource::<Failures>().0,
+            vec![CommandError::Other("something went wrong".into())]
+        );
+    }
+
+    #[test]
+    fn fallible_entity_commands_are_handled() {
+        let mut world = World::new();
+        world.insert_resource(CommandErrorHandler::Collect);
+
+        let entity = world.spawn_empty().id();
+        world
+            .run_system_once(move |mut commands: Commands| {
+                commands.entity(entity).queue(
+                    |_: crate::world::EntityWorldMut| -> Result<(), CommandError> {
+                        Err("entity rejected".into())
+                    },
+                );
+                commands.entity(entity).despawn();
+                commands
+                    .entity(entity)
+                    .queue(|mut entity: crate::world::EntityWorldMut| {
+                        entity.insert(A);
+                    });
+            })
+            .unwrap();
+
+        let events = world.resource::<Events<CommandFailed>>();
+        let failures: Vec<_> = events.iter_current_update_events().collect();
+        assert_eq!(failures.len(), 2);
+        assert_eq!(
+            failures[0].error,
+            CommandError::Other("entity rejected".into())
+        );
+        assert!(matches!(
+            failures[1].error,
+            CommandError::NoSuchEntity { .. }
+        ));
+        assert!(failures.iter().all(|failed| failed.caller.is_some()));
+    }
+
+    #[test]
+    fn try_commands_report_failures_at_debug_level() {
+        fn try_insert_into_despawned(mut commands: Commands) {
+            let entity = commands.spawn_empty().id();
+            commands.entity(entity).despawn();
+            commands.entity(entity).try_insert(A).remove::<A>().clear();
+            commands.entity(entity).try_despawn();
+            commands.try_insert_batch(vec![(entity, A)]);
+        }
+
+        // The default handler only panics on errors.
+        let mut world = World::new();
+        world.run_system_once(try_insert_into_despawned).unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(CommandErrorHandler::Collect);
+        world.run_system_once(try_insert_into_despawned).unwrap();
+
+        let events = world.resource::<Events<CommandFailed>>();
+        let failures: Vec<_> = events.iter_current_update_events().collect();
+        assert_eq!(failures.len(), 5);
+        assert!(failures.iter().all(|failed| failed.level == Level::DEBUG
+            && failed.caller.is_some()
+            && matches!(failed.error, CommandError::NoSuchEntity { .. })));
+    }
+
+    #[test]
+    fn entity_commands_report_their_caller() {
+        use crate::{entity::Entity, system::EntityCommand};
+
+        struct Reject;
+
+        impl EntityCommand for Reject {
+            fn apply(self, _: Entity, world: &mut World) {
+                CommandErrorHandler::handle(world, CommandFailed::new("rejected".into(), None));
+            }
+        }
+
+        let mut world = World::new();
+        world.insert_resource(CommandErrorHandler::Collect);
+        let entity = world.spawn_empty().id();
+        world
+            .run_system_once(move |mut commands: Commands| {
+                commands.entity(entity).queue(Reject);
+            })
+            .unwrap();
+
+        let events = world.resource::<Events<CommandFailed>>();
+        let failures: Vec<_> = events.iter_current_update_events().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].caller.unwrap().file(), file!());
+        assert!(world.command_caller.is_none());
+    }
+}
@@ -1,6 +1,7 @@
+mod error;
 mod parallel_scope;
 
-use core::{marker::PhantomData, panic::Location};
+use core::{future::Future, marker::PhantomData, panic::Location};
 
 use super::{
     Deferred, IntoObserverSystem, IntoSystem, RegisterSystem, Resource, RunSystemCachedWith,
@@ -10,17 +11,19 @@ use crate::{
     bundle::{Bundle, InsertMode},
     change_detection::Mut,
     component::{Component, ComponentId, ComponentInfo},
-    entity::{Entities, Entity},
+    entity::{Entities, Entity, EntityCloneBuilder},
+    entity_disabling::Disabled,
     event::{Event, SendEvent},
     observer::{Observer, TriggerEvent, TriggerTargets},
     system::{input::SystemInput, RunSystemWithInput, SystemId},
     world::{
-        command_queue::RawCommandQueue, unsafe_world_cell::UnsafeWorldCell, Command, CommandQueue,
-        EntityWorldMut, FromWorld, SpawnBatchIter, World,
+        command_queue::RawCommandQueue, unsafe_world_cell::UnsafeWorldCell, AsyncWorld, Command,
+        CommandQueue, EntityWorldMut, FromWorld, SpawnBatchIter, World,
     },
 };
 use bevy_ptr::OwningPtr;
-use bevy_utils::tracing::{error, info};
+use bevy_utils::tracing::{error, info, Level};
+pub use error::*;
 pub use parallel_scope::*;
 
 /// A [`Command`] queue to perform structural changes to the [`World`].
@@ -582,6 +585,40 @@ impl<'w, 's> Commands<'w, 's> {
         }
     }
 
+    /// Pushes a [`Command`] that can fail to the command queue.
+    ///
+    /// If the command returns an error when applied, the error is passed to the world's
+    /// [`CommandErrorHandler`] along with the location this method was called from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::CommandError;
+    /// #[derive(Resource)]
+    /// struct Counter(u64);
+    ///
+    /// fn increment_counter_system(mut commands: Commands) {
+    ///     commands.queue_fallible(|world: &mut World| -> Result<(), CommandError> {
+    ///         let mut counter = world
+    ///             .get_resource_mut::<Counter>()
+    ///             .ok_or("the counter has not been initialized")?;
+    ///         counter.0 += 1;
+    ///         Ok(())
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(increment_counter_system);
+    /// ```
+    #[track_caller]
+    pub fn queue_fallible<C: Command<Result<(), CommandError>>>(&mut self, command: C) {
+        let caller = Location::caller();
+        self.queue(move |world: &mut World| {
+            if let Err(error) = command.apply(world) {
+                CommandErrorHandler::handle(world, CommandFailed::new(error, Some(caller)));
+            }
+        });
+    }
+
     /// Pushes a [`Command`] to the queue for creating entities, if needed,
     /// and for adding a bundle to each entity.
     ///
@@ -679,7 +716,7 @@ impl<'w, 's> Commands<'w, 's> {
     /// and passing the bundle to [`insert`](EntityCommands::insert),
     /// but it is faster due to memory pre-allocation.
     ///
-    /// This command silently fails by ignoring any entities that do not exist.
+    /// Entities that do not exist are skipped, and reported to the [`CommandErrorHandler`] at [`Level::DEBUG`].
     ///
     /// For the panicking version, see [`insert_batch`](Self::insert_batch).
     #[track_caller]
@@ -704,7 +741,7 @@ impl<'w, 's> Commands<'w, 's> {
     /// and passing the bundle to [`insert_if_new`](EntityCommands::insert_if_new),
     /// but it is faster due to memory pre-allocation.
     ///
-    /// This command silently fails by ignoring any entities that do not exist.
+    /// Entities that do not exist are skipped, and reported to the [`CommandErrorHandler`] at [`Level::DEBUG`].
     ///
     /// For the panicking version, see [`insert_batch_if_new`](Self::insert_batch_if_new).
     #[track_caller]
@@ -947,6 +984,17 @@ impl<'w, 's> Commands<'w, 's> {
         self.spawn(Observer::new(observer))
     }
 
+    /// Spawns an async task that can access the [`World`] through an [`AsyncWorld`].
+    ///
+    /// See [`World::spawn_task`] for more details.
+    pub fn spawn_task<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(AsyncWorld) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.queue(move |world: &mut World| world.spawn_task(task));
+    }
+
     /// Sends an arbitrary [`Event`].
     ///
     /// This is a convenience method for sending events without requiring an [`EventWriter`].
@@ -1033,6 +1081,27 @@ pub trait EntityCommand<Marker = ()>: Send + 'static {
     {
         move |world: &mut World| self.apply(entity, world)
     }
+
+    /// Returns a [`Command`] which executes this [`EntityCommand`] for the given [`Entity`],
+    /// reporting failures as coming from `caller`.
+    ///
+    /// This is what [`EntityCommands::queue`] uses. The provided implementation applies the command
+    /// with `caller` as the caller of any failure passed to [`CommandErrorHandler::handle`] without one.
+    #[must_use = "commands do nothing unless applied to a `World`"]
+    fn with_entity_and_caller(
+        self,
+        entity: Entity,
+        caller: &'static Location<'static>,
+    ) -> impl Command
+    where
+        Self: Sized,
+    {
+        move |world: &mut World| {
+            let previous = world.command_caller.replace(caller);
+            self.apply(entity, world);
+            world.command_caller = previous;
+        }
+    }
 }
 
 /// A list of commands that will be run to modify an [entity](crate::entity).
@@ -1108,7 +1177,8 @@ impl<'a> EntityCommands<'a> {
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
     ///
     /// To avoid a panic in this case, use the command [`Self::try_insert`] instead.
     ///
@@ -1163,7 +1233,8 @@ impl<'a> EntityCommands<'a> {
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
     ///
     /// To avoid a panic in this case, use the command [`Self::try_insert_if`] instead.
     ///
@@ -1210,7 +1281,8 @@ impl<'a> EntityCommands<'a> {
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
     ///
     /// To avoid a panic in this case, use the command [`Self::try_insert_if_new`] instead.
     pub fn insert_if_new(&mut self, bundle: impl Bundle) -> &mut Self {
@@ -1226,8 +1298,8 @@ impl<'a> EntityCommands<'a> {
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not
-    /// exist.
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
     ///
     /// To avoid a panic in this case, use the command [`Self::try_insert_if_new`]
     /// instead.
@@ -1248,7 +1320,8 @@ impl<'a> EntityCommands<'a> {
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
     ///
     /// To avoid a panic in this case, use the command [`Self::try_insert_by_id`] instead.
     ///
@@ -1264,9 +1337,20 @@ impl<'a> EntityCommands<'a> {
     ) -> &mut Self {
         let caller = Location::caller();
         // SAFETY: same invariants as parent call
-        self.queue(unsafe {insert_by_id(component_id, value, move |entity| {
-            panic!("error[B0003]: {caller}: Could not insert a component {component_id:?} (with type {}) for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003", core::any::type_name::<T>());
-        })})
+        self.queue(unsafe {
+            insert_by_id(component_id, value, move |entity, world| {
+                handle_no_such_entity(
+                    world,
+                    entity,
+                    format!(
+                        "insert a component {component_id:?} (with type {})",
+                        core::any::type_name::<T>()
+                    ),
+                    caller,
+                    Level::ERROR,
+                );
+            })
+        })
     }
 
     /// Attempts to add a dynamic component to an entity.
@@ -1277,13 +1361,28 @@ impl<'a> EntityCommands<'a> {
     ///
     /// - [`ComponentId`] must be from the same world as `self`.
     /// - `T` must have the same layout as the one passed during `component_id` creation.
+    #[track_caller]
     pub unsafe fn try_insert_by_id<T: Send + 'static>(
         &mut self,
         component_id: ComponentId,
         value: T,
     ) -> &mut Self {
+        let caller = Location::caller();
         // SAFETY: same invariants as parent call
-        self.queue(unsafe { insert_by_id(component_id, value, |_| {}) })
+        self.queue(unsafe {
+            insert_by_id(component_id, value, move |entity, world| {
+                handle_no_such_entity(
+                    world,
+                    entity,
+                    format!(
+                        "insert a component {component_id:?} (with type {})",
+                        core::any::type_name::<T>()
+                    ),
+                    caller,
+                    Level::DEBUG,
+                );
+            })
+        })
     }
 
     /// Tries to add a [`Bundle`] of components to the entity.
@@ -1293,6 +1392,7 @@ impl<'a> EntityCommands<'a> {
     /// # Note
     ///
     /// Unlike [`Self::insert`], this will not panic if the associated entity does not exist.
+    /// Instead, the missing entity is reported to the [`CommandErrorHandler`] at [`Level::DEBUG`].
     ///
     /// # Example
     ///
@@ -1471,11 +1571,12 @@ impl<'a> EntityCommands<'a> {
     /// }
     /// # bevy_ecs::system::assert_is_system(remove_combat_stats_system);
     /// ```
+    #[track_caller]
     pub fn remove<T>(&mut self) -> &mut Self
     where
         T: Bundle,
     {
-        self.queue(remove::<T>)
+        self.queue(remove::<T>())
     }
 
     /// Removes all components in the [`Bundle`] components and remove all required components for each component in the [`Bundle`] from entity.
@@ -1502,20 +1603,50 @@ impl<'a> EntityCommands<'a> {
     /// }
     /// # bevy_ecs::system::assert_is_system(remove_with_requires_system);
     /// ```
+    #[track_caller]
     pub fn remove_with_requires<T: Bundle>(&mut self) -> &mut Self {
-        self.queue(remove_with_requires::<T>)
+        self.queue(remove_with_requires::<T>())
     }
 
     /// Removes a component from the entity.
+    #[track_caller]
     pub fn remove_by_id(&mut self, component_id: ComponentId) -> &mut Self {
         self.queue(remove_by_id(component_id))
     }
 
     /// Removes all components associated with the entity.
+    #[track_caller]
     pub fn clear(&mut self) -> &mut Self {
         self.queue(clear())
     }
 
+    /// Disables the entity by inserting the [`Disabled`] marker component.
+    ///
+    /// Disabled entities are excluded from queries that do not explicitly mention [`Disabled`].
+    /// See the [`entity_disabling`](crate::entity_disabling) module for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Bullet { entity: Entity }
+    /// #
+    /// fn park_bullet_system(mut commands: Commands, bullet: Res<Bullet>) {
+    ///     commands.entity(bullet.entity).disable();
+    /// }
+    /// # bevy_ecs::system::assert_is_system(park_bullet_system);
+    /// ```
+    pub fn disable(&mut self) -> &mut Self {
+        self.insert(Disabled)
+    }
+
+    /// Enables a previously [disabled](Self::disable) entity by removing the [`Disabled`] marker component.
+    pub fn enable(&mut self) -> &mut Self {
+        self.remove::<Disabled>()
+    }
+
     /// Despawns the entity.
     /// This will emit a warning if the entity does not exist.
     ///
@@ -1551,6 +1682,7 @@ impl<'a> EntityCommands<'a> {
     /// Despawns the entity.
     /// This will not emit a warning if the entity does not exist, essentially performing
     /// the same function as [`Self::despawn`] without emitting warnings.
+    /// Instead, the missing entity is reported to the [`CommandErrorHandler`] at [`Level::DEBUG`].
     #[track_caller]
     pub fn try_despawn(&mut self) {
         self.queue(try_despawn());
@@ -1572,8 +1704,10 @@ impl<'a> EntityCommands<'a> {
     /// # }
     /// # bevy_ecs::system::assert_is_system(my_system);
     /// ```
+    #[track_caller]
     pub fn queue<M: 'static>(&mut self, command: impl EntityCommand<M>) -> &mut Self {
-        self.commands.queue(command.with_entity(self.entity));
+        self.commands
+            .queue(command.with_entity_and_caller(self.entity, Location::caller()));
         self
     }
 
@@ -1614,20 +1748,23 @@ impl<'a> EntityCommands<'a> {
     /// }
     /// # bevy_ecs::system::assert_is_system(remove_combat_stats_system);
     /// ```
+    #[track_caller]
     pub fn retain<T>(&mut self) -> &mut Self
     where
         T: Bundle,
     {
-        self.queue(retain::<T>)
+        self.queue(retain::<T>())
     }
 
     /// Logs the components of the entity at the info level.
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
+    #[track_caller]
     pub fn log_components(&mut self) -> &mut Self {
-        self.queue(log_components)
+        self.queue(log_components())
     }
 
     /// Returns the underlying [`Commands`].
@@ -1656,6 +1793,75 @@ impl<'a> EntityCommands<'a> {
     ) -> &mut Self {
         self.queue(observe(system))
     }
+
+    /// Spawns an async task owned by this entity, which is cancelled when the entity is
+    /// despawned.
+    ///
+    /// See [`EntityWorldMut::spawn_task`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
+    #[track_caller]
+    pub fn spawn_task<F, Fut>(&mut self, task: F) -> &mut Self
+    where
+        F: FnOnce(AsyncWorld) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.queue(spawn_task(task))
+    }
+
+    /// Clones all components of the entity onto a new entity, returning the [`EntityCommands`]
+    /// of the clone.
+    ///
+    /// See [`World::clone_entity`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone)]
+    /// struct Projectile {
+    ///     speed: f32,
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct ProjectileTemplate(Entity);
+    ///
+    /// fn fire_system(mut commands: Commands, template: Res<ProjectileTemplate>) {
+    ///     commands.entity(template.0).clone_entity();
+    /// }
+    /// # bevy_ecs::system::assert_is_system(fire_system);
+    /// ```
+    #[track_caller]
+    pub fn clone_entity(&mut self) -> EntityCommands<'_> {
+        self.clone_entity_with(|_| {})
+    }
+
+    /// Clones the entity onto a new entity, using `config` to customize the [`EntityCloneBuilder`],
+    /// and returns the [`EntityCommands`] of the clone.
+    ///
+    /// See [`World::clone_entity_with`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with the [`CommandErrorHandler`] resource.
+    #[track_caller]
+    pub fn clone_entity_with(
+        &mut self,
+        config: impl FnOnce(&mut EntityCloneBuilder) + Send + Sync + 'static,
+    ) -> EntityCommands<'_> {
+        let target = self.commands.spawn_empty().id();
+        self.queue(clone_entity(target, config));
+        self.commands.entity(target)
+    }
 }
 
 /// A wrapper around [`EntityCommands`] with convenience methods for working with a specified component type.
@@ -1761,12 +1967,12 @@ impl<'a, T: Component> EntityEntryCommands<'a, T> {
     }
 }
 
-impl<F> Command for F
+impl<F, Out> Command<Out> for F
 where
-    F: FnOnce(&mut World) + Send + 'static,
+    F: FnOnce(&mut World) -> Out + Send + 'static,
 {
-    fn apply(self, world: &mut World) {
-        self(world);
+    fn apply(self, world: &mut World) -> Out {
+        self(world)
     }
 }
 
@@ -1775,7 +1981,40 @@ where
     F: FnOnce(EntityWorldMut) + Send + 'static,
 {
     fn apply(self, id: Entity, world: &mut World) {
-        self(world.entity_mut(id));
+        apply_to_entity_world_mut(id, world, None, |entity| {
+            self(entity);
+            Ok(())
+        });
+    }
+
+    fn with_entity_and_caller(
+        self,
+        entity: Entity,
+        caller: &'static Location<'static>,
+    ) -> impl Command {
+        move |world: &mut World| {
+            apply_to_entity_world_mut(entity, world, Some(caller), |entity| {
+                self(entity);
+                Ok(())
+            });
+        }
+    }
+}
+
+impl<F> EntityCommand<(World, CommandError)> for F
+where
+    F: FnOnce(EntityWorldMut) -> Result<(), CommandError> + Send + 'static,
+{
+    fn apply(self, id: Entity, world: &mut World) {
+        apply_to_entity_world_mut(id, world, None, self);
+    }
+
+    fn with_entity_and_caller(
+        self,
+        entity: Entity,
+        caller: &'static Location<'static>,
+    ) -> impl Command {
+        move |world: &mut World| apply_to_entity_world_mut(entity, world, Some(caller), self)
     }
 }
 
@@ -1788,6 +2027,65 @@ where
     }
 }
 
+impl<F> EntityCommand<CommandError> for F
+where
+    F: FnOnce(Entity, &mut World) -> Result<(), CommandError> + Send + 'static,
+{
+    fn apply(self, id: Entity, world: &mut World) {
+        if let Err(error) = self(id, world) {
+            CommandErrorHandler::handle(world, CommandFailed::new(error, None));
+        }
+    }
+
+    fn with_entity_and_caller(
+        self,
+        entity: Entity,
+        caller: &'static Location<'static>,
+    ) -> impl Command {
+        move |world: &mut World| {
+            if let Err(error) = self(entity, world) {
+                CommandErrorHandler::handle(world, CommandFailed::new(error, Some(caller)));
+            }
+        }
+    }
+}
+
+/// Runs `f` on the given entity, passing a [`CommandError::NoSuchEntity`] or the error returned
+/// by `f` to the world's [`CommandErrorHandler`].
+fn apply_to_entity_world_mut(
+    entity: Entity,
+    world: &mut World,
+    caller: Option<&'static Location<'static>>,
+    f: impl FnOnce(EntityWorldMut) -> Result<(), CommandError>,
+) {
+    let result = match world.get_entity_mut(entity) {
+        Ok(entity) => f(entity),
+        Err(_) => Err(CommandError::no_such_entity(
+            entity,
+            "apply an entity command",
+        )),
+    };
+    if let Err(error) = result {
+        CommandErrorHandler::handle(world, CommandFailed::new(error, caller));
+    }
+}
+
+/// Passes a [`CommandError::NoSuchEntity`] for a command queued at `caller` to the world's
+/// [`CommandErrorHandler`], at the given `level`.
+fn handle_no_such_entity(
+    world: &mut World,
+    entity: Entity,
+    action: impl Into<alloc::borrow::Cow<'static, str>>,
+    caller: &'static Location<'static>,
+    level: Level,
+) {
+    CommandErrorHandler::handle(
+        world,
+        CommandFailed::new(CommandError::no_such_entity(entity, action), Some(caller))
+            .with_level(level),
+    );
+}
+
 /// A [`Command`] that consumes an iterator of [`Bundle`]s to spawn a series of entities.
 ///
 /// This is more efficient than spawning the entities individually.
@@ -1881,7 +2179,7 @@ where
 }
 
 /// A [`Command`] that consumes an iterator to add a series of [`Bundles`](Bundle) to a set of entities.
-/// If any entities do not exist in the world, this command will ignore them.
+/// If any entities do not exist in the world, this command will skip them and report them at [`Level::DEBUG`].
 ///
 /// This is more efficient than inserting the bundles individually.
 #[track_caller]
@@ -1890,20 +2188,31 @@ where
     I: IntoIterator<Item = (Entity, B)> + Send + Sync + 'static,
     B: Bundle,
 {
-    #[cfg(feature = "track_change_detection")]
     let caller = Location::caller();
     move |world: &mut World| {
-        world.try_insert_batch_with_caller(
+        let invalid_entities = world.try_insert_batch_with_caller(
             batch,
             InsertMode::Replace,
             #[cfg(feature = "track_change_detection")]
             caller,
         );
+        for entity in invalid_entities {
+            handle_no_such_entity(
+                world,
+                entity,
+                format!(
+                    "insert a bundle (of type `{}`)",
+                    core::any::type_name::<B>()
+                ),
+                caller,
+                Level::DEBUG,
+            );
+        }
     }
 }
 
 /// A [`Command`] that consumes an iterator to add a series of [`Bundles`](Bundle) to a set of entities.
-/// If any entities do not exist in the world, this command will ignore them.
+/// If any entities do not exist in the world, this command will skip them and report them at [`Level::DEBUG`].
 ///
 /// This is more efficient than inserting the bundles individually.
 #[track_caller]
@@ -1912,15 +2221,26 @@ where
     I: IntoIterator<Item = (Entity, B)> + Send + Sync + 'static,
     B: Bundle,
 {
-    #[cfg(feature = "track_change_detection")]
     let caller = Location::caller();
     move |world: &mut World| {
-        world.try_insert_batch_with_caller(
+        let invalid_entities = world.try_insert_batch_with_caller(
             batch,
             InsertMode::Keep,
             #[cfg(feature = "track_change_detection")]
             caller,
         );
+        for entity in invalid_entities {
+            handle_no_such_entity(
+                world,
+                entity,
+                format!(
+                    "insert a bundle (of type `{}`)",
+                    core::any::type_name::<B>()
+                ),
+                caller,
+                Level::DEBUG,
+            );
+        }
     }
 }
 
@@ -1940,7 +2260,7 @@ fn despawn() -> impl EntityCommand {
 }
 
 /// A [`Command`] that despawns a specific entity.
-/// This will not emit a warning if the entity does not exist.
+/// This will not emit a warning if the entity does not exist, but report it at [`Level::DEBUG`].
 ///
 /// # Note
 ///
@@ -1950,7 +2270,9 @@ fn despawn() -> impl EntityCommand {
 fn try_despawn() -> impl EntityCommand {
     let caller = Location::caller();
     move |entity: Entity, world: &mut World| {
-        world.despawn_with_caller(entity, caller, false);
+        if !world.despawn_with_caller(entity, caller, false) {
+            handle_no_such_entity(world, entity, "despawn", caller, Level::DEBUG);
+        }
     }
 }
 
@@ -1967,7 +2289,16 @@ fn insert<T: Bundle>(bundle: T, mode: InsertMode) -> impl EntityCommand {
                 caller,
             );
         } else {
-            panic!("error[B0003]: {caller}: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003", core::any::type_name::<T>(), entity);
+            handle_no_such_entity(
+                world,
+                entity,
+                format!(
+                    "insert a bundle (of type `{}`)",
+                    core::any::type_name::<T>()
+                ),
+                caller,
+                Level::ERROR,
+            );
         }
     }
 }
@@ -1986,16 +2317,24 @@ fn insert_from_world<T: Component + FromWorld>(mode: InsertMode) -> impl EntityC
                 caller,
             );
         } else {
-            panic!("error[B0003]: {caller}: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003", core::any::type_name::<T>(), entity);
+            handle_no_such_entity(
+                world,
+                entity,
+                format!(
+                    "insert a bundle (of type `{}`)",
+                    core::any::type_name::<T>()
+                ),
+                caller,
+                Level::ERROR,
+            );
         }
     }
 }
 
 /// An [`EntityCommand`] that attempts to add the components in a [`Bundle`] to an entity.
-/// Does nothing if the entity does not exist.
+/// Reports the entity at [`Level::DEBUG`] if it does not exist.
 #[track_caller]
-fn try_insert(bundle: impl Bundle, mode: InsertMode) -> impl EntityCommand {
-    #[cfg(feature = "track_change_detection")]
+fn try_insert<T: Bundle>(bundle: T, mode: InsertMode) -> impl EntityCommand {
     let caller = Location::caller();
     move |entity: Entity, world: &mut World| {
         if let Ok(mut entity) = world.get_entity_mut(entity) {
@@ -2005,6 +2344,17 @@ fn try_insert(bundle: impl Bundle, mode: InsertMode) -> impl EntityCommand {
                 #[cfg(feature = "track_change_detection")]
                 caller,
             );
+        } else {
+            handle_no_such_entity(
+                world,
+                entity,
+                format!(
+                    "insert a bundle (of type `{}`)",
+                    core::any::type_name::<T>()
+                ),
+                caller,
+                Level::DEBUG,
+            );
         }
     }
 }
@@ -2018,7 +2368,7 @@ fn try_insert(bundle: impl Bundle, mode: InsertMode) -> impl EntityCommand {
 unsafe fn insert_by_id<T: Send + 'static>(
     component_id: ComponentId,
     value: T,
-    on_none_entity: impl FnOnce(Entity) + Send + 'static,
+    on_none_entity: impl FnOnce(Entity, &mut World) + Send + 'static,
 ) -> impl EntityCommand {
     move |entity: Entity, world: &mut World| {
         if let Ok(mut entity) = world.get_entity_mut(entity) {
@@ -2029,7 +2379,7 @@ unsafe fn insert_by_id<T: Send + 'static>(
                 entity.insert_by_id(component_id, ptr);
             });
         } else {
-            on_none_entity(entity);
+            on_none_entity(entity, world);
         }
     }
 }
@@ -2038,47 +2388,86 @@ unsafe fn insert_by_id<T: Send + 'static>(
 ///
 /// For a [`Bundle`] type `T`, this will remove any components in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
-fn remove<T: Bundle>(entity: Entity, world: &mut World) {
-    if let Ok(mut entity) = world.get_entity_mut(entity) {
-        entity.remove::<T>();
-    }
+#[track_caller]
+fn remove<T: Bundle>() -> impl EntityCommand {
+    with_existing_entity(
+        format!(
+            "remove a bundle (of type `{}`)",
+            core::any::type_name::<T>()
+        ),
+        |mut entity| {
+            entity.remove::<T>();
+        },
+    )
 }
 
 /// An [`EntityCommand`] that removes components with a provided [`ComponentId`] from an entity.
 /// # Panics
 ///
 /// Panics if the provided [`ComponentId`] does not exist in the [`World`].
+#[track_caller]
 fn remove_by_id(component_id: ComponentId) -> impl EntityCommand {
-    move |entity: Entity, world: &mut World| {
-        if let Ok(mut entity) = world.get_entity_mut(entity) {
+    with_existing_entity(
+        format!("remove a component {component_id:?}"),
+        move |mut entity| {
             entity.remove_by_id(component_id);
-        }
-    }
+        },
+    )
 }
 
 /// An [`EntityCommand`] that remove all components in the bundle and remove all required components for each component in the bundle.
-fn remove_with_requires<T: Bundle>(entity: Entity, world: &mut World) {
-    if let Ok(mut entity) = world.get_entity_mut(entity) {
-        entity.remove_with_requires::<T>();
-    }
+#[track_caller]
+fn remove_with_requires<T: Bundle>() -> impl EntityCommand {
+    with_existing_entity(
+        format!(
+            "remove a bundle (of type `{}`) with its required components",
+            core::any::type_name::<T>()
+        ),
+        |mut entity| {
+            entity.remove_with_requires::<T>();
+        },
+    )
 }
 
 /// An [`EntityCommand`] that removes all components associated with a provided entity.
+#[track_caller]
 fn clear() -> impl EntityCommand {
-    move |entity: Entity, world: &mut World| {
-        if let Ok(mut entity) = world.get_entity_mut(entity) {
-            entity.clear();
-        }
-    }
+    with_existing_entity("clear the components", |mut entity| {
+        entity.clear();
+    })
 }
 
 /// An [`EntityCommand`] that removes components from an entity.
 ///
 /// For a [`Bundle`] type `T`, this will remove all components except those in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
-fn retain<T: Bundle>(entity: Entity, world: &mut World) {
-    if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
-        entity_mut.retain::<T>();
+#[track_caller]
+fn retain<T: Bundle>() -> impl EntityCommand {
+    with_existing_entity(
+        format!(
+            "retain a bundle (of type `{}`)",
+            core::any::type_name::<T>()
+        ),
+        |mut entity| {
+            entity.retain::<T>();
+        },
+    )
+}
+
+/// An [`EntityCommand`] that runs `f` on the entity if it exists, and otherwise reports it at [`Level::DEBUG`],
+/// for commands that are expected to target despawned entities sometimes.
+#[track_caller]
+fn with_existing_entity(
+    action: impl Into<alloc::borrow::Cow<'static, str>> + Send + 'static,
+    f: impl FnOnce(EntityWorldMut) + Send + 'static,
+) -> impl EntityCommand {
+    let caller = Location::caller();
+    move |entity: Entity, world: &mut World| {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            f(entity);
+        } else {
+            handle_no_such_entity(world, entity, action, caller, Level::DEBUG);
+        }
     }
 }
 
@@ -2110,12 +2499,38 @@ fn insert_resource<R: Resource>(resource: R) -> impl Command {
 }
 
 /// [`EntityCommand`] to log the components of a given entity. See [`EntityCommands::log_components`].
-fn log_components(entity: Entity, world: &mut World) {
-    let debug_infos: Vec<_> = world
-        .inspect_entity(entity)
-        .map(ComponentInfo::name)
-        .collect();
-    info!("Entity {entity}: {debug_infos:?}");
+#[track_caller]
+fn log_components() -> impl EntityCommand {
+    let caller = Location::caller();
+    move |entity: Entity, world: &mut World| {
+        if world.get_entity(entity).is_err() {
+            handle_no_such_entity(world, entity, "log the components", caller, Level::ERROR);
+            return;
+        }
+        let debug_infos: Vec<_> = world
+            .inspect_entity(entity)
+            .map(ComponentInfo::name)
+            .collect();
+        info!("Entity {entity}: {debug_infos:?}");
+    }
+}
+
+/// An [`EntityCommand`] that clones an entity into `target`, using `config` to customize the [`EntityCloneBuilder`].
+#[track_caller]
+fn clone_entity(
+    target: Entity,
+    config: impl FnOnce(&mut EntityCloneBuilder) + Send + Sync + 'static,
+) -> impl EntityCommand {
+    let caller = Location::caller();
+    move |entity: Entity, world: &mut World| {
+        if world.get_entity(entity).is_err() {
+            handle_no_such_entity(world, entity, "clone entity", caller, Level::ERROR);
+            return;
+        }
+        let mut builder = EntityCloneBuilder::new(world);
+        config(&mut builder);
+        builder.clone_entity(entity, target);
+    }
 }
 
 fn observe<E: Event, B: Bundle, M>(
@@ -2128,6 +2543,23 @@ fn observe<E: Event, B: Bundle, M>(
     }
 }
 
+/// An [`EntityCommand`] that spawns an async task owned by an entity.
+#[track_caller]
+fn spawn_task<F, Fut>(task: F) -> impl EntityCommand
+where
+    F: FnOnce(AsyncWorld) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let caller = Location::caller();
+    move |entity: Entity, world: &mut World| {
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.spawn_task(task);
+        } else {
+            handle_no_such_entity(world, entity, "spawn a task", caller, Level::ERROR);
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::float_cmp, clippy::approx_constant)]
 mod tests {
@@ -2347,7 +2779,7 @@ mod tests {
             .iter(&world)
             .map(|v| v.0)
             .collect::<Vec<_>>();
-        assert_eq!(results_after_u64, vec![]);
+        assert!(results_after_u64.is_empty());
     }
 
     #[test]
@@ -2400,7 +2832,7 @@ mod tests {
             .iter(&world)
             .map(|v| v.0)
             .collect::<Vec<_>>();
-        assert_eq!(results_after_u64, vec![]);
+        assert!(results_after_u64.is_empty());
     }
 
     #[test]
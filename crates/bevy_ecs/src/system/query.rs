@@ -3,8 +3,9 @@ use crate::{
     component::Tick,
     entity::Entity,
     query::{
-        QueryCombinationIter, QueryData, QueryEntityError, QueryFilter, QueryIter, QueryManyIter,
-        QueryParIter, QuerySingleError, QueryState, ROQueryItem, ReadOnlyQueryData,
+        ArchetypeFilter, ContiguousQueryData, QueryChunkIter, QueryCombinationIter, QueryData,
+        QueryEntityError, QueryFilter, QueryIter, QueryManyIter, QueryParIter, QuerySingleError,
+        QueryState, ROQueryItem, ReadOnlyQueryData,
     },
     world::unsafe_world_cell::UnsafeWorldCell,
 };
@@ -521,6 +522,105 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the matched tables, yielding the read-only slices of all
+    /// entities in each table at once.
+    ///
+    /// Each item is a tuple (or single value) of contiguous slices, all of the same length and
+    /// in the same entity order: `&[Entity]` for [`Entity`], `&[T]` for `&T`, and a
+    /// [`ContiguousRef<T>`](crate::query::ContiguousRef) exposing the added and changed ticks for
+    /// [`Ref<T>`](crate::change_detection::Ref). Empty tables are skipped.
+    ///
+    /// Only filters that are evaluated per table, such as [`With`](crate::query::With) and
+    /// [`Without`](crate::query::Without), can be used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query accesses components stored in sparse sets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Mass(f32);
+    /// fn total_mass_system(query: Query<&Mass>) {
+    ///     let mut total = 0.0;
+    ///     for masses in query.iter_chunks() {
+    ///         total += masses.iter().map(|mass| mass.0).sum::<f32>();
+    ///     }
+    ///     println!("Total mass: {total}");
+    /// }
+    /// # bevy_ecs::system::assert_is_system(total_mass_system);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// [`iter_chunks_mut`](Self::iter_chunks_mut) for mutable slices.
+    #[inline]
+    pub fn iter_chunks(&self) -> QueryChunkIter<'_, 's, D::ReadOnly, F>
+    where
+        D::ReadOnly: ContiguousQueryData,
+        F: ArchetypeFilter,
+    {
+        // SAFETY: `self.world` has permission to access the required components, and the query is
+        // read-only.
+        unsafe {
+            self.state.as_readonly().iter_chunks_unchecked_manual(
+                self.world,
+                self.last_run,
+                self.this_run,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over the matched tables, yielding the slices of all entities in
+    /// each table at once.
+    ///
+    /// This works like [`iter_chunks`](Self::iter_chunks), but `&mut T` is fetched as a
+    /// [`ContiguousMut<T>`](crate::query::ContiguousMut). Mutably dereferencing it to `&mut [T]`
+    /// marks every value in the table as changed, while
+    /// [`ContiguousMut::iter_mut`](crate::query::ContiguousMut::iter_mut) only marks the values
+    /// that are written to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query accesses components stored in sparse sets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Position(f32);
+    /// # #[derive(Component)]
+    /// # struct Velocity(f32);
+    /// fn integrate_system(mut query: Query<(&mut Position, &Velocity)>) {
+    ///     const DELTA: f32 = 1.0 / 60.0;
+    ///     for (mut positions, velocities) in query.iter_chunks_mut() {
+    ///         // Marks every position in the table as changed.
+    ///         let positions: &mut [Position] = &mut positions;
+    ///         for (position, velocity) in positions.iter_mut().zip(velocities) {
+    ///             position.0 += velocity.0 * DELTA;
+    ///         }
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(integrate_system);
+    /// ```
+    #[inline]
+    pub fn iter_chunks_mut(&mut self) -> QueryChunkIter<'_, 's, D, F>
+    where
+        D: ContiguousQueryData,
+        F: ArchetypeFilter,
+    {
+        // SAFETY: `self.world` has permission to access the required components.
+        unsafe {
+            self.state
+                .iter_chunks_unchecked_manual(self.world, self.last_run, self.this_run)
+        }
+    }
+
     /// Returns a [`QueryCombinationIter`] over all combinations of `K` read-only query items without repetition.
     ///
     /// This iterator is always guaranteed to return results from each unique pair of matching entities.
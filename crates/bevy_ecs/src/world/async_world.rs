@@ -0,0 +1,414 @@
+use alloc::sync::{Arc, Weak};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use bevy_tasks::{AsyncComputeTaskPool, Task, TaskPool};
+
+use crate::{
+    self as bevy_ecs,
+    component::Component,
+    entity::Entity,
+    system::Resource,
+    world::{CommandQueue, EntityWorldMut, World},
+};
+
+/// The [`CommandQueue`] that async tasks use to run closures against the [`World`].
+///
+/// Closures sent through an [`AsyncWorld`] are stored here until the queue is applied, either
+/// with [`AsyncWorldQueue::apply`] or by the [`apply_async_world_queue`] system. The point in
+/// the schedule where that system runs is the sync point of all async tasks.
+#[derive(Resource, Clone, Default)]
+pub struct AsyncWorldQueue(Arc<Mutex<CommandQueue>>);
+
+impl AsyncWorldQueue {
+    /// Returns a handle to send closures to this queue, which isn't owned by any entity.
+    pub fn handle(&self) -> AsyncWorld {
+        AsyncWorld {
+            queue: self.0.clone(),
+            owner: None,
+        }
+    }
+
+    /// Returns `true` if there are no closures waiting to run.
+    pub fn is_empty(&self) -> bool {
+        lock(&self.0).is_empty()
+    }
+
+    /// Runs all closures that have been sent to this queue so far against `world`.
+    ///
+    /// Closures sent while the queue is being applied run the next time it is applied.
+    pub fn apply(&self, world: &mut World) {
+        let mut queue = core::mem::take(&mut *lock(&self.0));
+        queue.apply(world);
+    }
+}
+
+/// Applies the [`AsyncWorldQueue`] of the world, if it exists.
+///
+/// This is the sync point at which closures sent by async tasks run.
+pub fn apply_async_world_queue(world: &mut World) {
+    if let Some(queue) = world.get_resource::<AsyncWorldQueue>() {
+        queue.clone().apply(world);
+    }
+}
+
+/// A handle given to async tasks to run closures against the [`World`].
+///
+/// Created by [`World::spawn_task`] and [`EntityWorldMut::spawn_task`]. Closures passed to
+/// [`AsyncWorld::run`] are sent to the [`AsyncWorldQueue`], and run the next time it is applied.
+#[derive(Clone)]
+pub struct AsyncWorld {
+    queue: Arc<Mutex<CommandQueue>>,
+    owner: Option<Entity>,
+}
+
+impl AsyncWorld {
+    /// Returns the entity owning the task this handle was created for, if any.
+    pub fn owner(&self) -> Option<Entity> {
+        self.owner
+    }
+
+    /// Sends `f` to run against the [`World`] at the next sync point, and returns a [`Future`]
+    /// resolving to its result.
+    ///
+    /// `f` doesn't run if the returned future is dropped before the sync point, or if the task
+    /// is owned by an entity that has been despawned.
+    pub fn run<R, F>(&self, f: F) -> AsyncWorldRun<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut World) -> R + Send + 'static,
+    {
+        let slot = Arc::new(Mutex::new(RunSlot {
+            result: None,
+            waker: None,
+        }));
+        let weak: Weak<Mutex<RunSlot<R>>> = Arc::downgrade(&slot);
+        let owner = self.owner;
+        lock(&self.queue).push(move |world: &mut World| {
+            // The future was dropped, so the task has been cancelled.
+            let Some(slot) = weak.upgrade() else {
+                return;
+            };
+            if owner.is_some_and(|owner| !world.entities().contains(owner)) {
+                return;
+            }
+            let result = f(world);
+            let mut slot = lock(&slot);
+            slot.result = Some(result);
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        });
+        AsyncWorldRun { slot }
+    }
+}
+
+struct RunSlot<R> {
+    result: Option<R>,
+    waker: Option<Waker>,
+}
+
+/// A [`Future`] resolving to the result of a closure sent with [`AsyncWorld::run`].
+pub struct AsyncWorldRun<R> {
+    slot: Arc<Mutex<RunSlot<R>>>,
+}
+
+impl<R> Future for AsyncWorldRun<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut slot = lock(&self.slot);
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// The async tasks owned by an entity, spawned with [`EntityWorldMut::spawn_task`].
+///
+/// Tasks are removed from this component at the sync point after they finish. Despawning the
+/// entity or removing this component cancels all of its unfinished tasks.
+#[derive(Component, Default)]
+pub struct AsyncTasks {
+    tasks: Vec<(u64, Task<()>)>,
+    next_id: u64,
+}
+
+impl AsyncTasks {
+    /// Returns the number of tasks that haven't finished, or have finished since the last sync
+    /// point.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if there are no running tasks.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+impl World {
+    /// Spawns an async task on the [`AsyncComputeTaskPool`], which can access the world through
+    /// the [`AsyncWorld`] passed to `task`.
+    ///
+    /// The task isn't owned by any entity, and runs to completion. Closures sent by the task run
+    /// whenever the [`AsyncWorldQueue`] is applied, see [`apply_async_world_queue`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, world::apply_async_world_queue};
+    /// # use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPool};
+    /// # ComputeTaskPool::get_or_init(TaskPool::default);
+    /// # AsyncComputeTaskPool::get_or_init(TaskPool::default);
+    /// # IoTaskPool::get_or_init(TaskPool::default);
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(0));
+    /// world.spawn_task(|world| async move {
+    ///     let score = world.run(|world| world.resource::<Score>().0).await;
+    ///     world
+    ///         .run(move |world| world.resource_mut::<Score>().0 = score + 10)
+    ///         .await;
+    /// });
+    ///
+    /// // Each call is a sync point, running the closures sent since the last one.
+    /// # for _ in 0..10_000 {
+    /// #     bevy_tasks::tick_global_task_pools_on_main_thread();
+    /// apply_async_world_queue(&mut world);
+    /// #     if world.resource::<Score>().0 == 10 { break; }
+    /// #     std::thread::yield_now();
+    /// # }
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// ```
+    pub fn spawn_task<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let world = self.get_resource_or_init::<AsyncWorldQueue>().handle();
+        AsyncComputeTaskPool::get_or_init(TaskPool::default)
+            .spawn(task(world))
+            .detach();
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Spawns an async task owned by this entity on the [`AsyncComputeTaskPool`], which can
+    /// access the world through the [`AsyncWorld`] passed to `task`.
+    ///
+    /// The task is stored in the [`AsyncTasks`] component of this entity, and is cancelled when
+    /// the entity is despawned. See [`World::spawn_task`] for details.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub fn spawn_task<F, Fut>(&mut self, task: F) -> &mut Self
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let entity = self.id();
+        let world = AsyncWorld {
+            owner: Some(entity),
+            ..self.world_scope(|world| world.get_resource_or_init::<AsyncWorldQueue>().handle())
+        };
+        let mut tasks = self.entry::<AsyncTasks>().or_default();
+        let id = tasks.next_id;
+        tasks.next_id += 1;
+
+        let finished = world.clone();
+        let future = task(world);
+        let task = AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(async move {
+            future.await;
+            // Forget the finished task at the next sync point.
+            lock(&finished.queue).push(move |world: &mut World| {
+                if let Some(mut tasks) = world
+                    .get_entity_mut(entity)
+                    .ok()
+                    .and_then(EntityWorldMut::into_mut::<AsyncTasks>)
+                {
+                    tasks.tasks.retain(|(task_id, _)| *task_id != id);
+                }
+            });
+        });
+        tasks.tasks.push((id, task));
+        self
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::Events,
+        query::With,
+        system::{CommandError, CommandErrorHandler, CommandFailed, Commands, RunSystemOnce},
+    };
+    use bevy_tasks::{block_on, poll_once, ComputeTaskPool, IoTaskPool};
+    use core::pin::pin;
+    use std::sync::mpsc;
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    #[derive(Component)]
+    struct Owner;
+
+    fn tick_task_pools() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        IoTaskPool::get_or_init(TaskPool::default);
+        bevy_tasks::tick_global_task_pools_on_main_thread();
+    }
+
+    /// Blocks until `task` finishes, ticking the local executors first in case the task pools
+    /// are single-threaded.
+    fn finish(task: &mut Task<()>) {
+        block_on(core::future::poll_fn(|cx| {
+            tick_task_pools();
+            Pin::new(&mut *task).poll(cx)
+        }));
+    }
+
+    #[test]
+    fn run_closures_at_sync_point() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let queue = world.get_resource_or_init::<AsyncWorldQueue>().clone();
+        let async_world = queue.handle();
+        let mut task = pin!(async move {
+            for _ in 0..3 {
+                async_world
+                    .run(|world| world.resource_mut::<Counter>().0 += 1)
+                    .await;
+            }
+        });
+
+        // Each sync point runs at most one closure, since the task waits for its result.
+        for count in 1..=3 {
+            assert!(block_on(poll_once(task.as_mut())).is_none());
+            assert_eq!(world.resource::<Counter>().0, count - 1);
+            queue.apply(&mut world);
+            assert_eq!(world.resource::<Counter>().0, count);
+        }
+        assert!(block_on(poll_once(task.as_mut())).is_some());
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn dropped_runs_are_skipped() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let queue = world.get_resource_or_init::<AsyncWorldQueue>().clone();
+
+        drop(
+            queue
+                .handle()
+                .run(|world| world.resource_mut::<Counter>().0 += 1),
+        );
+        queue.apply(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 0);
+    }
+
+    #[test]
+    fn finished_tasks_are_removed() {
+        let mut world = World::new();
+        let (sender, receiver) = mpsc::channel();
+        let entity = world
+            .spawn(Owner)
+            .spawn_task(move |world| async move {
+                sender.send(world.owner()).unwrap();
+            })
+            .id();
+        assert_eq!(world.get::<AsyncTasks>(entity).unwrap().len(), 1);
+
+        finish(&mut world.get_mut::<AsyncTasks>(entity).unwrap().tasks[0].1);
+        assert_eq!(receiver.try_recv(), Ok(Some(entity)));
+        // The task is forgotten at the next sync point.
+        assert_eq!(world.get::<AsyncTasks>(entity).unwrap().len(), 1);
+        apply_async_world_queue(&mut world);
+        assert!(world.get::<AsyncTasks>(entity).unwrap().is_empty());
+    }
+
+    #[test]
+    fn despawned_owners_skip_closures() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let queue = world.get_resource_or_init::<AsyncWorldQueue>().clone();
+        let owner = world.spawn(Owner).id();
+        let async_world = AsyncWorld {
+            owner: Some(owner),
+            ..queue.handle()
+        };
+
+        let run = async_world.run(|world| world.resource_mut::<Counter>().0 += 1);
+        world.despawn(owner);
+        queue.apply(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 0);
+        assert!(block_on(poll_once(run)).is_none());
+    }
+
+    #[test]
+    fn despawn_cancels_owned_tasks() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world
+            .run_system_once(|mut commands: Commands| {
+                commands.spawn(Owner).spawn_task(|world| async move {
+                    loop {
+                        world
+                            .run(|world| world.resource_mut::<Counter>().0 += 1)
+                            .await;
+                    }
+                });
+            })
+            .unwrap();
+
+        let entity = world.query_filtered::<Entity, With<Owner>>().single(&world);
+        world.despawn(entity);
+        tick_task_pools();
+        apply_async_world_queue(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 0);
+    }
+
+    #[test]
+    fn spawn_task_on_missing_entity_is_reported() {
+        let mut world = World::new();
+        world.insert_resource(CommandErrorHandler::Collect);
+
+        let entity = world
+            .run_system_once(|mut commands: Commands| {
+                let entity = commands.spawn_empty().id();
+                commands.entity(entity).despawn();
+                commands.entity(entity).spawn_task(|_| async {});
+                entity
+            })
+            .unwrap();
+
+        let failures: Vec<_> = world
+            .resource::<Events<CommandFailed>>()
+            .iter_current_update_events()
+            .cloned()
+            .collect();
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(
+            failures[0].error,
+            CommandError::NoSuchEntity { entity: failed, .. } if failed == entity
+        ));
+    }
+}
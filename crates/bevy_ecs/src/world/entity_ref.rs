@@ -3,7 +3,7 @@ use crate::{
     bundle::{Bundle, BundleId, BundleInfo, BundleInserter, DynamicBundle, InsertMode},
     change_detection::MutUntyped,
     component::{Component, ComponentId, ComponentTicks, Components, StorageType},
-    entity::{Entities, Entity, EntityLocation},
+    entity::{Entities, Entity, EntityCloneBuilder, EntityLocation},
     event::Event,
     observer::{Observer, Observers},
     query::{Access, ReadOnlyQueryData},
@@ -1862,6 +1862,23 @@ impl<'w> EntityWorldMut<'w> {
             .spawn(Observer::new(observer).with_entity(self.entity));
         self
     }
+
+    /// Clones all components of this entity onto a newly spawned entity, and returns the [`Entity`]
+    /// of the clone.
+    ///
+    /// See [`World::clone_entity`] for more details.
+    pub fn clone_entity(&mut self) -> Entity {
+        self.clone_entity_with(|_| {})
+    }
+
+    /// Clones this entity onto a newly spawned entity, using `config` to customize the
+    /// [`EntityCloneBuilder`], and returns the [`Entity`] of the clone.
+    ///
+    /// See [`World::clone_entity_with`] for more details.
+    pub fn clone_entity_with(&mut self, config: impl FnOnce(&mut EntityCloneBuilder)) -> Entity {
+        let source = self.entity;
+        self.world_scope(|world| world.clone_entity_with(source, config))
+    }
 }
 
 /// # Safety
@@ -3467,7 +3484,7 @@ mod tests {
         let b = vec![1];
         super::sorted_remove(&mut a, &b);
 
-        assert_eq!(a, vec![]);
+        assert!(a.is_empty());
 
         let mut a = vec![1];
         let b = vec![2];
@@ -1,5 +1,6 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+mod async_world;
 pub(crate) mod command_queue;
 mod component_constants;
 mod deferred_world;
@@ -18,6 +19,7 @@ pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
     world::command_queue::CommandQueue,
 };
+pub use async_world::*;
 pub use component_constants::*;
 pub use deferred_world::DeferredWorld;
 pub use entity_fetch::WorldEntityFetch;
@@ -34,10 +36,15 @@ use crate::{
     bundle::{Bundle, BundleInfo, BundleInserter, BundleSpawner, Bundles, InsertMode},
     change_detection::{MutUntyped, TicksMut},
     component::{
-        Component, ComponentDescriptor, ComponentHooks, ComponentId, ComponentInfo, ComponentTicks,
-        Components, RequiredComponents, RequiredComponentsError, Tick,
+        Component, ComponentCloneHandlers, ComponentDescriptor, ComponentHooks, ComponentId,
+        ComponentInfo, ComponentTicks, Components, RequiredComponents, RequiredComponentsError,
+        Tick,
     },
-    entity::{AllocAtWithoutReplacement, Entities, Entity, EntityHashSet, EntityLocation},
+    entity::{
+        AllocAtWithoutReplacement, Entities, Entity, EntityCloneBuilder, EntityHashSet,
+        EntityLocation,
+    },
+    entity_disabling::DefaultQueryFilters,
     event::{Event, EventId, Events, SendBatchIds},
     observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryEntityError, QueryFilter, QueryState},
@@ -92,13 +99,44 @@ use unsafe_world_cell::{UnsafeEntityCell, UnsafeWorldCell};
 ///     commands.queue(AddToCounter(42));
 /// }
 /// ```
-pub trait Command: Send + 'static {
+///
+/// # Errors
+///
+/// Commands that can fail should use `Result<(), CommandError>` as their output type `Out`.
+/// When queued through [`Commands::queue_fallible`], their errors are passed to the world's
+/// [`CommandErrorHandler`], which panics by default but can be configured to log or collect them.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::{system::CommandError, world::Command};
+/// struct Heal(Entity);
+///
+/// impl Command<Result<(), CommandError>> for Heal {
+///     fn apply(self, world: &mut World) -> Result<(), CommandError> {
+///         let Ok(mut entity) = world.get_entity_mut(self.0) else {
+///             return Err(CommandError::no_such_entity(self.0, "heal"));
+///         };
+///         // ...
+///         Ok(())
+///     }
+/// }
+///
+/// fn heal_system(mut commands: Commands, target: Single<Entity, With<Name>>) {
+///     commands.queue_fallible(Heal(*target));
+/// }
+/// # #[derive(Component)]
+/// # struct Name;
+/// # bevy_ecs::system::assert_is_system(heal_system);
+/// ```
+///
+/// [`CommandErrorHandler`]: crate::system::CommandErrorHandler
+pub trait Command<Out = ()>: Send + 'static {
     /// Applies this command, causing it to mutate the provided `world`.
     ///
     /// This method is used to define what a command "does" when it is ultimately applied.
     /// Because this method takes `self`, you can store data or settings on the type that implements this trait.
     /// This data is set by the system or other source of the command, and then ultimately read in this method.
-    fn apply(self, world: &mut World);
+    fn apply(self, world: &mut World) -> Out;
 }
 
 /// Stores and exposes operations on [entities](Entity), [components](Component), resources,
@@ -134,6 +172,9 @@ pub struct World {
     pub(crate) last_check_tick: Tick,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
+    /// The location the [`EntityCommand`](crate::system::EntityCommand) being applied was queued from,
+    /// reported as the caller of failures that don't know their own.
+    pub(crate) command_caller: Option<&'static Location<'static>>,
 }
 
 impl Default for World {
@@ -154,6 +195,7 @@ impl Default for World {
             last_check_tick: Tick::new(0),
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
+            command_caller: None,
         };
         world.bootstrap();
         world
@@ -182,6 +224,7 @@ impl World {
         assert_eq!(ON_INSERT, self.register_component::<OnInsert>());
         assert_eq!(ON_REPLACE, self.register_component::<OnReplace>());
         assert_eq!(ON_REMOVE, self.register_component::<OnRemove>());
+        self.init_resource::<DefaultQueryFilters>();
     }
     /// Creates a new empty [`World`].
     ///
@@ -293,6 +336,12 @@ impl World {
         self.components.get_hooks_mut(id)
     }
 
+    /// Returns a mutable reference to the [`ComponentCloneHandlers`], which can be used to set and
+    /// update the functions used to clone components when entities are cloned.
+    pub fn get_component_clone_handlers_mut(&mut self) -> &mut ComponentCloneHandlers {
+        self.components.get_component_clone_handlers_mut()
+    }
+
     /// Registers the given component `R` as a [required component] for `T`.
     ///
     /// When `T` is added to an entity, `R` and its own required components will also be added
@@ -1535,6 +1584,65 @@ impl World {
         }
     }
 
+    /// Clones all components of the `source` entity onto a newly spawned entity, and returns the
+    /// [`Entity`] of the clone.
+    ///
+    /// Components are cloned according to their [`ComponentCloneHandler`](crate::component::ComponentCloneHandler),
+    /// see [`EntityCloneBuilder`] for more details. Use [`World::clone_entity_with`] to configure
+    /// which components are cloned and how.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `source` entity does not exist.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Health(10)).id();
+    /// let clone = world.clone_entity(entity);
+    /// assert_eq!(world.get::<Health>(clone), Some(&Health(10)));
+    /// ```
+    pub fn clone_entity(&mut self, source: Entity) -> Entity {
+        self.clone_entity_with(source, |_| {})
+    }
+
+    /// Clones the `source` entity onto a newly spawned entity, using `config` to customize the
+    /// [`EntityCloneBuilder`], and returns the [`Entity`] of the clone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `source` entity does not exist.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Health(u32);
+    /// #[derive(Component, Clone)]
+    /// struct Player;
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn((Health(10), Player)).id();
+    /// let clone = world.clone_entity_with(entity, |builder| {
+    ///     builder.deny::<Player>();
+    /// });
+    /// assert_eq!(world.get::<Health>(clone), Some(&Health(10)));
+    /// assert!(world.get::<Player>(clone).is_none());
+    /// ```
+    pub fn clone_entity_with(
+        &mut self,
+        source: Entity,
+        config: impl FnOnce(&mut EntityCloneBuilder),
+    ) -> Entity {
+        let target = self.spawn_empty().id();
+        let mut builder = EntityCloneBuilder::new(self);
+        config(&mut builder);
+        builder.clone_entity(source, target);
+        target
+    }
+
     /// Clears the internal component tracker state.
     ///
     /// The world maintains some internal state about changed and removed components. This state
@@ -2684,13 +2792,16 @@ impl World {
     /// - [`World::try_insert_batch_if_new`]
     /// - [`Commands::try_insert_batch`]
     /// - [`Commands::try_insert_batch_if_new`]
+    ///
+    /// Returns the entities that did not exist, in the order they were encountered.
     #[inline]
     pub(crate) fn try_insert_batch_with_caller<I, B>(
         &mut self,
         iter: I,
         insert_mode: InsertMode,
         #[cfg(feature = "track_change_detection")] caller: &'static Location,
-    ) where
+    ) -> Vec<Entity>
+    where
         I: IntoIterator,
         I::IntoIter: Iterator<Item = (Entity, B)>,
         B: Bundle,
@@ -2709,8 +2820,9 @@ impl World {
         }
 
         let mut batch = iter.into_iter();
+        let mut invalid_entities = Vec::new();
 
-        if let Some((first_entity, first_bundle)) = batch.next() {
+        while let Some((first_entity, first_bundle)) = batch.next() {
             if let Some(first_location) = self.entities().get(first_entity) {
                 let mut cache = InserterArchetypeCache {
                     // SAFETY: we initialized this bundle_id in `register_info`
@@ -2763,10 +2875,16 @@ impl World {
                                 caller,
                             )
                         };
+                    } else {
+                        invalid_entities.push(entity);
                     }
                 }
+                break;
             }
+            invalid_entities.push(first_entity);
         }
+
+        invalid_entities
     }
 
     /// Temporarily removes the requested resource from this [`World`], runs custom user code,
@@ -3314,6 +3432,7 @@ impl World {
     /// # struct B(u32);
     /// #
     /// # let mut world = World::new();
+    /// # world.remove_resource::<bevy_ecs::entity_disabling::DefaultQueryFilters>();
     /// # world.insert_resource(A(1));
     /// # world.insert_resource(B(2));
     /// let mut total = 0;
@@ -3793,6 +3912,7 @@ mod tests {
         change_detection::DetectChangesMut,
         component::{ComponentDescriptor, ComponentInfo, StorageType},
         entity::EntityHashSet,
+        entity_disabling::DefaultQueryFilters,
         ptr::OwningPtr,
         system::Resource,
         world::error::EntityFetchError,
@@ -3976,6 +4096,8 @@ mod tests {
     #[test]
     fn iter_resources() {
         let mut world = World::new();
+        // Remove the resources every `World` starts with, so only the ones inserted below are iterated.
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
@@ -4002,6 +4124,8 @@ mod tests {
     #[test]
     fn iter_resources_mut() {
         let mut world = World::new();
+        // Remove the resources every `World` starts with, so only the ones inserted below are iterated.
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
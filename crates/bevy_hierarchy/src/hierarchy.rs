@@ -1,8 +1,12 @@
-use crate::components::{Children, Parent};
+use crate::{
+    components::{Children, Parent},
+    BuildChildren,
+};
 use bevy_ecs::{
-    entity::Entity,
+    component::ComponentCloneHandler,
+    entity::{Entity, EntityCloneBuilder, EntityCloner},
     system::EntityCommands,
-    world::{Command, EntityWorldMut, World},
+    world::{Command, DeferredWorld, EntityWorldMut, World},
 };
 use bevy_utils::tracing::debug;
 
@@ -198,6 +202,73 @@ impl<'w> DespawnRecursiveExt for EntityWorldMut<'w> {
     }
 }
 
+/// Trait that holds functions for cloning entities with their hierarchy.
+pub trait CloneEntityHierarchyExt {
+    /// Sets the option to recursively clone entities.
+    /// When set to true all children will be cloned with the same options as the parent,
+    /// and added as children of the parent's clone.
+    ///
+    /// [`Entity`] references between the cloned entities are remapped to their clones through
+    /// [`ReflectMapEntities`](bevy_ecs::reflect::ReflectMapEntities).
+    fn recursive(&mut self, recursive: bool) -> &mut Self;
+
+    /// Sets the option to add the cloned entity as a child to the parent entity.
+    fn as_child(&mut self, as_child: bool) -> &mut Self;
+}
+
+impl CloneEntityHierarchyExt for EntityCloneBuilder<'_> {
+    fn recursive(&mut self, recursive: bool) -> &mut Self {
+        if recursive {
+            self.override_component_clone_handler::<Children>(ComponentCloneHandler::Custom(
+                component_clone_children,
+            ))
+        } else {
+            self.remove_component_clone_handler_override::<Children>()
+        }
+    }
+
+    fn as_child(&mut self, as_child: bool) -> &mut Self {
+        if as_child {
+            self.override_component_clone_handler::<Parent>(ComponentCloneHandler::Custom(
+                component_clone_parent,
+            ))
+        } else {
+            self.remove_component_clone_handler_override::<Parent>()
+        }
+    }
+}
+
+/// Clone handler for the [`Children`] component. Allows to clone the entity recursively.
+fn component_clone_children(world: &mut DeferredWorld, entity_cloner: &EntityCloner) {
+    let Some(children) = world.get::<Children>(entity_cloner.source()) else {
+        return;
+    };
+    let children: Vec<Entity> = children.iter().copied().collect();
+    let parent = entity_cloner.target();
+    for child in children {
+        let child_clone = world.commands().spawn_empty().id();
+        let mut entity_cloner = entity_cloner.with_source_and_target(child, child_clone);
+        world.commands().queue(move |world: &mut World| {
+            entity_cloner.clone_entity(world);
+            world.entity_mut(child_clone).set_parent(parent);
+        });
+    }
+}
+
+/// Clone handler for the [`Parent`] component. Allows to add the clone as a child to the parent entity.
+///
+/// If the parent was itself cloned as part of the same operation, the clone is added to the parent's clone instead.
+fn component_clone_parent(world: &mut DeferredWorld, entity_cloner: &EntityCloner) {
+    let Some(parent) = world.get::<Parent>(entity_cloner.source()).map(Parent::get) else {
+        return;
+    };
+    let parent = entity_cloner.mapped_entity(parent).unwrap_or(parent);
+    world
+        .commands()
+        .entity(entity_cloner.target())
+        .set_parent(parent);
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ecs::{
@@ -206,10 +277,10 @@ mod tests {
         world::{CommandQueue, World},
     };
 
-    use super::DespawnRecursiveExt;
+    use super::{CloneEntityHierarchyExt, DespawnRecursiveExt};
     use crate::{
         child_builder::{BuildChildren, ChildBuild},
-        components::Children,
+        components::{Children, Parent},
     };
 
     #[derive(Component, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug)]
@@ -342,4 +413,55 @@ mod tests {
         // The original child should be despawned.
         assert!(world.get_entity(child).is_err());
     }
+
+    #[test]
+    fn clone_entity_recursive() {
+        let mut world = World::default();
+        let root = world.spawn(Idx(0)).id();
+        let child = world.spawn(Idx(1)).set_parent(root).id();
+        let grandchild = world.spawn(Idx(2)).set_parent(child).id();
+
+        let root_clone = world.clone_entity_with(root, |builder| {
+            builder.recursive(true);
+        });
+
+        let child_clone = world.get::<Children>(root_clone).unwrap()[0];
+        let grandchild_clone = world.get::<Children>(child_clone).unwrap()[0];
+
+        assert_ne!(child_clone, child);
+        assert_ne!(grandchild_clone, grandchild);
+        assert_eq!(world.get::<Idx>(child_clone), Some(&Idx(1)));
+        assert_eq!(world.get::<Idx>(grandchild_clone), Some(&Idx(2)));
+        assert_eq!(world.get::<Parent>(child_clone).unwrap().get(), root_clone);
+        assert_eq!(
+            world.get::<Parent>(grandchild_clone).unwrap().get(),
+            child_clone
+        );
+        // The original hierarchy is left untouched.
+        assert_eq!(world.get::<Children>(root).unwrap().as_ref(), &[child]);
+        assert_eq!(
+            world.get::<Children>(child).unwrap().as_ref(),
+            &[grandchild]
+        );
+    }
+
+    #[test]
+    fn clone_entity_as_child() {
+        let mut world = World::default();
+        let root = world.spawn_empty().id();
+        let child = world.spawn(Idx(1)).set_parent(root).id();
+
+        let not_child_clone = world.clone_entity(child);
+        assert!(world.get::<Parent>(not_child_clone).is_none());
+
+        let child_clone = world.clone_entity_with(child, |builder| {
+            builder.as_child(true);
+        });
+
+        assert_eq!(world.get::<Parent>(child_clone).unwrap().get(), root);
+        assert_eq!(
+            world.get::<Children>(root).unwrap().as_ref(),
+            &[child, child_clone]
+        );
+    }
 }
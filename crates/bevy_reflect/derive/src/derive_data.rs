@@ -3,7 +3,7 @@ use proc_macro2::Span;
 
 use crate::{
     container_attributes::{ContainerAttributes, FromReflectAttrs, TypePathAttrs},
-    field_attributes::FieldAttributes,
+    field_attributes::{DefaultBehavior, FieldAttributes, FieldMetadataAttrs},
     remote::RemoteType,
     result_sifter::ResultSifter,
     serialization::SerializationDataDef,
@@ -12,6 +12,7 @@ use crate::{
     where_clause_options::WhereClauseOptions,
     REFLECT_ATTRIBUTE_NAME, TYPE_NAME_ATTRIBUTE_NAME, TYPE_PATH_ATTRIBUTE_NAME,
 };
+use bevy_macro_utils::fq_std::{FQBox, FQOption};
 use quote::{quote, ToTokens};
 use syn::token::Comma;
 
@@ -521,6 +522,12 @@ impl<'a> StructField<'a> {
             #field_info::new::<#ty>(#name).with_custom_attributes(#custom_attributes)
         };
 
+        if let Some(metadata) = self.metadata_tokens(bevy_reflect_path) {
+            info.extend(quote! {
+                .with_metadata(#metadata)
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
@@ -532,6 +539,58 @@ impl<'a> StructField<'a> {
         info
     }
 
+    /// Generates a `TokenStream` for `FieldMetadata` construction,
+    /// or `None` if the field has no metadata.
+    fn metadata_tokens(&self, bevy_reflect_path: &Path) -> Option<proc_macro2::TokenStream> {
+        let FieldMetadataAttrs {
+            display_name,
+            min,
+            max,
+            step,
+            hidden,
+            read_only,
+        } = &self.attrs.metadata;
+
+        let mut metadata = Vec::new();
+        if let Some(display_name) = display_name {
+            metadata.push(quote!(.with_display_name(#display_name)));
+        }
+        if min.is_some() || max.is_some() {
+            let [min, max] = [min, max].map(|bound| match bound {
+                Some(bound) => quote!(#FQOption::Some((#bound) as f64)),
+                None => quote!(#FQOption::None),
+            });
+            metadata.push(quote!(.with_range(#min, #max)));
+        }
+        if let Some(step) = step {
+            metadata.push(quote!(.with_step((#step) as f64)));
+        }
+        if *hidden {
+            metadata.push(quote!(.with_hidden(true)));
+        }
+        if *read_only {
+            metadata.push(quote!(.with_read_only(true)));
+        }
+        match &self.attrs.default {
+            DefaultBehavior::Required => {}
+            DefaultBehavior::Default => metadata.push(quote!(.with_default())),
+            // The default function returns the remote type rather than its reflected wrapper.
+            DefaultBehavior::Func(_) if self.attrs.remote.is_some() => {}
+            DefaultBehavior::Func(func) => {
+                let ty = &self.data.ty;
+                metadata.push(quote!(.with_default_fn(|| #FQBox::<#ty>::new(#func()))));
+            }
+        }
+
+        if metadata.is_empty() {
+            return None;
+        }
+
+        Some(quote! {
+            #bevy_reflect_path::FieldMetadata::default() #(#metadata)*
+        })
+    }
+
     /// Returns the reflected type of this field.
     ///
     /// Normally this is just the field's defined type.
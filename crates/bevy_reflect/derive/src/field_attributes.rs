@@ -9,13 +9,18 @@ use crate::{
     REFLECT_ATTRIBUTE_NAME,
 };
 use quote::ToTokens;
-use syn::{parse::ParseStream, Attribute, LitStr, Meta, Token, Type};
+use syn::{parse::ParseStream, Attribute, Expr, LitStr, Meta, RangeLimits, Token, Type};
 
 mod kw {
     syn::custom_keyword!(ignore);
     syn::custom_keyword!(skip_serializing);
     syn::custom_keyword!(default);
     syn::custom_keyword!(remote);
+    syn::custom_keyword!(display_name);
+    syn::custom_keyword!(range);
+    syn::custom_keyword!(step);
+    syn::custom_keyword!(hidden);
+    syn::custom_keyword!(read_only);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
@@ -80,6 +85,19 @@ pub(crate) struct FieldAttributes {
     pub custom_attributes: CustomAttributes,
     /// For defining the remote wrapper type that should be used in place of the field for reflection logic.
     pub remote: Option<Type>,
+    /// Well-known metadata for editors, such as `#[reflect(display_name = "...")]`.
+    pub metadata: FieldMetadataAttrs,
+}
+
+/// The well-known metadata attributes of a field, stored in its `FieldMetadata`.
+#[derive(Default, Clone)]
+pub(crate) struct FieldMetadataAttrs {
+    pub display_name: Option<LitStr>,
+    pub min: Option<Expr>,
+    pub max: Option<Expr>,
+    pub step: Option<Expr>,
+    pub hidden: bool,
+    pub read_only: bool,
 }
 
 impl FieldAttributes {
@@ -125,6 +143,20 @@ impl FieldAttributes {
             self.parse_default(input)
         } else if lookahead.peek(kw::remote) {
             self.parse_remote(input)
+        } else if lookahead.peek(kw::display_name) {
+            self.parse_display_name(input)
+        } else if lookahead.peek(kw::range) {
+            self.parse_range(input)
+        } else if lookahead.peek(kw::step) {
+            self.parse_step(input)
+        } else if lookahead.peek(kw::hidden) {
+            input.parse::<kw::hidden>()?;
+            self.metadata.hidden = true;
+            Ok(())
+        } else if lookahead.peek(kw::read_only) {
+            input.parse::<kw::read_only>()?;
+            self.metadata.read_only = true;
+            Ok(())
         } else {
             Err(lookahead.error())
         }
@@ -217,6 +249,66 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `display_name` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(display_name = "Max Health")]`
+    fn parse_display_name(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.metadata.display_name.is_some() {
+            return Err(input.error("display name already specified"));
+        }
+
+        input.parse::<kw::display_name>()?;
+        input.parse::<Token![=]>()?;
+
+        self.metadata.display_name = Some(input.parse()?);
+        Ok(())
+    }
+
+    /// Parse `range` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(range = 0.0..=1.0)]`
+    /// - `#[reflect(range = 0..)]`
+    fn parse_range(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.metadata.min.is_some() || self.metadata.max.is_some() {
+            return Err(input.error("range already specified"));
+        }
+
+        input.parse::<kw::range>()?;
+        input.parse::<Token![=]>()?;
+
+        let Expr::Range(range) = input.parse::<Expr>()? else {
+            return Err(input.error("expected a range, such as `0.0..=1.0`"));
+        };
+        if range.end.is_some() && matches!(range.limits, RangeLimits::HalfOpen(_)) {
+            return Err(syn::Error::new_spanned(
+                range,
+                "expected an inclusive range, such as `0.0..=1.0`",
+            ));
+        }
+
+        self.metadata.min = range.start.map(|start| *start);
+        self.metadata.max = range.end.map(|end| *end);
+        Ok(())
+    }
+
+    /// Parse `step` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(step = 0.1)]`
+    fn parse_step(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.metadata.step.is_some() {
+            return Err(input.error("step already specified"));
+        }
+
+        input.parse::<kw::step>()?;
+        input.parse::<Token![=]>()?;
+
+        self.metadata.step = Some(input.parse()?);
+        Ok(())
+    }
+
     /// Returns `Some(true)` if the field has a generic remote type.
     ///
     /// If the remote type is not generic, returns `Some(false)`.
@@ -339,6 +339,33 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## Field metadata
+///
+/// The following attributes set the well-known `FieldMetadata` of the field's `TypeInfo`,
+/// which editors and schema exporters can use to display the field:
+///
+/// * `#[reflect(display_name = "...")]` sets the name shown for the field.
+/// * `#[reflect(range = min..=max)]` sets the inclusive numeric range of the field.
+///   Either bound may be omitted, such as `#[reflect(range = 0..)]`.
+/// * `#[reflect(step = ...)]` sets the numeric step of the field.
+/// * `#[reflect(hidden)]` hides the field.
+/// * `#[reflect(read_only)]` marks the field as read-only.
+///
+/// Fields marked with `#[reflect(default)]` or `#[reflect(default = "...")]` also expose their
+/// default value through the metadata.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// struct Light {
+///   #[reflect(display_name = "Intensity (lm)", range = 0.0.., step = 10.0)]
+///   intensity: f32,
+///   #[reflect(read_only)]
+///   id: u32,
+/// }
+/// ```
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the field's `TypeInfo`.
@@ -0,0 +1,236 @@
+use super::{Diff, DiffError, FieldId, ListChange, MapChange, SetChange};
+use crate::{ListInfo, MapInfo, PartialReflect, ReflectMut, Type, TypeInfo};
+use alloc::format;
+
+impl Diff {
+    /// Applies this diff to `value`, turning a value equal to the old value of [`diff`] into the
+    /// new one.
+    ///
+    /// Changes are applied in order. If an error is returned, the changes applied before it are
+    /// kept, so `value` may be partially modified.
+    ///
+    /// [`diff`]: super::diff
+    pub fn apply(&self, value: &mut dyn PartialReflect) -> Result<(), DiffError> {
+        match self {
+            Diff::Replace { new, .. } => Ok(value.try_apply(&**new)?),
+            Diff::Fields(fields) => {
+                for (field, diff) in fields {
+                    let type_path = value.reflect_type_path().into();
+                    let field_value =
+                        field_mut(value, field)?.ok_or_else(|| DiffError::MissingField {
+                            field: field.clone(),
+                            type_path,
+                        })?;
+                    diff.apply(field_value)?;
+                }
+                Ok(())
+            }
+            Diff::List(changes) => {
+                let kind = value.reflect_kind();
+                let ReflectMut::List(list) = value.reflect_mut() else {
+                    return Err(DiffError::MismatchedKinds { diff: "list", kind });
+                };
+                let item_ty = list.get_represented_list_info().map(ListInfo::item_ty);
+                for change in changes {
+                    let len = list.len();
+                    match change {
+                        ListChange::Insert { index, value } => {
+                            // Values can be inserted at the end of the list.
+                            if *index > len {
+                                return Err(DiffError::IndexOutOfBounds { index: *index, len });
+                            }
+                            check_type(&**value, item_ty)?;
+                            list.insert(*index, value.clone_value());
+                        }
+                        ListChange::Remove { index, .. } => {
+                            check_index(*index, len)?;
+                            list.remove(*index);
+                        }
+                        ListChange::Modify { index, diff } => {
+                            check_index(*index, len)?;
+                            diff.apply(list.get_mut(*index).unwrap())?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Diff::Map(changes) => {
+                let kind = value.reflect_kind();
+                let ReflectMut::Map(map) = value.reflect_mut() else {
+                    return Err(DiffError::MismatchedKinds { diff: "map", kind });
+                };
+                let info = map.get_represented_map_info();
+                for change in changes {
+                    match change {
+                        MapChange::Insert { key, value } => {
+                            check_type(&**key, info.map(MapInfo::key_ty))?;
+                            check_type(&**value, info.map(MapInfo::value_ty))?;
+                            map.insert_boxed(key.clone_value(), value.clone_value());
+                        }
+                        MapChange::Remove { key, .. } => {
+                            map.remove(&**key).ok_or_else(|| missing_key(&**key))?;
+                        }
+                        MapChange::Modify { key, diff } => {
+                            let value = map.get_mut(&**key).ok_or_else(|| missing_key(&**key))?;
+                            diff.apply(value)?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Diff::Set(changes) => {
+                let kind = value.reflect_kind();
+                let ReflectMut::Set(set) = value.reflect_mut() else {
+                    return Err(DiffError::MismatchedKinds { diff: "set", kind });
+                };
+                let value_ty = match set.get_represented_type_info() {
+                    Some(TypeInfo::Set(info)) => Some(info.value_ty()),
+                    _ => None,
+                };
+                for change in changes {
+                    match change {
+                        SetChange::Insert(value) => {
+                            check_type(&**value, value_ty)?;
+                            set.insert_boxed(value.clone_value());
+                        }
+                        SetChange::Remove(value) => {
+                            if !set.remove(&**value) {
+                                return Err(missing_key(&**value));
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the inverse of this diff, which undoes its changes.
+    ///
+    /// Applying a diff and then its inverse to a value leaves the value unchanged.
+    pub fn inverse(&self) -> Diff {
+        match self {
+            Diff::Replace { old, new } => Diff::Replace {
+                old: new.clone_value(),
+                new: old.clone_value(),
+            },
+            Diff::Fields(fields) => Diff::Fields(
+                fields
+                    .iter()
+                    .map(|(field, diff)| (field.clone(), diff.inverse()))
+                    .collect(),
+            ),
+            // List changes depend on the previous ones, so they are undone in reverse order.
+            Diff::List(changes) => Diff::List(
+                changes
+                    .iter()
+                    .rev()
+                    .map(|change| match change {
+                        ListChange::Insert { index, value } => ListChange::Remove {
+                            index: *index,
+                            value: value.clone_value(),
+                        },
+                        ListChange::Remove { index, value } => ListChange::Insert {
+                            index: *index,
+                            value: value.clone_value(),
+                        },
+                        ListChange::Modify { index, diff } => ListChange::Modify {
+                            index: *index,
+                            diff: diff.inverse(),
+                        },
+                    })
+                    .collect(),
+            ),
+            Diff::Map(changes) => Diff::Map(
+                changes
+                    .iter()
+                    .map(|change| match change {
+                        MapChange::Insert { key, value } => MapChange::Remove {
+                            key: key.clone_value(),
+                            value: value.clone_value(),
+                        },
+                        MapChange::Remove { key, value } => MapChange::Insert {
+                            key: key.clone_value(),
+                            value: value.clone_value(),
+                        },
+                        MapChange::Modify { key, diff } => MapChange::Modify {
+                            key: key.clone_value(),
+                            diff: diff.inverse(),
+                        },
+                    })
+                    .collect(),
+            ),
+            Diff::Set(changes) => Diff::Set(
+                changes
+                    .iter()
+                    .map(|change| match change {
+                        SetChange::Insert(value) => SetChange::Remove(value.clone_value()),
+                        SetChange::Remove(value) => SetChange::Insert(value.clone_value()),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Returns the field of `value` identified by `field`, or an error if `value` has no fields.
+fn field_mut<'a>(
+    value: &'a mut dyn PartialReflect,
+    field: &FieldId,
+) -> Result<Option<&'a mut dyn PartialReflect>, DiffError> {
+    let kind = value.reflect_kind();
+    Ok(match (value.reflect_mut(), field) {
+        (ReflectMut::Struct(value), FieldId::Name(name)) => value.field_mut(name),
+        (ReflectMut::TupleStruct(value), FieldId::Index(index)) => value.field_mut(*index),
+        (ReflectMut::Tuple(value), FieldId::Index(index)) => value.field_mut(*index),
+        (ReflectMut::Array(value), FieldId::Index(index)) => value.get_mut(*index),
+        (ReflectMut::Enum(value), FieldId::Name(name)) => value.field_mut(name),
+        (ReflectMut::Enum(value), FieldId::Index(index)) => value.field_at_mut(*index),
+        (
+            ReflectMut::Struct(_)
+            | ReflectMut::TupleStruct(_)
+            | ReflectMut::Tuple(_)
+            | ReflectMut::Array(_),
+            _,
+        ) => None,
+        _ => {
+            return Err(DiffError::MismatchedKinds {
+                diff: "field",
+                kind,
+            })
+        }
+    })
+}
+
+/// Returns an error if `value` doesn't represent the type `expected`, when it is known,
+/// since typed collections panic when inserting a value they can't convert.
+fn check_type(value: &dyn PartialReflect, expected: Option<Type>) -> Result<(), DiffError> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+    if value
+        .get_represented_type_info()
+        .is_some_and(|info| info.type_id() == expected.id())
+    {
+        Ok(())
+    } else {
+        Err(DiffError::MismatchedType {
+            expected: expected.path(),
+            found: value.reflect_type_path().into(),
+        })
+    }
+}
+
+fn check_index(index: usize, len: usize) -> Result<(), DiffError> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(DiffError::IndexOutOfBounds { index, len })
+    }
+}
+
+fn missing_key(key: &dyn PartialReflect) -> DiffError {
+    DiffError::MissingKey {
+        key: format!("{key:?}").into(),
+    }
+}
@@ -0,0 +1,320 @@
+use super::{Diff, FieldId, ListChange, MapChange, SetChange};
+use crate::{Enum, List, Map, PartialReflect, ReflectRef, Set, TypeInfo, VariantType};
+use alloc::borrow::ToOwned;
+
+/// Returns the [`Diff`] turning `old` into `new`, or [`None`] if they are equal.
+///
+/// Values are compared with [`PartialReflect::reflect_partial_eq`]. [Opaque] values that can't
+/// be compared are considered different.
+///
+/// Lists are compared element by element. Their common prefix and suffix are skipped, and the
+/// longest common subsequence of the remaining elements is used to find the elements that were
+/// inserted or removed. This takes `O(n * m)` time and memory for `n` and `m` remaining elements,
+/// so when `n * m` is too large, the elements are compared by position instead, in linear time,
+/// and the elements that differ are replaced.
+///
+/// See the [module-level documentation](super) for an example.
+///
+/// [Opaque]: crate::ReflectKind::Opaque
+pub fn diff(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Option<Diff> {
+    let old_type = old.get_represented_type_info().map(TypeInfo::type_id);
+    let new_type = new.get_represented_type_info().map(TypeInfo::type_id);
+    if old_type.is_some() && new_type.is_some() && old_type != new_type {
+        return Some(replace(old, new));
+    }
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old_struct), ReflectRef::Struct(new_struct)) => {
+            if old_struct.field_len() != new_struct.field_len() {
+                return Some(replace(old, new));
+            }
+            let mut fields = Vec::new();
+            for (index, old_field) in old_struct.iter_fields().enumerate() {
+                let name = old_struct.name_at(index).unwrap_or_default();
+                let Some(new_field) = new_struct.field(name) else {
+                    return Some(replace(old, new));
+                };
+                if let Some(diff) = diff(old_field, new_field) {
+                    fields.push((FieldId::Name(name.to_owned()), diff));
+                }
+            }
+            fields_diff(fields)
+        }
+        (ReflectRef::TupleStruct(old_tuple), ReflectRef::TupleStruct(new_tuple)) => {
+            indexed_diff(old, new, old_tuple.iter_fields(), new_tuple.iter_fields())
+        }
+        (ReflectRef::Tuple(old_tuple), ReflectRef::Tuple(new_tuple)) => {
+            indexed_diff(old, new, old_tuple.iter_fields(), new_tuple.iter_fields())
+        }
+        (ReflectRef::Array(old_array), ReflectRef::Array(new_array)) => {
+            indexed_diff(old, new, old_array.iter(), new_array.iter())
+        }
+        (ReflectRef::Enum(old_enum), ReflectRef::Enum(new_enum)) => {
+            if old_enum.variant_name() != new_enum.variant_name()
+                || old_enum.field_len() != new_enum.field_len()
+            {
+                return Some(replace(old, new));
+            }
+            diff_variant(old_enum, new_enum).unwrap_or_else(|| Some(replace(old, new)))
+        }
+        (ReflectRef::List(old_list), ReflectRef::List(new_list)) => {
+            let changes = diff_list(old_list, new_list);
+            (!changes.is_empty()).then_some(Diff::List(changes))
+        }
+        (ReflectRef::Map(old_map), ReflectRef::Map(new_map)) => {
+            let changes = diff_map(old_map, new_map);
+            (!changes.is_empty()).then_some(Diff::Map(changes))
+        }
+        (ReflectRef::Set(old_set), ReflectRef::Set(new_set)) => {
+            let changes = diff_set(old_set, new_set);
+            (!changes.is_empty()).then_some(Diff::Set(changes))
+        }
+        _ => (!equals(old, new)).then(|| replace(old, new)),
+    }
+}
+
+fn replace(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Diff {
+    Diff::Replace {
+        old: old.clone_value(),
+        new: new.clone_value(),
+    }
+}
+
+fn equals(old: &dyn PartialReflect, new: &dyn PartialReflect) -> bool {
+    old.reflect_partial_eq(new) == Some(true)
+}
+
+fn fields_diff(fields: Vec<(FieldId, Diff)>) -> Option<Diff> {
+    (!fields.is_empty()).then_some(Diff::Fields(fields))
+}
+
+/// Diffs the fields of two values whose fields are identified by their index.
+fn indexed_diff<'a>(
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    old_fields: impl ExactSizeIterator<Item = &'a dyn PartialReflect>,
+    new_fields: impl ExactSizeIterator<Item = &'a dyn PartialReflect>,
+) -> Option<Diff> {
+    if old_fields.len() != new_fields.len() {
+        return Some(replace(old, new));
+    }
+    let fields = old_fields
+        .zip(new_fields)
+        .enumerate()
+        .filter_map(|(index, (old_field, new_field))| {
+            Some((FieldId::Index(index), diff(old_field, new_field)?))
+        })
+        .collect();
+    fields_diff(fields)
+}
+
+/// Diffs the fields of two enums of the same variant, returning `None` if their fields don't
+/// match.
+fn diff_variant(old: &dyn Enum, new: &dyn Enum) -> Option<Option<Diff>> {
+    let mut fields = Vec::new();
+    for (index, old_field) in old.iter_fields().enumerate() {
+        let (id, new_field) = match old.variant_type() {
+            VariantType::Struct => {
+                let name = old_field.name()?;
+                (FieldId::Name(name.to_owned()), new.field(name)?)
+            }
+            VariantType::Tuple | VariantType::Unit => (FieldId::Index(index), new.field_at(index)?),
+        };
+        if let Some(diff) = diff(old_field.value(), new_field) {
+            fields.push((id, diff));
+        }
+    }
+    Some(fields_diff(fields))
+}
+
+/// The maximum number of cells of the table used to find the longest common subsequence of the
+/// changed parts of two lists, above which [`diff_list_by_position`] is used instead.
+const MAX_LCS_TABLE_LEN: usize = 1 << 16;
+
+fn diff_list(old: &dyn List, new: &dyn List) -> Vec<ListChange> {
+    let old_items: Vec<_> = old.iter().collect();
+    let new_items: Vec<_> = new.iter().collect();
+
+    // Only the elements between the common prefix and suffix of the lists can have changed.
+    let prefix = old_items
+        .iter()
+        .zip(&new_items)
+        .take_while(|(old, new)| equals(**old, **new))
+        .count();
+    let suffix = old_items[prefix..]
+        .iter()
+        .rev()
+        .zip(new_items[prefix..].iter().rev())
+        .take_while(|(old, new)| equals(**old, **new))
+        .count();
+    let old_items = &old_items[prefix..old_items.len() - suffix];
+    let new_items = &new_items[prefix..new_items.len() - suffix];
+
+    let table_len = (old_items.len() + 1).saturating_mul(new_items.len() + 1);
+    if table_len > MAX_LCS_TABLE_LEN {
+        diff_list_by_position(prefix, old_items, new_items)
+    } else {
+        diff_list_by_lcs(prefix, old_items, new_items)
+    }
+}
+
+/// Diffs lists using their longest common subsequence to find the elements that were inserted or
+/// removed, where `index` is the index of the first element of the slices in the list.
+fn diff_list_by_lcs(
+    mut index: usize,
+    old_items: &[&dyn PartialReflect],
+    new_items: &[&dyn PartialReflect],
+) -> Vec<ListChange> {
+    let (old_len, new_len) = (old_items.len(), new_items.len());
+
+    // `common[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let width = new_len + 1;
+    let mut common = vec![0_usize; (old_len + 1) * width];
+    for i in (0..old_len).rev() {
+        for j in (0..new_len).rev() {
+            common[i * width + j] = if equals(old_items[i], new_items[j]) {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let mut removed = Vec::new();
+    let mut inserted = Vec::new();
+    // `index` is the index in the list as modified by the changes so far.
+    let (mut i, mut j) = (0, 0);
+    while i < old_len || j < new_len {
+        if i < old_len && j < new_len && equals(old_items[i], new_items[j]) {
+            flush_list_gap(&mut changes, &mut index, &mut removed, &mut inserted);
+            index += 1;
+            i += 1;
+            j += 1;
+        } else if j == new_len
+            || (i < old_len && common[(i + 1) * width + j] >= common[i * width + j + 1])
+        {
+            removed.push(old_items[i]);
+            i += 1;
+        } else {
+            inserted.push(new_items[j]);
+            j += 1;
+        }
+    }
+    flush_list_gap(&mut changes, &mut index, &mut removed, &mut inserted);
+
+    changes
+}
+
+/// Diffs lists by comparing the elements at the same position, replacing those that differ and
+/// removing or inserting the elements past the end of the shorter list.
+///
+/// Unlike [`diff_list_by_lcs`], this takes linear time, but an element inserted or removed near
+/// the start of the list replaces all the elements after it.
+fn diff_list_by_position(
+    mut index: usize,
+    old_items: &[&dyn PartialReflect],
+    new_items: &[&dyn PartialReflect],
+) -> Vec<ListChange> {
+    let mut changes = Vec::new();
+    for (old, new) in old_items.iter().zip(new_items) {
+        if !equals(*old, *new) {
+            changes.push(ListChange::Modify {
+                index,
+                diff: replace(*old, *new),
+            });
+        }
+        index += 1;
+    }
+    let common_len = old_items.len().min(new_items.len());
+    for old in &old_items[common_len..] {
+        changes.push(ListChange::Remove {
+            index,
+            value: old.clone_value(),
+        });
+    }
+    for new in &new_items[common_len..] {
+        changes.push(ListChange::Insert {
+            index,
+            value: new.clone_value(),
+        });
+        index += 1;
+    }
+    changes
+}
+
+/// Turns the elements removed and inserted between two common elements into changes.
+///
+/// Elements removed and inserted at the same position are diffed rather than replaced, so that
+/// changing a field of an element doesn't replace the whole element.
+fn flush_list_gap(
+    changes: &mut Vec<ListChange>,
+    index: &mut usize,
+    removed: &mut Vec<&dyn PartialReflect>,
+    inserted: &mut Vec<&dyn PartialReflect>,
+) {
+    let modified = removed.len().min(inserted.len());
+    for (old, new) in removed.drain(..modified).zip(inserted.drain(..modified)) {
+        if let Some(diff) = diff(old, new) {
+            changes.push(ListChange::Modify {
+                index: *index,
+                diff,
+            });
+        }
+        *index += 1;
+    }
+    for old in removed.drain(..) {
+        changes.push(ListChange::Remove {
+            index: *index,
+            value: old.clone_value(),
+        });
+    }
+    for new in inserted.drain(..) {
+        changes.push(ListChange::Insert {
+            index: *index,
+            value: new.clone_value(),
+        });
+        *index += 1;
+    }
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> Vec<MapChange> {
+    let mut changes = Vec::new();
+    for (key, old_value) in old.iter() {
+        match new.get(key) {
+            Some(new_value) => {
+                if let Some(diff) = diff(old_value, new_value) {
+                    changes.push(MapChange::Modify {
+                        key: key.clone_value(),
+                        diff,
+                    });
+                }
+            }
+            None => changes.push(MapChange::Remove {
+                key: key.clone_value(),
+                value: old_value.clone_value(),
+            }),
+        }
+    }
+    for (key, new_value) in new.iter() {
+        if old.get(key).is_none() {
+            changes.push(MapChange::Insert {
+                key: key.clone_value(),
+                value: new_value.clone_value(),
+            });
+        }
+    }
+    changes
+}
+
+fn diff_set(old: &dyn Set, new: &dyn Set) -> Vec<SetChange> {
+    let removed = old
+        .iter()
+        .filter(|value| !new.contains(*value))
+        .map(|value| SetChange::Remove(value.clone_value()));
+    let inserted = new
+        .iter()
+        .filter(|value| !old.contains(*value))
+        .map(|value| SetChange::Insert(value.clone_value()));
+    removed.chain(inserted).collect()
+}
@@ -0,0 +1,473 @@
+//! Structural diffing of reflected values.
+//!
+//! The [`diff`] function compares two [`PartialReflect`] values and returns a [`Diff`]
+//! describing how to turn the first one into the second one. A `Diff` only contains the parts
+//! that changed: the fields of structs, tuple structs, tuples, arrays and enums, the insertions
+//! and removals of lists, and the keys added to, removed from or changed in maps and sets.
+//!
+//! A `Diff` can be [applied](Diff::apply) to a value later on, and [inverted](Diff::inverse) to
+//! undo it. This makes diffs suitable for undo/redo stacks and for replicating changes as deltas.
+//! Diffs can be serialized with a [`DiffSerializer`] and deserialized with a
+//! [`DiffDeserializer`], which rely on the [type registry] to serialize the values they contain.
+//!
+//! ```
+//! # use bevy_reflect::{diff::diff, Reflect};
+//! #[derive(Reflect, Clone, PartialEq, Debug)]
+//! struct Player {
+//!     name: String,
+//!     inventory: Vec<u32>,
+//! }
+//!
+//! let old = Player { name: String::from("Ferris"), inventory: vec![1, 2, 3] };
+//! let new = Player { name: String::from("Ferris"), inventory: vec![1, 3, 4] };
+//!
+//! let diff = diff(&old, &new).unwrap();
+//!
+//! let mut value = old.clone();
+//! diff.apply(&mut value).unwrap();
+//! assert_eq!(value, new);
+//!
+//! diff.inverse().apply(&mut value).unwrap();
+//! assert_eq!(value, old);
+//! ```
+//!
+//! [type registry]: crate::TypeRegistry
+
+mod apply;
+mod compute;
+mod serde;
+
+pub use compute::diff;
+pub use serde::{DiffDeserializer, DiffSerializer};
+
+use crate::{PartialReflect, ReflectKind};
+use core::fmt::{Display, Formatter};
+use derive_more::derive::{Display, Error};
+
+/// The difference between two reflected values, created by [`diff`].
+///
+/// Values contained in a `Diff` are stored as owned clones, so that the diff can be
+/// [inverted](Diff::inverse) without access to the original values.
+#[derive(Debug)]
+pub enum Diff {
+    /// The value is replaced as a whole.
+    ///
+    /// This is used for [opaque] values, for values whose type or kind changed, and for enums
+    /// whose variant changed, since the fields of the new variant are unrelated to the old ones.
+    ///
+    /// [opaque]: ReflectKind::Opaque
+    Replace {
+        /// The value before the change.
+        old: Box<dyn PartialReflect>,
+        /// The value after the change.
+        new: Box<dyn PartialReflect>,
+    },
+    /// Some fields of a struct, tuple struct, tuple, array or enum changed.
+    ///
+    /// For an enum, the variant is unchanged.
+    Fields(Vec<(FieldId, Diff)>),
+    /// Elements of a list were inserted, removed or changed, in order.
+    List(Vec<ListChange>),
+    /// Entries of a map were inserted, removed or changed.
+    Map(Vec<MapChange>),
+    /// Values of a set were inserted or removed.
+    Set(Vec<SetChange>),
+}
+
+/// Identifies a field changed by a [`Diff::Fields`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldId {
+    /// A named field of a struct or of a struct variant.
+    Name(String),
+    /// A field of a tuple struct, tuple or tuple variant, or an element of an array.
+    Index(usize),
+}
+
+impl Display for FieldId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "{name}"),
+            Self::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+/// A change to a list, part of a [`Diff::List`].
+///
+/// The index of each change is relative to the list as modified by the previous changes.
+#[derive(Debug)]
+pub enum ListChange {
+    /// A value was inserted at `index`, shifting the following elements.
+    Insert {
+        /// The index of the inserted value.
+        index: usize,
+        /// The inserted value.
+        value: Box<dyn PartialReflect>,
+    },
+    /// The value at `index` was removed, shifting the following elements.
+    Remove {
+        /// The index of the removed value.
+        index: usize,
+        /// The removed value.
+        value: Box<dyn PartialReflect>,
+    },
+    /// The value at `index` changed.
+    Modify {
+        /// The index of the changed value.
+        index: usize,
+        /// The changes of the value.
+        diff: Diff,
+    },
+}
+
+/// A change to a map, part of a [`Diff::Map`].
+#[derive(Debug)]
+pub enum MapChange {
+    /// An entry was inserted.
+    Insert {
+        /// The key of the inserted entry.
+        key: Box<dyn PartialReflect>,
+        /// The value of the inserted entry.
+        value: Box<dyn PartialReflect>,
+    },
+    /// An entry was removed.
+    Remove {
+        /// The key of the removed entry.
+        key: Box<dyn PartialReflect>,
+        /// The value of the removed entry.
+        value: Box<dyn PartialReflect>,
+    },
+    /// The value of an entry changed.
+    Modify {
+        /// The key of the changed entry.
+        key: Box<dyn PartialReflect>,
+        /// The changes of the value.
+        diff: Diff,
+    },
+}
+
+/// A change to a set, part of a [`Diff::Set`].
+#[derive(Debug)]
+pub enum SetChange {
+    /// A value was inserted.
+    Insert(Box<dyn PartialReflect>),
+    /// A value was removed.
+    Remove(Box<dyn PartialReflect>),
+}
+
+/// An error returned when [applying](Diff::apply) a [`Diff`] to a value that doesn't match it.
+#[derive(Error, Display, Debug)]
+pub enum DiffError {
+    /// Replacing a value failed.
+    #[display("{_0}")]
+    Apply(crate::ApplyError),
+
+    /// The diff expects a different kind of value, e.g. a list diff applied to a struct.
+    #[display("cannot apply a {diff} diff to a `{kind}`")]
+    MismatchedKinds {
+        /// The kind of diff.
+        diff: &'static str,
+        /// The kind of the value the diff was applied to.
+        kind: ReflectKind,
+    },
+
+    /// A value inserted by the diff isn't of the type expected by the list, map or set.
+    #[display("expected a value of type `{expected}`, found `{found}`")]
+    MismatchedType {
+        /// The type path of the values of the list, map or set.
+        expected: &'static str,
+        /// The type path of the inserted value.
+        found: Box<str>,
+    },
+
+    /// The value doesn't have a field changed by the diff.
+    #[display("no field `{field}` in `{type_path}`")]
+    MissingField {
+        /// The missing field.
+        field: FieldId,
+        /// The type of the value the diff was applied to.
+        type_path: Box<str>,
+    },
+
+    /// A list change refers to an index past the end of the list.
+    #[display("index {index} is out of bounds of a list of length {len}")]
+    IndexOutOfBounds {
+        /// The index of the change.
+        index: usize,
+        /// The length of the list at the time of the change.
+        len: usize,
+    },
+
+    /// A map or set change refers to a key that isn't in the map or set.
+    #[display("`{key}` is missing from the map or set")]
+    MissingKey {
+        /// The debug representation of the missing key.
+        key: Box<str>,
+    },
+}
+
+impl From<crate::ApplyError> for DiffError {
+    fn from(error: crate::ApplyError) -> Self {
+        Self::Apply(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, serde::ReflectSerializer, Reflect, TypeRegistry};
+    use ::serde::de::DeserializeSeed;
+    use bevy_utils::{HashMap, HashSet};
+    use bincode::Options;
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Player {
+        name: String,
+        health: Health,
+        state: State,
+        inventory: Vec<Item>,
+        stats: HashMap<String, u32>,
+        tags: HashSet<String>,
+        position: (f32, f32),
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Health(u32, u32);
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    enum State {
+        Idle,
+        Walking { speed: f32 },
+        Attacking(u32),
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Item {
+        id: u32,
+        count: u32,
+    }
+
+    fn player() -> Player {
+        Player {
+            name: String::from("Ferris"),
+            health: Health(10, 10),
+            state: State::Walking { speed: 1.0 },
+            inventory: vec![
+                Item { id: 1, count: 1 },
+                Item { id: 2, count: 1 },
+                Item { id: 3, count: 1 },
+            ],
+            stats: HashMap::from_iter([(String::from("str"), 3), (String::from("dex"), 4)]),
+            tags: HashSet::from_iter([String::from("hero")]),
+            position: (0.0, 0.0),
+        }
+    }
+
+    fn assert_round_trip(old: &Player, new: &Player) -> Diff {
+        let diff = diff(old, new).unwrap();
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(&value, new);
+        diff.inverse().apply(&mut value).unwrap();
+        assert_eq!(&value, old);
+        diff
+    }
+
+    #[test]
+    fn equal_values_have_no_diff() {
+        assert!(diff(&player(), &player()).is_none());
+        assert!(diff(&1_u32, &1_u32).is_none());
+    }
+
+    #[test]
+    fn should_diff_fields() {
+        let old = player();
+        let mut new = player();
+        new.health.0 = 5;
+        new.position.1 = 2.0;
+        new.state = State::Walking { speed: 2.0 };
+
+        let Diff::Fields(fields) = assert_round_trip(&old, &new) else {
+            panic!("expected a field diff");
+        };
+        let fields: Vec<_> = fields.iter().map(|(field, _)| field.to_string()).collect();
+        assert_eq!(fields, ["health", "state", "position"]);
+    }
+
+    #[test]
+    fn should_replace_switched_variant() {
+        let old = player();
+        let mut new = player();
+        new.state = State::Attacking(3);
+        let Diff::Fields(fields) = assert_round_trip(&old, &new) else {
+            panic!("expected a field diff");
+        };
+        assert!(matches!(fields[..], [(_, Diff::Replace { .. })]));
+
+        new.state = State::Idle;
+        assert_round_trip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_lists() {
+        let old = player();
+        let mut new = player();
+        new.inventory.remove(0);
+        new.inventory[1].count = 2;
+        new.inventory.insert(1, Item { id: 4, count: 1 });
+        new.inventory.push(Item { id: 5, count: 1 });
+        assert_round_trip(&old, &new);
+
+        new.inventory.clear();
+        assert_round_trip(&old, &new);
+        assert_round_trip(&new, &old);
+    }
+
+    #[test]
+    fn should_diff_large_lists() {
+        let round_trip = |old: &Vec<u32>, new: &Vec<u32>| {
+            let diff = diff(old, new).unwrap();
+            let mut value = old.clone();
+            diff.apply(&mut value).unwrap();
+            assert_eq!(&value, new);
+            diff.inverse().apply(&mut value).unwrap();
+            assert_eq!(&value, old);
+            let Diff::List(changes) = diff else {
+                panic!("expected a list diff");
+            };
+            changes
+        };
+
+        // Only the elements between the common prefix and suffix are compared.
+        let old: Vec<u32> = (0..1000).collect();
+        let mut new = old.clone();
+        new.insert(500, 1000);
+        let changes = round_trip(&old, &new);
+        assert!(matches!(
+            changes[..],
+            [ListChange::Insert { index: 500, .. }]
+        ));
+
+        // Lists too long to find their longest common subsequence are compared by position.
+        let new: Vec<u32> = old.iter().rev().copied().collect();
+        let changes = round_trip(&old, &new);
+        assert_eq!(changes.len(), 1000);
+        assert!(changes.iter().all(|change| matches!(
+            change,
+            ListChange::Modify {
+                diff: Diff::Replace { .. },
+                ..
+            }
+        )));
+
+        let new: Vec<u32> = (1..2000).rev().collect();
+        round_trip(&old, &new);
+        round_trip(&new, &old);
+    }
+
+    #[test]
+    fn should_diff_maps_and_sets() {
+        let old = player();
+        let mut new = player();
+        new.stats.remove("str");
+        new.stats.insert(String::from("dex"), 5);
+        new.stats.insert(String::from("int"), 1);
+        new.tags.remove("hero");
+        new.tags.insert(String::from("villain"));
+        assert_round_trip(&old, &new);
+    }
+
+    #[test]
+    fn should_fail_to_apply_mismatched_diff() {
+        let diff = diff(&vec![1_u32], &vec![1_u32, 2]).unwrap();
+        let mut value = player();
+        assert!(matches!(
+            diff.apply(&mut value),
+            Err(DiffError::MismatchedKinds { .. })
+        ));
+
+        let mut value = Vec::<u32>::new();
+        let diff = super::diff(&vec![1_u32, 2], &vec![2_u32]).unwrap();
+        assert!(matches!(
+            diff.apply(&mut value),
+            Err(DiffError::IndexOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_insert_mismatched_types() {
+        let assert_mismatched = |result: Result<(), DiffError>| {
+            let Err(DiffError::MismatchedType { expected, found }) = result else {
+                panic!("expected a type mismatch, got {result:?}");
+            };
+            assert_eq!((expected, &*found), ("u32", "alloc::string::String"));
+        };
+
+        let diff = diff(&Vec::<String>::new(), &vec![String::from("a")]).unwrap();
+        assert_mismatched(diff.apply(&mut Vec::<u32>::new()));
+
+        let diff = super::diff(
+            &HashMap::<u32, String>::default(),
+            &HashMap::from_iter([(1_u32, String::from("a"))]),
+        )
+        .unwrap();
+        let mut map = HashMap::<u32, u32>::default();
+        assert_mismatched(diff.apply(&mut map));
+        assert!(map.is_empty());
+
+        let diff = super::diff(
+            &HashSet::<String>::default(),
+            &HashSet::from_iter([String::from("a")]),
+        )
+        .unwrap();
+        assert_mismatched(diff.apply(&mut HashSet::<u32>::default()));
+    }
+
+    #[test]
+    fn should_serialize_diff() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+
+        let old = player();
+        let mut new = player();
+        new.name = String::from("Crab");
+        new.state = State::Attacking(3);
+        new.inventory.remove(1);
+        new.inventory[0].count = 4;
+        new.stats.insert(String::from("int"), 1);
+        new.tags.insert(String::from("villain"));
+        let diff = diff(&old, &new).unwrap();
+
+        let serializer = DiffSerializer::new(&diff, &registry);
+        let ron = ron::to_string(&serializer).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+        let deserialized = DiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut value = old.clone();
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let json = serde_json::to_string(&DiffSerializer::new(&deserialized, &registry)).unwrap();
+        let deserialized = DiffDeserializer::new(&registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        deserialized.inverse().apply(&mut value).unwrap();
+        assert_eq!(value, old);
+
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+        let bytes = options
+            .serialize(&DiffSerializer::new(&deserialized, &registry))
+            .unwrap();
+        let deserialized = options
+            .deserialize_seed(DiffDeserializer::new(&registry), &bytes)
+            .unwrap();
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        // Replaced values are serialized like any other reflected value.
+        let state = ReflectSerializer::new(&State::Attacking(3), &registry);
+        assert!(ron.contains(&ron::to_string(&state).unwrap()));
+    }
+}
@@ -0,0 +1,654 @@
+use super::{Diff, FieldId, ListChange, MapChange, SetChange};
+use crate::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    PartialReflect, TypeRegistry,
+};
+use alloc::string::String;
+use core::{fmt, fmt::Formatter, marker::PhantomData};
+use serde::{
+    de::{
+        DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, Unexpected, VariantAccess,
+        Visitor,
+    },
+    ser::{SerializeStructVariant, SerializeTuple},
+    Deserializer, Serialize, Serializer,
+};
+
+const DIFF_VARIANTS: &[&str] = &["Replace", "Fields", "List", "Map", "Set"];
+const FIELD_ID_VARIANTS: &[&str] = &["Name", "Index"];
+const CHANGE_VARIANTS: &[&str] = &["Insert", "Remove", "Modify"];
+const SET_CHANGE_VARIANTS: &[&str] = &["Insert", "Remove"];
+const REPLACE_FIELDS: &[&str] = &["old", "new"];
+const INDEX_VALUE_FIELDS: &[&str] = &["index", "value"];
+const INDEX_DIFF_FIELDS: &[&str] = &["index", "diff"];
+const KEY_VALUE_FIELDS: &[&str] = &["key", "value"];
+const KEY_DIFF_FIELDS: &[&str] = &["key", "diff"];
+
+/// A serializer for a [`Diff`].
+///
+/// The diff is serialized like an enum, and the values it contains are serialized with a
+/// [`ReflectSerializer`], which requires their types to be registered in the [`TypeRegistry`].
+///
+/// This is the serializer counterpart to [`DiffDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff::{diff, DiffSerializer}, Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// #[type_path = "my_crate"]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let diff = diff(&Player { health: 10 }, &Player { health: 5 }).unwrap();
+/// let output = ron::to_string(&DiffSerializer::new(&diff, &registry)).unwrap();
+///
+/// assert_eq!(output, r#"Fields([(Name("health"),Replace(old:{"u32":10},new:{"u32":5}))])"#);
+/// ```
+pub struct DiffSerializer<'a> {
+    diff: &'a Diff,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffSerializer<'a> {
+    /// Creates a serializer for `diff`.
+    pub fn new(diff: &'a Diff, registry: &'a TypeRegistry) -> Self {
+        Self { diff, registry }
+    }
+}
+
+impl<'a> Serialize for DiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        match self.diff {
+            Diff::Replace { old, new } => {
+                let mut state = serializer.serialize_struct_variant("Diff", 0, "Replace", 2)?;
+                state.serialize_field("old", &ReflectSerializer::new(&**old, registry))?;
+                state.serialize_field("new", &ReflectSerializer::new(&**new, registry))?;
+                state.end()
+            }
+            Diff::Fields(fields) => serializer.serialize_newtype_variant(
+                "Diff",
+                1,
+                "Fields",
+                &WithRegistry::new(&fields[..], registry),
+            ),
+            Diff::List(changes) => serializer.serialize_newtype_variant(
+                "Diff",
+                2,
+                "List",
+                &WithRegistry::new(&changes[..], registry),
+            ),
+            Diff::Map(changes) => serializer.serialize_newtype_variant(
+                "Diff",
+                3,
+                "Map",
+                &WithRegistry::new(&changes[..], registry),
+            ),
+            Diff::Set(changes) => serializer.serialize_newtype_variant(
+                "Diff",
+                4,
+                "Set",
+                &WithRegistry::new(&changes[..], registry),
+            ),
+        }
+    }
+}
+
+/// Serializes a part of a [`Diff`] that contains reflected values.
+struct WithRegistry<'a, T: ?Sized> {
+    value: &'a T,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, T: ?Sized> WithRegistry<'a, T> {
+    fn new(value: &'a T, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+}
+
+impl<'a, T> Serialize for WithRegistry<'a, [T]>
+where
+    for<'b> WithRegistry<'b, T>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(
+            self.value
+                .iter()
+                .map(|value| WithRegistry::new(value, self.registry)),
+        )
+    }
+}
+
+impl<'a> Serialize for WithRegistry<'a, (FieldId, Diff)> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (field, diff) = self.value;
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&SerializableFieldId(field))?;
+        state.serialize_element(&DiffSerializer::new(diff, self.registry))?;
+        state.end()
+    }
+}
+
+struct SerializableFieldId<'a>(&'a FieldId);
+
+impl<'a> Serialize for SerializableFieldId<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            FieldId::Name(name) => serializer.serialize_newtype_variant("FieldId", 0, "Name", name),
+            FieldId::Index(index) => {
+                serializer.serialize_newtype_variant("FieldId", 1, "Index", index)
+            }
+        }
+    }
+}
+
+impl<'a> Serialize for WithRegistry<'a, ListChange> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        let (variant_index, variant, index) = match self.value {
+            ListChange::Insert { index, .. } => (0, "Insert", index),
+            ListChange::Remove { index, .. } => (1, "Remove", index),
+            ListChange::Modify { index, .. } => (2, "Modify", index),
+        };
+        let mut state =
+            serializer.serialize_struct_variant("ListChange", variant_index, variant, 2)?;
+        state.serialize_field("index", index)?;
+        match self.value {
+            ListChange::Insert { value, .. } | ListChange::Remove { value, .. } => {
+                state.serialize_field("value", &ReflectSerializer::new(&**value, registry))?;
+            }
+            ListChange::Modify { diff, .. } => {
+                state.serialize_field("diff", &DiffSerializer::new(diff, registry))?;
+            }
+        }
+        state.end()
+    }
+}
+
+impl<'a> Serialize for WithRegistry<'a, MapChange> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        let (variant_index, variant, key) = match self.value {
+            MapChange::Insert { key, .. } => (0, "Insert", key),
+            MapChange::Remove { key, .. } => (1, "Remove", key),
+            MapChange::Modify { key, .. } => (2, "Modify", key),
+        };
+        let mut state =
+            serializer.serialize_struct_variant("MapChange", variant_index, variant, 2)?;
+        state.serialize_field("key", &ReflectSerializer::new(&**key, registry))?;
+        match self.value {
+            MapChange::Insert { value, .. } | MapChange::Remove { value, .. } => {
+                state.serialize_field("value", &ReflectSerializer::new(&**value, registry))?;
+            }
+            MapChange::Modify { diff, .. } => {
+                state.serialize_field("diff", &DiffSerializer::new(diff, registry))?;
+            }
+        }
+        state.end()
+    }
+}
+
+impl<'a> Serialize for WithRegistry<'a, SetChange> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (variant_index, variant, value) = match self.value {
+            SetChange::Insert(value) => (0, "Insert", value),
+            SetChange::Remove(value) => (1, "Remove", value),
+        };
+        serializer.serialize_newtype_variant(
+            "SetChange",
+            variant_index,
+            variant,
+            &ReflectSerializer::new(&**value, self.registry),
+        )
+    }
+}
+
+/// A deserializer for a [`Diff`] serialized by a [`DiffSerializer`].
+///
+/// The values contained in the diff are deserialized with a [`ReflectDeserializer`], so they
+/// are usually dynamic values. They can still be [applied](Diff::apply) to concrete values.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff::DiffDeserializer, Reflect, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let input = r#"Fields([(Name("health"), Replace(old: {"u32": 10}, new: {"u32": 5}))])"#;
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let diff = DiffDeserializer::new(&registry).deserialize(&mut deserializer).unwrap();
+///
+/// let mut player = Player { health: 10 };
+/// diff.apply(&mut player).unwrap();
+/// assert_eq!(player, Player { health: 5 });
+/// ```
+#[derive(Clone, Copy)]
+pub struct DiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffDeserializer<'a> {
+    /// Creates a deserializer for a [`Diff`].
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("Diff", DIFF_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.registry;
+        let (variant, data) = data.variant_seed(NameSeed(DIFF_VARIANTS))?;
+        Ok(match variant {
+            0 => {
+                let value = ValueSeed(registry);
+                let (old, new) =
+                    data.struct_variant(REPLACE_FIELDS, PairVisitor(value, value, REPLACE_FIELDS))?;
+                Diff::Replace { old, new }
+            }
+            1 => Diff::Fields(data.newtype_variant_seed(SeqSeed(FieldSeed(registry)))?),
+            2 => Diff::List(data.newtype_variant_seed(SeqSeed(ListChangeSeed(registry)))?),
+            3 => Diff::Map(data.newtype_variant_seed(SeqSeed(MapChangeSeed(registry)))?),
+            _ => Diff::Set(data.newtype_variant_seed(SeqSeed(SetChangeSeed(registry)))?),
+        })
+    }
+}
+
+/// Deserializes a variant or field name into its index in a list of names.
+#[derive(Clone, Copy)]
+struct NameSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for NameSeed {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for NameSeed {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("one of ")?;
+        for (index, name) in self.0.iter().enumerate() {
+            if index > 0 {
+                formatter.write_str(", ")?;
+            }
+            write!(formatter, "`{name}`")?;
+        }
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        usize::try_from(value)
+            .ok()
+            .filter(|index| *index < self.0.len())
+            .ok_or_else(|| Error::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0
+            .iter()
+            .position(|name| *name == value)
+            .ok_or_else(|| Error::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+/// Deserializes a reflected value with a [`ReflectDeserializer`].
+#[derive(Clone, Copy)]
+struct ValueSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for ValueSeed<'a> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ReflectDeserializer::new(self.0).deserialize(deserializer)
+    }
+}
+
+/// Deserializes a sequence of values with the same seed.
+struct SeqSeed<S>(S);
+
+impl<'de, S> DeserializeSeed<'de> for SeqSeed<S>
+where
+    S: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<S::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, S> Visitor<'de> for SeqSeed<S>
+where
+    S: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of changes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Visits a tuple or a struct of two fields, deserialized with the given seeds.
+struct PairVisitor<A, B>(A, B, &'static [&'static str]);
+
+impl<'de, A, B> Visitor<'de> for PairVisitor<A, B>
+where
+    A: DeserializeSeed<'de> + Copy,
+    B: DeserializeSeed<'de> + Copy,
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "`{}` and `{}`", self.2[0], self.2[1])
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok((first, second))
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut first = None;
+        let mut second = None;
+        while let Some(field) = map.next_key_seed(NameSeed(self.2))? {
+            if field == 0 {
+                if first.is_some() {
+                    return Err(Error::duplicate_field(self.2[0]));
+                }
+                first = Some(map.next_value_seed(self.0)?);
+            } else {
+                if second.is_some() {
+                    return Err(Error::duplicate_field(self.2[1]));
+                }
+                second = Some(map.next_value_seed(self.1)?);
+            }
+        }
+        Ok((
+            first.ok_or_else(|| Error::missing_field(self.2[0]))?,
+            second.ok_or_else(|| Error::missing_field(self.2[1]))?,
+        ))
+    }
+}
+
+/// Deserializes a changed field of a [`Diff::Fields`].
+#[derive(Clone, Copy)]
+struct FieldSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldSeed<'a> {
+    type Value = (FieldId, Diff);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            2,
+            PairVisitor(
+                FieldIdSeed,
+                DiffDeserializer::new(self.0),
+                &["field", "diff"],
+            ),
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FieldIdSeed;
+
+impl<'de> DeserializeSeed<'de> for FieldIdSeed {
+    type Value = FieldId;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("FieldId", FIELD_ID_VARIANTS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldIdSeed {
+    type Value = FieldId;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a field name or index")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, data) = data.variant_seed(NameSeed(FIELD_ID_VARIANTS))?;
+        Ok(match variant {
+            0 => FieldId::Name(data.newtype_variant::<String>()?),
+            _ => FieldId::Index(data.newtype_variant::<usize>()?),
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ListChangeSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for ListChangeSeed<'a> {
+    type Value = ListChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("ListChange", CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListChangeSeed<'a> {
+    type Value = ListChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a list change")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let index = PhantomData::<usize>;
+        let (variant, data) = data.variant_seed(NameSeed(CHANGE_VARIANTS))?;
+        Ok(match variant {
+            0 | 1 => {
+                let (index, value) = data.struct_variant(
+                    INDEX_VALUE_FIELDS,
+                    PairVisitor(index, ValueSeed(self.0), INDEX_VALUE_FIELDS),
+                )?;
+                if variant == 0 {
+                    ListChange::Insert { index, value }
+                } else {
+                    ListChange::Remove { index, value }
+                }
+            }
+            _ => {
+                let (index, diff) = data.struct_variant(
+                    INDEX_DIFF_FIELDS,
+                    PairVisitor(index, DiffDeserializer::new(self.0), INDEX_DIFF_FIELDS),
+                )?;
+                ListChange::Modify { index, diff }
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MapChangeSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for MapChangeSeed<'a> {
+    type Value = MapChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("MapChange", CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapChangeSeed<'a> {
+    type Value = MapChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map change")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let value = ValueSeed(self.0);
+        let (variant, data) = data.variant_seed(NameSeed(CHANGE_VARIANTS))?;
+        Ok(match variant {
+            0 | 1 => {
+                let (key, value) = data.struct_variant(
+                    KEY_VALUE_FIELDS,
+                    PairVisitor(value, value, KEY_VALUE_FIELDS),
+                )?;
+                if variant == 0 {
+                    MapChange::Insert { key, value }
+                } else {
+                    MapChange::Remove { key, value }
+                }
+            }
+            _ => {
+                let (key, diff) = data.struct_variant(
+                    KEY_DIFF_FIELDS,
+                    PairVisitor(value, DiffDeserializer::new(self.0), KEY_DIFF_FIELDS),
+                )?;
+                MapChange::Modify { key, diff }
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct SetChangeSeed<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for SetChangeSeed<'a> {
+    type Value = SetChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("SetChange", SET_CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SetChangeSeed<'a> {
+    type Value = SetChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a set change")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, data) = data.variant_seed(NameSeed(SET_CHANGE_VARIANTS))?;
+        let value = data.newtype_variant_seed(ValueSeed(self.0))?;
+        Ok(match variant {
+            0 => SetChange::Insert(value),
+            _ => SetChange::Remove(value),
+        })
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     attributes::{impl_custom_attribute_methods, CustomAttributes},
+    std_traits::ReflectDefault,
     type_info::impl_type_methods,
-    MaybeTyped, PartialReflect, Type, TypeInfo, TypePath,
+    MaybeTyped, PartialReflect, Type, TypeInfo, TypePath, TypeRegistry,
 };
 use alloc::sync::Arc;
 
@@ -12,6 +13,7 @@ pub struct NamedField {
     type_info: fn() -> Option<&'static TypeInfo>,
     ty: Type,
     custom_attributes: Arc<CustomAttributes>,
+    metadata: FieldMetadata,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -24,6 +26,7 @@ impl NamedField {
             type_info: T::maybe_type_info,
             ty: Type::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            metadata: FieldMetadata::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -43,6 +46,27 @@ impl NamedField {
         }
     }
 
+    /// Sets the [metadata] for this field.
+    ///
+    /// [metadata]: FieldMetadata
+    pub fn with_metadata(self, metadata: FieldMetadata) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// The well-known [metadata] of this field, such as its display name or numeric range.
+    ///
+    /// [metadata]: FieldMetadata
+    pub fn metadata(&self) -> &FieldMetadata {
+        &self.metadata
+    }
+
+    /// Returns the default value of this field, if it has one.
+    ///
+    /// See [`FieldMetadata::default_value`] for more information.
+    pub fn default_value(&self, registry: &TypeRegistry) -> Option<Box<dyn PartialReflect>> {
+        self.metadata.default_value(self.ty, registry)
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &'static str {
         self.name
@@ -75,6 +99,7 @@ pub struct UnnamedField {
     type_info: fn() -> Option<&'static TypeInfo>,
     ty: Type,
     custom_attributes: Arc<CustomAttributes>,
+    metadata: FieldMetadata,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -86,6 +111,7 @@ impl UnnamedField {
             type_info: T::maybe_type_info,
             ty: Type::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            metadata: FieldMetadata::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -105,6 +131,27 @@ impl UnnamedField {
         }
     }
 
+    /// Sets the [metadata] for this field.
+    ///
+    /// [metadata]: FieldMetadata
+    pub fn with_metadata(self, metadata: FieldMetadata) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// The well-known [metadata] of this field, such as its display name or numeric range.
+    ///
+    /// [metadata]: FieldMetadata
+    pub fn metadata(&self) -> &FieldMetadata {
+        &self.metadata
+    }
+
+    /// Returns the default value of this field, if it has one.
+    ///
+    /// See [`FieldMetadata::default_value`] for more information.
+    pub fn default_value(&self, registry: &TypeRegistry) -> Option<Box<dyn PartialReflect>> {
+        self.metadata.default_value(self.ty, registry)
+    }
+
     /// Returns the index of the field.
     pub fn index(&self) -> usize {
         self.index
@@ -129,3 +176,164 @@ impl UnnamedField {
 
     impl_custom_attribute_methods!(self.custom_attributes, "field");
 }
+
+/// Well-known metadata of a field, for use by editors and schema exporters.
+///
+/// This is set using field attributes in `#[derive(Reflect)]`:
+///
+/// * `#[reflect(display_name = "...")]` sets the name shown for the field
+/// * `#[reflect(range = min..=max)]` sets the numeric range of the field (either bound may be omitted)
+/// * `#[reflect(step = ...)]` sets the numeric step of the field
+/// * `#[reflect(hidden)]` hides the field
+/// * `#[reflect(read_only)]` makes the field read-only
+/// * `#[reflect(default)]` and `#[reflect(default = "path::to::func")]` give the field a [default value]
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeInfo, TypeRegistry, Typed};
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(display_name = "Health", range = 0..=100, step = 5)]
+///     health: u32,
+///     #[reflect(default, read_only)]
+///     level: u32,
+///     #[reflect(hidden)]
+///     seed: u64,
+/// }
+///
+/// let TypeInfo::Struct(info) = Player::type_info() else { unreachable!() };
+///
+/// let health = info.field("health").unwrap().metadata();
+/// assert_eq!(health.display_name(), Some("Health"));
+/// assert_eq!((health.min(), health.max(), health.step()), (Some(0.0), Some(100.0), Some(5.0)));
+///
+/// let registry = TypeRegistry::new();
+/// let level = info.field("level").unwrap();
+/// assert!(level.metadata().is_read_only());
+/// assert_eq!(level.default_value(&registry).unwrap().try_downcast_ref::<u32>(), Some(&0));
+///
+/// assert!(info.field("seed").unwrap().metadata().is_hidden());
+/// ```
+///
+/// [default value]: Self::default_value
+#[derive(Clone, Debug, Default)]
+pub struct FieldMetadata {
+    display_name: Option<&'static str>,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: Option<f64>,
+    hidden: bool,
+    read_only: bool,
+    default: FieldDefault,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+enum FieldDefault {
+    #[default]
+    None,
+    FromType,
+    Func(fn() -> Box<dyn PartialReflect>),
+}
+
+impl FieldMetadata {
+    /// Sets the name shown for the field.
+    pub fn with_display_name(self, display_name: &'static str) -> Self {
+        Self {
+            display_name: Some(display_name),
+            ..self
+        }
+    }
+
+    /// Sets the inclusive numeric range of the field.
+    pub fn with_range(self, min: Option<f64>, max: Option<f64>) -> Self {
+        Self { min, max, ..self }
+    }
+
+    /// Sets the numeric step of the field.
+    pub fn with_step(self, step: f64) -> Self {
+        Self {
+            step: Some(step),
+            ..self
+        }
+    }
+
+    /// Sets whether the field is hidden.
+    pub fn with_hidden(self, hidden: bool) -> Self {
+        Self { hidden, ..self }
+    }
+
+    /// Sets whether the field is read-only.
+    pub fn with_read_only(self, read_only: bool) -> Self {
+        Self { read_only, ..self }
+    }
+
+    /// Makes the default value of the field the one registered by the field type's [`ReflectDefault`].
+    pub fn with_default(self) -> Self {
+        Self {
+            default: FieldDefault::FromType,
+            ..self
+        }
+    }
+
+    /// Makes the default value of the field the one returned by `default`.
+    pub fn with_default_fn(self, default: fn() -> Box<dyn PartialReflect>) -> Self {
+        Self {
+            default: FieldDefault::Func(default),
+            ..self
+        }
+    }
+
+    /// The name shown for the field, if any.
+    pub fn display_name(&self) -> Option<&'static str> {
+        self.display_name
+    }
+
+    /// The inclusive minimum value of the field, if any.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// The inclusive maximum value of the field, if any.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// The numeric step of the field, if any.
+    pub fn step(&self) -> Option<f64> {
+        self.step
+    }
+
+    /// Whether the field is hidden.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Whether the field is read-only.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Whether the field has a default value.
+    pub fn has_default(&self) -> bool {
+        !matches!(self.default, FieldDefault::None)
+    }
+
+    /// Returns the default value of a field of type `ty`, if it has one.
+    ///
+    /// For fields marked `#[reflect(default)]`, the value is created using the [`ReflectDefault`]
+    /// registered for `ty`, so `None` is returned if it isn't registered.
+    pub fn default_value(
+        &self,
+        ty: Type,
+        registry: &TypeRegistry,
+    ) -> Option<Box<dyn PartialReflect>> {
+        match self.default {
+            FieldDefault::None => None,
+            FieldDefault::FromType => registry
+                .get_type_data::<ReflectDefault>(ty.id())
+                .map(|default| default.default().into_partial_reflect()),
+            FieldDefault::Func(default) => Some(default()),
+        }
+    }
+}
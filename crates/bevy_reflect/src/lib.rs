@@ -233,6 +233,9 @@
 //! assert_eq!(None, value);
 //! ```
 //!
+//! Patches can also be computed from two values of the same type with [`diff::diff`],
+//! which only records the parts that changed and can be inverted and serialized.
+//!
 //! ## `FromReflect`
 //!
 //! It's important to remember that dynamic types are _not_ the concrete type they may be representing.
@@ -593,6 +596,7 @@ mod impls {
 }
 
 pub mod attributes;
+pub mod diff;
 mod enums;
 mod generics;
 pub mod serde;
@@ -1641,6 +1645,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_derive_field_metadata() {
+        fn default_speed<T: From<u8>>() -> T {
+            T::from(3)
+        }
+
+        #[derive(Reflect)]
+        struct Mover<T: From<u8>> {
+            #[reflect(range = 0.., step = 0.5, default = "default_speed")]
+            speed: T,
+            #[reflect(display_name = "Name", read_only)]
+            name: String,
+        }
+
+        #[derive(Reflect)]
+        struct Color(#[reflect(range = 0.0..=1.0, hidden)] f32);
+
+        #[derive(Reflect)]
+        enum Shape {
+            Circle {
+                #[reflect(range = ..=10)]
+                radius: f32,
+            },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Mover<f64>>();
+
+        let info = <Mover<f64> as Typed>::type_info().as_struct().unwrap();
+        let speed = info.field("speed").unwrap();
+        assert_eq!(speed.metadata().min(), Some(0.0));
+        assert_eq!(speed.metadata().max(), None);
+        assert_eq!(speed.metadata().step(), Some(0.5));
+        assert_eq!(
+            speed
+                .default_value(&registry)
+                .unwrap()
+                .try_downcast_ref::<f64>(),
+            Some(&3.0)
+        );
+        let name = info.field("name").unwrap();
+        assert_eq!(name.metadata().display_name(), Some("Name"));
+        assert!(name.metadata().is_read_only());
+        assert!(!name.metadata().has_default());
+        assert!(name.default_value(&registry).is_none());
+
+        let info = Color::type_info().as_tuple_struct().unwrap();
+        let field = info.field_at(0).unwrap();
+        assert_eq!(field.metadata().min(), Some(0.0));
+        assert_eq!(field.metadata().max(), Some(1.0));
+        assert!(field.metadata().is_hidden());
+        assert!(!field.metadata().is_read_only());
+
+        let info = Shape::type_info().as_enum().unwrap();
+        let VariantInfo::Struct(circle) = info.variant("Circle").unwrap() else {
+            panic!("expected struct variant");
+        };
+        let radius = circle.field("radius").unwrap();
+        assert_eq!(radius.metadata().min(), None);
+        assert_eq!(radius.metadata().max(), Some(10.0));
+    }
+
     #[test]
     fn reflect_type_info() {
         // TypeInfo
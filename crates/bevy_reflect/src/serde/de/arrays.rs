@@ -1,5 +1,7 @@
 use crate::{
-    serde::{de::registration_utils::try_get_registration, TypedReflectDeserializer},
+    serde::{
+        de::registration_utils::try_get_registration, TypeVersionTable, TypedReflectDeserializer,
+    },
     ArrayInfo, DynamicArray, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
@@ -11,13 +13,19 @@ use serde::de::{Error, SeqAccess, Visitor};
 pub(super) struct ArrayVisitor<'a> {
     array_info: &'static ArrayInfo,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> ArrayVisitor<'a> {
-    pub fn new(array_info: &'static ArrayInfo, registry: &'a TypeRegistry) -> Self {
+    pub fn new(
+        array_info: &'static ArrayInfo,
+        registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
+    ) -> Self {
         Self {
             array_info,
             registry,
+            versions,
         }
     }
 }
@@ -38,6 +46,7 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer::new_internal(
             registration,
             self.registry,
+            self.versions,
         ))? {
             vec.push(value);
         }
@@ -0,0 +1,585 @@
+use crate::{
+    serde::{
+        de::{error_utils::make_custom_error, registration_utils::try_get_registration},
+        ReflectDeserializeWithRegistry, SerializationData, COMPACT_FORMAT_VERSION,
+    },
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Map, PartialReflect, ReflectDeserialize, Set, Type,
+    TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+};
+use bevy_utils::HashMap;
+use core::{any::TypeId, fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+
+/// A deserializer for reflected values serialized by a [`CompactReflectSerializer`].
+///
+/// # Input
+///
+/// This deserializer expects the output of a [`CompactReflectSerializer`]: a header containing
+/// the type table, the id of the type of the value, and the value.
+/// The types of the table are looked up by their [stable type id].
+///
+/// The type table records the layout each type had when the data was written.
+/// This allows reading data written before fields were appended to a struct, a tuple struct or
+/// an enum variant, or before variants were appended to an enum. Fields can only be appended:
+/// removing or reordering fields makes older data unreadable.
+///
+/// # Output
+///
+/// Like the [`ReflectDeserializer`], this deserializer returns a [`Box<dyn PartialReflect>`]
+/// containing the dynamic equivalent of the value, or the concrete value for types that register
+/// [`ReflectDeserialize`] type data.
+///
+/// Fields that were appended after the data was written are missing from the output.
+/// They can be filled in by [`FromReflect`] if the type implements [`Default`] and registers it
+/// with `#[reflect(Default)]`, or if the fields are marked with `#[reflect(default)]`.
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::CompactReflectDeserializer};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct MyStruct {
+///   value: i32,
+///   // This field was added after the data was written.
+///   #[reflect(default)]
+///   extra: bool,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+///
+/// // Data written when `MyStruct` only had one field.
+/// let id = registry.get(TypeId::of::<MyStruct>()).unwrap().stable_type_id();
+/// let input = format!("((1, [({id}, [1])]), 0, (123))");
+///
+/// let mut deserializer = ron::Deserializer::from_str(&input).unwrap();
+/// let reflect_deserializer = CompactReflectDeserializer::new(&registry);
+///
+/// let output: Box<dyn PartialReflect> = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+///
+/// let value: MyStruct = <MyStruct as FromReflect>::from_reflect(output.as_partial_reflect()).unwrap();
+/// assert_eq!(value, MyStruct { value: 123, extra: false });
+/// ```
+///
+/// [`CompactReflectSerializer`]: crate::serde::CompactReflectSerializer
+/// [stable type id]: TypeRegistration::stable_type_id
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+/// [`Box<dyn PartialReflect>`]: crate::PartialReflect
+/// [`FromReflect`]: crate::FromReflect
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a header, a type id and a reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let (version, types): (u32, Vec<(u64, Vec<u32>)>) = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        if version > COMPACT_FORMAT_VERSION {
+            return Err(make_custom_error(format_args!(
+                "unsupported compact format version {version}, expected at most {COMPACT_FORMAT_VERSION}",
+            )));
+        }
+        let table = TypeTable::new(types, self.registry)?;
+
+        let root_id: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        let registration = table.types.get(root_id as usize).ok_or_else(|| {
+            make_custom_error(format_args!("no type with id {root_id} in the type table"))
+        })?;
+
+        seq.next_element_seed(CompactValueDeserializer {
+            registration,
+            table: &table,
+            registry: self.registry,
+        })?
+        .ok_or_else(|| Error::invalid_length(2, &self))
+    }
+}
+
+/// The type table read from the header.
+struct TypeTable<'a> {
+    /// The registrations of the types, in the order of their ids.
+    types: Vec<&'a TypeRegistration>,
+    /// The layout each type had when the data was written.
+    layouts: HashMap<TypeId, Vec<u32>>,
+}
+
+impl<'a> TypeTable<'a> {
+    fn new<E: Error>(types: Vec<(u64, Vec<u32>)>, registry: &'a TypeRegistry) -> Result<Self, E> {
+        let mut table = TypeTable {
+            types: Vec::with_capacity(types.len()),
+            layouts: HashMap::default(),
+        };
+        for (stable_type_id, layout) in types {
+            let registration = registry
+                .get_with_stable_type_id(stable_type_id)
+                .ok_or_else(|| {
+                    if registry.is_stable_type_id_ambiguous(stable_type_id) {
+                        make_custom_error(format_args!(
+                            "stable type id {stable_type_id} is shared by several registered types"
+                        ))
+                    } else {
+                        make_custom_error(format_args!(
+                            "no registration found for stable type id {stable_type_id}"
+                        ))
+                    }
+                })?;
+            table.types.push(registration);
+            table.layouts.insert(registration.type_id(), layout);
+        }
+        Ok(table)
+    }
+
+    /// Returns the number of fields written for the type, or for its variant at `index` if it
+    /// is an enum.
+    fn written_len<E: Error>(&self, info: &TypeInfo, index: usize) -> Result<usize, E> {
+        self.layouts
+            .get(&info.type_id())
+            .and_then(|layout| layout.get(index))
+            .map(|len| *len as usize)
+            .ok_or_else(|| {
+                make_custom_error(format_args!(
+                    "missing layout of `{}` in the type table",
+                    info.type_path()
+                ))
+            })
+    }
+}
+
+/// Deserializes a value whose type is known, using the layouts of the type table.
+struct CompactValueDeserializer<'a, 't> {
+    registration: &'a TypeRegistration,
+    table: &'t TypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 't, 'de> DeserializeSeed<'de> for CompactValueDeserializer<'a, 't> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+            let value = deserialize_reflect.deserialize(deserializer)?;
+            return Ok(value.into_partial_reflect());
+        }
+
+        if let Some(deserialize_reflect) =
+            self.registration.data::<ReflectDeserializeWithRegistry>()
+        {
+            return deserialize_reflect.deserialize(deserializer, self.registry);
+        }
+
+        let type_info = self.registration.type_info();
+        let serialization_data = self.registration.data::<SerializationData>();
+        match type_info {
+            TypeInfo::Struct(struct_info) => {
+                let written_len = self.table.written_len(type_info, 0)?;
+                let types: Vec<_> = struct_info.iter().map(|field| *field.ty()).collect();
+                let fields = self
+                    .fields(&types, written_len, serialization_data)
+                    .deserialize(deserializer)?;
+                let mut dynamic_struct = DynamicStruct::default();
+                for (index, value) in fields {
+                    dynamic_struct.insert_boxed(struct_info.field_at(index).unwrap().name(), value);
+                }
+                dynamic_struct.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                let written_len = self.table.written_len(type_info, 0)?;
+                let types: Vec<_> = tuple_struct_info.iter().map(|field| *field.ty()).collect();
+                let fields = self
+                    .fields(&types, written_len, serialization_data)
+                    .deserialize(deserializer)?;
+                let mut dynamic_tuple_struct = DynamicTupleStruct::default();
+                for (_, value) in fields {
+                    dynamic_tuple_struct.insert_boxed(value);
+                }
+                dynamic_tuple_struct.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::Tuple(tuple_info) => {
+                let types: Vec<_> = tuple_info.iter().map(|field| *field.ty()).collect();
+                let fields = self
+                    .fields(&types, types.len(), None)
+                    .deserialize(deserializer)?;
+                let mut dynamic_tuple: DynamicTuple =
+                    fields.into_iter().map(|(_, value)| value).collect();
+                dynamic_tuple.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_tuple))
+            }
+            TypeInfo::Array(array_info) => {
+                let types = vec![array_info.item_ty(); array_info.capacity()];
+                let fields = self
+                    .fields(&types, types.len(), None)
+                    .deserialize(deserializer)?;
+                let mut dynamic_array: DynamicArray =
+                    fields.into_iter().map(|(_, value)| value).collect();
+                dynamic_array.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_array))
+            }
+            TypeInfo::List(list_info) => {
+                let items = deserializer.deserialize_seq(ItemsVisitor {
+                    registration: try_get_registration(list_info.item_ty(), self.registry)?,
+                    table: self.table,
+                    registry: self.registry,
+                })?;
+                let mut dynamic_list = DynamicList::default();
+                items
+                    .into_iter()
+                    .for_each(|item| dynamic_list.push_box(item));
+                dynamic_list.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_list))
+            }
+            TypeInfo::Set(set_info) => {
+                let items = deserializer.deserialize_seq(ItemsVisitor {
+                    registration: try_get_registration(set_info.value_ty(), self.registry)?,
+                    table: self.table,
+                    registry: self.registry,
+                })?;
+                let mut dynamic_set = DynamicSet::default();
+                items.into_iter().for_each(|item| {
+                    dynamic_set.insert_boxed(item);
+                });
+                dynamic_set.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_set))
+            }
+            TypeInfo::Map(map_info) => {
+                let mut dynamic_map = deserializer.deserialize_map(EntriesVisitor {
+                    key_registration: try_get_registration(map_info.key_ty(), self.registry)?,
+                    value_registration: try_get_registration(map_info.value_ty(), self.registry)?,
+                    table: self.table,
+                    registry: self.registry,
+                })?;
+                dynamic_map.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_map))
+            }
+            TypeInfo::Enum(enum_info)
+                if enum_info.type_path_table().module_path() == Some("core::option")
+                    && enum_info.type_path_table().ident() == Some("Option") =>
+            {
+                let Some(VariantInfo::Tuple(some_info)) = enum_info.variant("Some") else {
+                    return Err(make_custom_error("expected `Some` to be a tuple variant"));
+                };
+                let mut dynamic_enum = deserializer.deserialize_option(OptionVisitor {
+                    registration: try_get_registration(
+                        *some_info.field_at(0).unwrap().ty(),
+                        self.registry,
+                    )?,
+                    table: self.table,
+                    registry: self.registry,
+                })?;
+                dynamic_enum.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_enum))
+            }
+            TypeInfo::Enum(_) => {
+                let mut dynamic_enum = deserializer.deserialize_tuple(2, self)?;
+                dynamic_enum.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_enum))
+            }
+            TypeInfo::Opaque(_) => Err(make_custom_error(format_args!(
+                "type `{}` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`",
+                type_info.type_path(),
+            ))),
+        }
+    }
+}
+
+impl<'a, 't> CompactValueDeserializer<'a, 't> {
+    fn fields(
+        &self,
+        types: &[Type],
+        written_len: usize,
+        serialization_data: Option<&'a SerializationData>,
+    ) -> FieldsSeed<'a, 't> {
+        FieldsSeed {
+            types: types.to_vec(),
+            written_len,
+            serialization_data,
+            table: self.table,
+            registry: self.registry,
+        }
+    }
+}
+
+/// Visits an enum as its variant index and the fields of the variant.
+impl<'a, 't, 'de> Visitor<'de> for CompactValueDeserializer<'a, 't> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a variant index and the fields of the variant")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_info = self.registration.type_info();
+        let TypeInfo::Enum(enum_info) = type_info else {
+            return Err(make_custom_error(format_args!(
+                "expected enum type but received {type_info:?}"
+            )));
+        };
+
+        let variant_index: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let variant_index = variant_index as usize;
+        let variant_info = enum_info.variant_at(variant_index).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "no variant at index {variant_index} on enum `{}`",
+                enum_info.type_path()
+            ))
+        })?;
+        let written_len = self.table.written_len(type_info, variant_index)?;
+
+        let variant = match variant_info {
+            VariantInfo::Struct(struct_info) => {
+                let types: Vec<_> = struct_info.iter().map(|field| *field.ty()).collect();
+                let fields = seq
+                    .next_element_seed(self.fields(&types, written_len, None))?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+                let mut dynamic_struct = DynamicStruct::default();
+                for (index, value) in fields {
+                    dynamic_struct.insert_boxed(struct_info.field_at(index).unwrap().name(), value);
+                }
+                DynamicVariant::Struct(dynamic_struct)
+            }
+            VariantInfo::Tuple(tuple_info) => {
+                let types: Vec<_> = tuple_info.iter().map(|field| *field.ty()).collect();
+                let fields = seq
+                    .next_element_seed(self.fields(&types, written_len, None))?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+                DynamicVariant::Tuple(fields.into_iter().map(|(_, value)| value).collect())
+            }
+            VariantInfo::Unit(_) => {
+                seq.next_element_seed(self.fields(&[], written_len, None))?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+                DynamicVariant::Unit
+            }
+        };
+
+        Ok(DynamicEnum::new_with_index(
+            variant_index,
+            variant_info.name(),
+            variant,
+        ))
+    }
+}
+
+/// Deserializes the fields of a struct-like or tuple-like value that were written, returning
+/// them with their index.
+///
+/// Skipped fields are filled in with their default value.
+struct FieldsSeed<'a, 't> {
+    types: Vec<Type>,
+    written_len: usize,
+    serialization_data: Option<&'a SerializationData>,
+    table: &'t TypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 't> FieldsSeed<'a, 't> {
+    fn is_skipped(&self, index: usize) -> bool {
+        self.serialization_data
+            .is_some_and(|data| data.is_field_skipped(index))
+    }
+}
+
+impl<'a, 't, 'de> DeserializeSeed<'de> for FieldsSeed<'a, 't> {
+    type Value = Vec<(usize, Box<dyn PartialReflect>)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if self.written_len > self.types.len() {
+            return Err(make_custom_error(format_args!(
+                "expected at most {} fields but the data has {}. Fields can only be appended to a type",
+                self.types.len(),
+                self.written_len,
+            )));
+        }
+        let len = (0..self.written_len)
+            .filter(|index| !self.is_skipped(*index))
+            .count();
+        deserializer.deserialize_tuple(len, self)
+    }
+}
+
+impl<'a, 't, 'de> Visitor<'de> for FieldsSeed<'a, 't> {
+    type Value = Vec<(usize, Box<dyn PartialReflect>)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{} fields", self.written_len)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut fields = Vec::with_capacity(self.types.len());
+        for (index, ty) in self.types.iter().enumerate() {
+            if self.is_skipped(index) {
+                if let Some(value) = self.serialization_data.unwrap().generate_default(index) {
+                    fields.push((index, value.into_partial_reflect()));
+                }
+                continue;
+            }
+            if index >= self.written_len {
+                continue;
+            }
+            let value = seq
+                .next_element_seed(CompactValueDeserializer {
+                    registration: try_get_registration(*ty, self.registry)?,
+                    table: self.table,
+                    registry: self.registry,
+                })?
+                .ok_or_else(|| Error::invalid_length(index, &self))?;
+            fields.push((index, value));
+        }
+        Ok(fields)
+    }
+}
+
+/// Visits an [`Option`].
+struct OptionVisitor<'a, 't> {
+    registration: &'a TypeRegistration,
+    table: &'t TypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 't, 'de> Visitor<'de> for OptionVisitor<'a, 't> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an option")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(DynamicEnum::new_with_index(0, "None", DynamicVariant::Unit))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = CompactValueDeserializer {
+            registration: self.registration,
+            table: self.table,
+            registry: self.registry,
+        }
+        .deserialize(deserializer)?;
+        let mut tuple = DynamicTuple::default();
+        tuple.insert_boxed(value);
+        Ok(DynamicEnum::new_with_index(
+            1,
+            "Some",
+            DynamicVariant::Tuple(tuple),
+        ))
+    }
+}
+
+/// Visits the items of a list or a set.
+struct ItemsVisitor<'a, 't> {
+    registration: &'a TypeRegistration,
+    table: &'t TypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 't, 'de> Visitor<'de> for ItemsVisitor<'a, 't> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(item) = seq.next_element_seed(CompactValueDeserializer {
+            registration: self.registration,
+            table: self.table,
+            registry: self.registry,
+        })? {
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
+
+/// Visits the entries of a map.
+struct EntriesVisitor<'a, 't> {
+    key_registration: &'a TypeRegistration,
+    value_registration: &'a TypeRegistration,
+    table: &'t TypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 't, 'de> Visitor<'de> for EntriesVisitor<'a, 't> {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of reflected values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dynamic_map = DynamicMap::default();
+        while let Some(key) = map.next_key_seed(CompactValueDeserializer {
+            registration: self.key_registration,
+            table: self.table,
+            registry: self.registry,
+        })? {
+            let value = map.next_value_seed(CompactValueDeserializer {
+                registration: self.value_registration,
+                table: self.table,
+                registry: self.registry,
+            })?;
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(dynamic_map)
+    }
+}
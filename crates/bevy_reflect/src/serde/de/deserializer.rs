@@ -5,10 +5,11 @@ use crate::{
     serde::{
         de::{
             arrays::ArrayVisitor, enums::EnumVisitor, error_utils::make_custom_error,
-            lists::ListVisitor, maps::MapVisitor, options::OptionVisitor, sets::SetVisitor,
-            structs::StructVisitor, tuple_structs::TupleStructVisitor, tuples::TupleVisitor,
+            lists::ListVisitor, maps::MapVisitor, options::OptionVisitor,
+            registrations::get_versioned_registration, sets::SetVisitor, structs::StructVisitor,
+            tuple_structs::TupleStructVisitor, tuples::TupleVisitor,
         },
-        TypeRegistrationDeserializer,
+        MigratingReflectDeserializer, TypeVersion, TypeVersionTable, TypeVersionTableDeserializer,
     },
     PartialReflect, ReflectDeserialize, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
 };
@@ -27,6 +28,11 @@ use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
 /// where the key is the _full_ [type path] of the reflected type
 /// and the value is the serialized data.
 ///
+/// If the type declares a [`TypeVersion`], the type path is followed by the version of the data,
+/// and data written by older versions is migrated using the type's [`TypeMigrations`].
+/// This entry may be preceded by a [`TypeVersionTable`] recording the versions of nested types,
+/// so that nested values are migrated too.
+///
 /// # Output
 ///
 /// This deserializer will return a [`Box<dyn Reflect>`] containing the deserialized data.
@@ -87,6 +93,9 @@ use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
 ///
 /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
 /// [type path]: crate::TypePath::type_path
+/// [`TypeVersion`]: crate::serde::TypeVersion
+/// [`TypeMigrations`]: crate::serde::TypeMigrations
+/// [`TypeVersionTable`]: crate::serde::TypeVersionTable
 /// [`Box<dyn Reflect>`]: crate::Reflect
 /// [`ReflectKind::Opaque`]: crate::ReflectKind::Opaque
 /// [`ReflectDeserialize`]: crate::ReflectDeserialize
@@ -127,14 +136,28 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
             where
                 A: MapAccess<'de>,
             {
-                let registration = map
-                    .next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+                let mut versions = TypeVersionTable::new();
+                let mut key = map
+                    .next_key_seed(EntryKeyDeserializer(self.registry))?
                     .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
+                if let EntryKey::Versions = key {
+                    versions =
+                        map.next_value_seed(TypeVersionTableDeserializer::new(self.registry))?;
+                    key = map
+                        .next_key_seed(EntryKeyDeserializer(self.registry))?
+                        .ok_or_else(|| Error::invalid_length(1, &"a value after the versions"))?;
+                }
+                let EntryKey::Type(registration, version) = key else {
+                    return Err(Error::custom(format_args!(
+                        "duplicate `{}` entry",
+                        TypeVersionTable::KEY
+                    )));
+                };
 
-                let value = map.next_value_seed(TypedReflectDeserializer {
-                    registration,
-                    registry: self.registry,
-                })?;
+                let value = map.next_value_seed(
+                    MigratingReflectDeserializer::new(registration, version, self.registry)
+                        .with_versions(&versions),
+                )?;
 
                 if map.next_key::<IgnoredAny>()?.is_some() {
                     return Err(Error::invalid_length(2, &"a single entry"));
@@ -150,6 +173,48 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
     }
 }
 
+/// The key of an entry in the map read by the [`ReflectDeserializer`].
+enum EntryKey<'a> {
+    /// The [`TypeVersionTable`] of the value.
+    Versions,
+    /// The type of the value and the version it was written at.
+    Type(&'a TypeRegistration, u32),
+}
+
+struct EntryKeyDeserializer<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for EntryKeyDeserializer<'a> {
+    type Value = EntryKey<'a>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct EntryKeyVisitor<'a>(&'a TypeRegistry);
+
+        impl<'a, 'de> Visitor<'de> for EntryKeyVisitor<'a> {
+            type Value = EntryKey<'a>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("string containing `type` entry for the reflected value")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                if value == TypeVersionTable::KEY {
+                    return Ok(EntryKey::Versions);
+                }
+                let (registration, version) = get_versioned_registration(self.0, value)?;
+                Ok(EntryKey::Type(registration, version))
+            }
+        }
+
+        deserializer.deserialize_str(EntryKeyVisitor(self.0))
+    }
+}
+
 /// A deserializer for reflected types whose [`TypeRegistration`] is known.
 ///
 /// This is the deserializer counterpart to [`TypedReflectSerializer`].
@@ -229,6 +294,7 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
 pub struct TypedReflectDeserializer<'a> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> TypedReflectDeserializer<'a> {
@@ -240,6 +306,7 @@ impl<'a> TypedReflectDeserializer<'a> {
         Self {
             registration,
             registry,
+            versions: None,
         }
     }
 
@@ -256,29 +323,47 @@ impl<'a> TypedReflectDeserializer<'a> {
         Self {
             registration,
             registry,
+            versions: None,
         }
     }
 
+    /// Sets the versions at which the types nested in the data were written.
+    ///
+    /// Values whose type was written at an older [version] are migrated using the type's
+    /// [`TypeMigrations`], as done by the [`MigratingReflectDeserializer`].
+    /// Without a table, all values are read as the current version of their type.
+    ///
+    /// [version]: crate::serde::TypeVersion
+    /// [`TypeMigrations`]: crate::serde::TypeMigrations
+    pub fn with_versions(mut self, versions: &'a TypeVersionTable) -> Self {
+        self.versions = Some(versions);
+        self
+    }
+
     /// An internal constructor for creating a deserializer without resetting the type info stack.
     pub(super) fn new_internal(
         registration: &'a TypeRegistration,
         registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
     ) -> Self {
         Self {
             registration,
             registry,
+            versions,
         }
     }
-}
-
-impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
-    type Value = Box<dyn PartialReflect>;
 
-    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    /// Deserializes the data as the current version of the type.
+    ///
+    /// Nested values are still migrated according to the [`TypeVersionTable`], if any.
+    pub(super) fn deserialize_current<'de, D>(
+        self,
+        deserializer: D,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let deserialize_internal = || -> Result<Self::Value, D::Error> {
+        let deserialize_internal = || -> Result<Box<dyn PartialReflect>, D::Error> {
             let type_path = self.registration.type_info().type_path();
 
             // Handle both Value case and types that have a custom `ReflectDeserialize`
@@ -299,7 +384,12 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     let mut dynamic_struct = deserializer.deserialize_struct(
                         struct_info.type_path_table().ident().unwrap(),
                         struct_info.field_names(),
-                        StructVisitor::new(struct_info, self.registration, self.registry),
+                        StructVisitor::new(
+                            struct_info,
+                            self.registration,
+                            self.registry,
+                            self.versions,
+                        ),
                     )?;
                     dynamic_struct.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_struct))
@@ -314,6 +404,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                                 tuple_struct_info,
                                 self.registration,
                                 self.registry,
+                                self.versions,
                             ),
                         )?
                     } else {
@@ -324,6 +415,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                                 tuple_struct_info,
                                 self.registration,
                                 self.registry,
+                                self.versions,
                             ),
                         )?
                     };
@@ -332,35 +424,49 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     Ok(Box::new(dynamic_tuple_struct))
                 }
                 TypeInfo::List(list_info) => {
-                    let mut dynamic_list =
-                        deserializer.deserialize_seq(ListVisitor::new(list_info, self.registry))?;
+                    let mut dynamic_list = deserializer.deserialize_seq(ListVisitor::new(
+                        list_info,
+                        self.registry,
+                        self.versions,
+                    ))?;
                     dynamic_list.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_list))
                 }
                 TypeInfo::Array(array_info) => {
                     let mut dynamic_array = deserializer.deserialize_tuple(
                         array_info.capacity(),
-                        ArrayVisitor::new(array_info, self.registry),
+                        ArrayVisitor::new(array_info, self.registry, self.versions),
                     )?;
                     dynamic_array.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_array))
                 }
                 TypeInfo::Map(map_info) => {
-                    let mut dynamic_map =
-                        deserializer.deserialize_map(MapVisitor::new(map_info, self.registry))?;
+                    let mut dynamic_map = deserializer.deserialize_map(MapVisitor::new(
+                        map_info,
+                        self.registry,
+                        self.versions,
+                    ))?;
                     dynamic_map.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_map))
                 }
                 TypeInfo::Set(set_info) => {
-                    let mut dynamic_set =
-                        deserializer.deserialize_seq(SetVisitor::new(set_info, self.registry))?;
+                    let mut dynamic_set = deserializer.deserialize_seq(SetVisitor::new(
+                        set_info,
+                        self.registry,
+                        self.versions,
+                    ))?;
                     dynamic_set.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_set))
                 }
                 TypeInfo::Tuple(tuple_info) => {
                     let mut dynamic_tuple = deserializer.deserialize_tuple(
                         tuple_info.field_len(),
-                        TupleVisitor::new(tuple_info, self.registration, self.registry),
+                        TupleVisitor::new(
+                            tuple_info,
+                            self.registration,
+                            self.registry,
+                            self.versions,
+                        ),
                     )?;
                     dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_tuple))
@@ -370,13 +476,21 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                         == Some("core::option")
                         && enum_info.type_path_table().ident() == Some("Option")
                     {
-                        deserializer
-                            .deserialize_option(OptionVisitor::new(enum_info, self.registry))?
+                        deserializer.deserialize_option(OptionVisitor::new(
+                            enum_info,
+                            self.registry,
+                            self.versions,
+                        ))?
                     } else {
                         deserializer.deserialize_enum(
                             enum_info.type_path_table().ident().unwrap(),
                             enum_info.variant_names(),
-                            EnumVisitor::new(enum_info, self.registration, self.registry),
+                            EnumVisitor::new(
+                                enum_info,
+                                self.registration,
+                                self.registry,
+                                self.versions,
+                            ),
                         )?
                     };
                     dynamic_enum.set_represented_type(Some(self.registration.type_info()));
@@ -402,3 +516,27 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
         output
     }
 }
+
+impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if let Some(versions) = self.versions {
+            let version = versions.get(self.registration.type_id());
+            if version != TypeVersion::of(self.registration.type_info()) {
+                return MigratingReflectDeserializer::new_internal(
+                    self.registration,
+                    version,
+                    self.registry,
+                    Some(versions),
+                )
+                .deserialize(deserializer);
+            }
+        }
+
+        self.deserialize_current(deserializer)
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
             struct_utils::{visit_struct, visit_struct_seq},
             tuple_utils::{visit_tuple, TupleLikeInfo},
         },
-        TypedReflectDeserializer,
+        TypeMigrations, TypeVersionTable, TypedReflectDeserializer,
     },
     DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, EnumInfo, StructVariantInfo,
     TupleVariantInfo, TypeRegistration, TypeRegistry, VariantInfo,
@@ -22,6 +22,7 @@ pub(super) struct EnumVisitor<'a> {
     enum_info: &'static EnumInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> EnumVisitor<'a> {
@@ -29,11 +30,13 @@ impl<'a> EnumVisitor<'a> {
         enum_info: &'static EnumInfo,
         registration: &'a TypeRegistration,
         registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
     ) -> Self {
         Self {
             enum_info,
             registration,
             registry,
+            versions,
         }
     }
 }
@@ -52,43 +55,16 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
         let mut dynamic_enum = DynamicEnum::default();
         let (variant_info, variant) = data.variant_seed(VariantDeserializer {
             enum_info: self.enum_info,
+            migrations: None,
         })?;
 
-        let value: DynamicVariant = match variant_info {
-            VariantInfo::Unit(..) => variant.unit_variant()?.into(),
-            VariantInfo::Struct(struct_info) => variant
-                .struct_variant(
-                    struct_info.field_names(),
-                    StructVariantVisitor {
-                        struct_info,
-                        registration: self.registration,
-                        registry: self.registry,
-                    },
-                )?
-                .into(),
-            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
-                let registration = try_get_registration(
-                    *TupleLikeInfo::field_at(tuple_info, 0)?.ty(),
-                    self.registry,
-                )?;
-                let value = variant.newtype_variant_seed(
-                    TypedReflectDeserializer::new_internal(registration, self.registry),
-                )?;
-                let mut dynamic_tuple = DynamicTuple::default();
-                dynamic_tuple.insert_boxed(value);
-                dynamic_tuple.into()
-            }
-            VariantInfo::Tuple(tuple_info) => variant
-                .tuple_variant(
-                    tuple_info.field_len(),
-                    TupleVariantVisitor {
-                        tuple_info,
-                        registration: self.registration,
-                        registry: self.registry,
-                    },
-                )?
-                .into(),
-        };
+        let value = visit_variant(
+            variant_info,
+            variant,
+            self.registration,
+            self.registry,
+            self.versions,
+        )?;
         let variant_name = variant_info.name();
         let variant_index = self
             .enum_info
@@ -99,20 +75,75 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
     }
 }
 
-struct VariantDeserializer {
-    enum_info: &'static EnumInfo,
+/// Deserializes the data of the enum variant described by `variant_info`.
+pub(super) fn visit_variant<'de, A>(
+    variant_info: &'static VariantInfo,
+    variant: A,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    versions: Option<&TypeVersionTable>,
+) -> Result<DynamicVariant, A::Error>
+where
+    A: VariantAccess<'de>,
+{
+    Ok(match variant_info {
+        VariantInfo::Unit(..) => variant.unit_variant()?.into(),
+        VariantInfo::Struct(struct_info) => variant
+            .struct_variant(
+                struct_info.field_names(),
+                StructVariantVisitor {
+                    struct_info,
+                    registration,
+                    registry,
+                    versions,
+                },
+            )?
+            .into(),
+        VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+            let registration =
+                try_get_registration(*TupleLikeInfo::field_at(tuple_info, 0)?.ty(), registry)?;
+            let value = variant.newtype_variant_seed(TypedReflectDeserializer::new_internal(
+                registration,
+                registry,
+                versions,
+            ))?;
+            let mut dynamic_tuple = DynamicTuple::default();
+            dynamic_tuple.insert_boxed(value);
+            dynamic_tuple.into()
+        }
+        VariantInfo::Tuple(tuple_info) => variant
+            .tuple_variant(
+                tuple_info.field_len(),
+                TupleVariantVisitor {
+                    tuple_info,
+                    registration,
+                    registry,
+                    versions,
+                },
+            )?
+            .into(),
+    })
+}
+
+/// Deserializes the [`VariantInfo`] of an enum from a variant index or name.
+///
+/// If `migrations` are given, variant names of data written at the given version are
+/// renamed to their current name first.
+pub(super) struct VariantDeserializer<'a> {
+    pub enum_info: &'static EnumInfo,
+    pub migrations: Option<(&'a TypeMigrations, u32)>,
 }
 
-impl<'de> DeserializeSeed<'de> for VariantDeserializer {
+impl<'a, 'de> DeserializeSeed<'de> for VariantDeserializer<'a> {
     type Value = &'static VariantInfo;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        struct VariantVisitor(&'static EnumInfo);
+        struct VariantVisitor<'a>(&'static EnumInfo, Option<(&'a TypeMigrations, u32)>);
 
-        impl<'de> Visitor<'de> for VariantVisitor {
+        impl<'a, 'de> Visitor<'de> for VariantVisitor<'a> {
             type Value = &'static VariantInfo;
 
             fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -136,6 +167,10 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
             where
                 E: Error,
             {
+                let variant_name = match self.1 {
+                    Some((migrations, version)) => migrations.variant_name(version, variant_name),
+                    None => variant_name,
+                };
                 self.0.variant(variant_name).ok_or_else(|| {
                     let names = self.0.iter().map(VariantInfo::name);
                     make_custom_error(format_args!(
@@ -147,7 +182,7 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
             }
         }
 
-        deserializer.deserialize_identifier(VariantVisitor(self.enum_info))
+        deserializer.deserialize_identifier(VariantVisitor(self.enum_info, self.migrations))
     }
 }
 
@@ -155,6 +190,7 @@ struct StructVariantVisitor<'a> {
     struct_info: &'static StructVariantInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
@@ -168,14 +204,26 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.versions,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.versions,
+        )
     }
 }
 
@@ -183,6 +231,7 @@ struct TupleVariantVisitor<'a> {
     tuple_info: &'static TupleVariantInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
@@ -196,6 +245,12 @@ impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.registration,
+            self.registry,
+            self.versions,
+        )
     }
 }
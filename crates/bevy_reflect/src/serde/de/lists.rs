@@ -1,5 +1,7 @@
 use crate::{
-    serde::{de::registration_utils::try_get_registration, TypedReflectDeserializer},
+    serde::{
+        de::registration_utils::try_get_registration, TypeVersionTable, TypedReflectDeserializer,
+    },
     DynamicList, ListInfo, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
@@ -11,13 +13,19 @@ use serde::de::{SeqAccess, Visitor};
 pub(super) struct ListVisitor<'a> {
     list_info: &'static ListInfo,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> ListVisitor<'a> {
-    pub fn new(list_info: &'static ListInfo, registry: &'a TypeRegistry) -> Self {
+    pub fn new(
+        list_info: &'static ListInfo,
+        registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
+    ) -> Self {
         Self {
             list_info,
             registry,
+            versions,
         }
     }
 }
@@ -38,6 +46,7 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer::new_internal(
             registration,
             self.registry,
+            self.versions,
         ))? {
             list.push_box(value);
         }
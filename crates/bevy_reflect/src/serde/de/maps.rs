@@ -1,5 +1,7 @@
 use crate::{
-    serde::{de::registration_utils::try_get_registration, TypedReflectDeserializer},
+    serde::{
+        de::registration_utils::try_get_registration, TypeVersionTable, TypedReflectDeserializer,
+    },
     DynamicMap, Map, MapInfo, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
@@ -11,11 +13,20 @@ use serde::de::{MapAccess, Visitor};
 pub(super) struct MapVisitor<'a> {
     map_info: &'static MapInfo,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> MapVisitor<'a> {
-    pub fn new(map_info: &'static MapInfo, registry: &'a TypeRegistry) -> Self {
-        Self { map_info, registry }
+    pub fn new(
+        map_info: &'static MapInfo,
+        registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
+    ) -> Self {
+        Self {
+            map_info,
+            registry,
+            versions,
+        }
     }
 }
 
@@ -36,10 +47,12 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
         while let Some(key) = map.next_key_seed(TypedReflectDeserializer::new_internal(
             key_registration,
             self.registry,
+            self.versions,
         ))? {
             let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
                 value_registration,
                 self.registry,
+                self.versions,
             ))?;
             dynamic_map.insert_boxed(key, value);
         }
@@ -0,0 +1,327 @@
+#[cfg(feature = "debug_stack")]
+use crate::serde::de::error_utils::TYPE_INFO_STACK;
+use crate::{
+    serde::{
+        de::{
+            enums::{visit_variant, VariantDeserializer},
+            error_utils::make_custom_error,
+            helpers::{ExpectedValues, Ident},
+            registration_utils::try_get_registration,
+            struct_utils::visit_struct_seq,
+        },
+        ReflectDeserializeWithRegistry, SerializationData, TypeMigrations, TypeVersion,
+        TypeVersionTable, TypedReflectDeserializer, VersionedTypeRegistrationDeserializer,
+    },
+    DynamicEnum, DynamicStruct, EnumInfo, NamedField, PartialReflect, ReflectDeserialize,
+    StructInfo, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use core::{fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, EnumAccess, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+/// A deserializer for reflected types whose data may have been written by an older
+/// [version] of the type.
+///
+/// This works like the [`TypedReflectDeserializer`], except that data written at an older version
+/// is migrated to the current version using the type's [`TypeMigrations`]:
+/// renamed fields and variants are read under their current name, removed fields are skipped,
+/// and the migration functions are run on the deserialized value.
+///
+/// Field renames and removals rely on field names being present in the data,
+/// so they are only supported by self-describing formats such as RON or JSON.
+///
+/// Nested values, such as the fields of a struct, are migrated as well if a [`TypeVersionTable`]
+/// recording the versions of their types is given with [`with_versions`](Self::with_versions).
+/// Otherwise, they are read as the current version of their types.
+///
+/// See [`TypeMigrations`] for an example.
+///
+/// # Output
+///
+/// Like the [`TypedReflectDeserializer`], this will usually return a dynamic value,
+/// which can be converted to the concrete type using [`FromReflect`].
+///
+/// [version]: TypeVersion
+/// [`FromReflect`]: crate::FromReflect
+pub struct MigratingReflectDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    version: u32,
+    registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
+}
+
+impl<'a> MigratingReflectDeserializer<'a> {
+    /// Creates a new [`MigratingReflectDeserializer`] for data written at `version`
+    /// of the given type.
+    pub fn new(
+        registration: &'a TypeRegistration,
+        version: u32,
+        registry: &'a TypeRegistry,
+    ) -> Self {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.set(crate::type_info_stack::TypeInfoStack::new());
+
+        Self::new_internal(registration, version, registry, None)
+    }
+
+    /// An internal constructor for creating a deserializer without resetting the type info stack.
+    pub(super) fn new_internal(
+        registration: &'a TypeRegistration,
+        version: u32,
+        registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
+    ) -> Self {
+        Self {
+            registration,
+            version,
+            registry,
+            versions,
+        }
+    }
+
+    /// Sets the versions at which the types nested in the data were written,
+    /// so that nested values are migrated too.
+    pub fn with_versions(mut self, versions: &'a TypeVersionTable) -> Self {
+        self.versions = Some(versions);
+        self
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MigratingReflectDeserializer<'a> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let type_info = self.registration.type_info();
+        let current_version = TypeVersion::of(type_info);
+        if self.version > current_version {
+            return Err(make_custom_error(format_args!(
+                "`{}` data was written at version {}, but the current version is {}",
+                type_info.type_path(),
+                self.version,
+                current_version,
+            )));
+        }
+
+        let typed_deserializer =
+            TypedReflectDeserializer::new_internal(self.registration, self.registry, self.versions);
+        let Some(migrations) = self
+            .registration
+            .data::<TypeMigrations>()
+            .filter(|_| self.version < current_version)
+        else {
+            return typed_deserializer.deserialize_current(deserializer);
+        };
+
+        let has_custom_deserialization = self.registration.data::<ReflectDeserialize>().is_some()
+            || self
+                .registration
+                .data::<ReflectDeserializeWithRegistry>()
+                .is_some();
+
+        Ok(match type_info {
+            TypeInfo::Struct(struct_info) if !has_custom_deserialization => {
+                let mut dynamic_struct = deserializer.deserialize_struct(
+                    struct_info.type_path_table().ident().unwrap(),
+                    struct_info.field_names(),
+                    MigratingStructVisitor {
+                        struct_info,
+                        migrations,
+                        version: self.version,
+                        registration: self.registration,
+                        registry: self.registry,
+                        versions: self.versions,
+                    },
+                )?;
+                dynamic_struct.set_represented_type(Some(type_info));
+                migrations.migrate_struct(&mut dynamic_struct, self.version);
+                Box::new(dynamic_struct)
+            }
+            TypeInfo::Enum(enum_info) if !has_custom_deserialization => {
+                let mut dynamic_enum = deserializer.deserialize_enum(
+                    enum_info.type_path_table().ident().unwrap(),
+                    enum_info.variant_names(),
+                    MigratingEnumVisitor {
+                        enum_info,
+                        migrations,
+                        version: self.version,
+                        registration: self.registration,
+                        registry: self.registry,
+                        versions: self.versions,
+                    },
+                )?;
+                dynamic_enum.set_represented_type(Some(type_info));
+                migrations.migrate_enum(&mut dynamic_enum, self.version);
+                Box::new(dynamic_enum)
+            }
+            _ => typed_deserializer.deserialize_current(deserializer)?,
+        })
+    }
+}
+
+struct MigratingStructVisitor<'a> {
+    struct_info: &'static StructInfo,
+    migrations: &'a TypeMigrations,
+    version: u32,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
+}
+
+impl<'a, 'de> Visitor<'de> for MigratingStructVisitor<'a> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected struct value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.versions,
+        )
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut dynamic_struct = DynamicStruct::default();
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            let Some(name) = self.migrations.field_name(self.version, &key) else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+            let field = self.struct_info.field(name).ok_or_else(|| {
+                let fields = self.struct_info.iter().map(NamedField::name);
+                make_custom_error(format_args!(
+                    "unknown field `{}`, expected one of {:?}",
+                    key,
+                    ExpectedValues::from_iter(fields)
+                ))
+            })?;
+            let registration = try_get_registration(*field.ty(), self.registry)?;
+            let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
+                registration,
+                self.registry,
+                self.versions,
+            ))?;
+            dynamic_struct.insert_boxed(field.name(), value);
+        }
+
+        if let Some(serialization_data) = self.registration.data::<SerializationData>() {
+            for (skipped_index, skipped_field) in serialization_data.iter_skipped() {
+                let Some(field) = self.struct_info.field_at(*skipped_index) else {
+                    continue;
+                };
+                dynamic_struct.insert_boxed(
+                    field.name(),
+                    skipped_field.generate_default().into_partial_reflect(),
+                );
+            }
+        }
+
+        Ok(dynamic_struct)
+    }
+}
+
+struct MigratingEnumVisitor<'a> {
+    enum_info: &'static EnumInfo,
+    migrations: &'a TypeMigrations,
+    version: u32,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
+}
+
+impl<'a, 'de> Visitor<'de> for MigratingEnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected enum value")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant_info, variant) = data.variant_seed(VariantDeserializer {
+            enum_info: self.enum_info,
+            migrations: Some((self.migrations, self.version)),
+        })?;
+
+        let value = visit_variant(
+            variant_info,
+            variant,
+            self.registration,
+            self.registry,
+            self.versions,
+        )?;
+        let variant_name = variant_info.name();
+        let variant_index = self
+            .enum_info
+            .index_of(variant_name)
+            .expect("variant should exist");
+        Ok(DynamicEnum::new_with_index(
+            variant_index,
+            variant_name,
+            value,
+        ))
+    }
+}
+
+/// A deserializer for a [`TypeVersionTable`].
+///
+/// This expects a sequence of versioned type paths, as read by the
+/// [`VersionedTypeRegistrationDeserializer`].
+pub struct TypeVersionTableDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypeVersionTableDeserializer<'a> {
+    /// Creates a new [`TypeVersionTableDeserializer`].
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for TypeVersionTableDeserializer<'a> {
+    type Value = TypeVersionTable;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct TypeVersionTableVisitor<'a>(&'a TypeRegistry);
+
+        impl<'a, 'de> Visitor<'de> for TypeVersionTableVisitor<'a> {
+            type Value = TypeVersionTable;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("sequence of versioned type paths")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut versions = TypeVersionTable::new();
+                while let Some((registration, version)) =
+                    seq.next_element_seed(VersionedTypeRegistrationDeserializer::new(self.0))?
+                {
+                    versions.insert(registration.type_id(), version);
+                }
+                Ok(versions)
+            }
+        }
+
+        deserializer.deserialize_seq(TypeVersionTableVisitor(self.registry))
+    }
+}
@@ -1,8 +1,11 @@
+pub use compact::*;
 pub use deserialize_with_registry::*;
 pub use deserializer::*;
+pub use migrations::*;
 pub use registrations::*;
 
 mod arrays;
+mod compact;
 mod deserialize_with_registry;
 mod deserializer;
 mod enums;
@@ -10,6 +13,7 @@ mod error_utils;
 mod helpers;
 mod lists;
 mod maps;
+mod migrations;
 mod options;
 mod registration_utils;
 mod registrations;
@@ -275,7 +279,8 @@ mod tests {
         let mut registry = get_registry();
         registry.register::<Foo>();
         let registration = registry.get(TypeId::of::<Foo>()).unwrap();
-        let reflect_deserializer = TypedReflectDeserializer::new_internal(registration, &registry);
+        let reflect_deserializer =
+            TypedReflectDeserializer::new_internal(registration, &registry, None);
         let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
         let dynamic_output = reflect_deserializer
             .deserialize(&mut ron_deserializer)
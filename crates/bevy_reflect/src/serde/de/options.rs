@@ -1,7 +1,7 @@
 use crate::{
     serde::{
         de::{error_utils::make_custom_error, registration_utils::try_get_registration},
-        TypedReflectDeserializer,
+        TypeVersionTable, TypedReflectDeserializer,
     },
     DynamicEnum, DynamicTuple, EnumInfo, TypeRegistry, VariantInfo,
 };
@@ -12,13 +12,19 @@ use serde::de::{DeserializeSeed, Error, Visitor};
 pub(super) struct OptionVisitor<'a> {
     enum_info: &'static EnumInfo,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> OptionVisitor<'a> {
-    pub fn new(enum_info: &'static EnumInfo, registry: &'a TypeRegistry) -> Self {
+    pub fn new(
+        enum_info: &'static EnumInfo,
+        registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
+    ) -> Self {
         Self {
             enum_info,
             registry,
+            versions,
         }
     }
 }
@@ -49,7 +55,11 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
             VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
                 let field = tuple_info.field_at(0).unwrap();
                 let registration = try_get_registration(*field.ty(), self.registry)?;
-                let de = TypedReflectDeserializer::new_internal(registration, self.registry);
+                let de = TypedReflectDeserializer::new_internal(
+                    registration,
+                    self.registry,
+                    self.versions,
+                );
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
                 let mut option = DynamicEnum::default();
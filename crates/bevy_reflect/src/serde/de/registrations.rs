@@ -49,3 +49,78 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
         deserializer.deserialize_str(TypeRegistrationVisitor(self.registry))
     }
 }
+
+/// A deserializer for type registrations and the version of their data.
+///
+/// This will return a [`&TypeRegistration`] corresponding to the given type,
+/// along with the version the data was written at.
+/// This deserializer expects a string containing the _full_ [type path] of the type,
+/// or one of its [aliases], optionally followed by an `@` and its [version].
+/// Type paths without a version are at version `0`.
+///
+/// [`&TypeRegistration`]: TypeRegistration
+/// [type path]: crate::TypePath::type_path
+/// [aliases]: crate::serde::TypeMigrations::with_alias
+/// [version]: crate::serde::TypeVersion
+pub struct VersionedTypeRegistrationDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> VersionedTypeRegistrationDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for VersionedTypeRegistrationDeserializer<'a> {
+    type Value = (&'a TypeRegistration, u32);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct VersionedTypeRegistrationVisitor<'a>(&'a TypeRegistry);
+
+        impl<'de, 'a> Visitor<'de> for VersionedTypeRegistrationVisitor<'a> {
+            type Value = (&'a TypeRegistration, u32);
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("string containing `type` entry for the reflected value")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                get_versioned_registration(self.0, value)
+            }
+        }
+
+        deserializer.deserialize_str(VersionedTypeRegistrationVisitor(self.registry))
+    }
+}
+
+/// Returns the registration of the type whose versioned type path is `value`,
+/// along with the version.
+pub(super) fn get_versioned_registration<'a, E: Error>(
+    registry: &'a TypeRegistry,
+    value: &str,
+) -> Result<(&'a TypeRegistration, u32), E> {
+    let (type_path, version) = match value.rsplit_once('@') {
+        Some((type_path, version)) => {
+            let version = version.parse().map_err(|_| {
+                make_custom_error(format_args!(
+                    "invalid version `{version}` for `{type_path}`"
+                ))
+            })?;
+            (type_path, version)
+        }
+        None => (value, 0),
+    };
+    let registration = registry
+        .get_with_type_path_alias(type_path)
+        .ok_or_else(|| {
+            make_custom_error(format_args!("no registration found for `{type_path}`"))
+        })?;
+    Ok((registration, version))
+}
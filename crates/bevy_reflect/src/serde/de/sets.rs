@@ -1,5 +1,7 @@
 use crate::{
-    serde::{de::registration_utils::try_get_registration, TypedReflectDeserializer},
+    serde::{
+        de::registration_utils::try_get_registration, TypeVersionTable, TypedReflectDeserializer,
+    },
     DynamicSet, Set, SetInfo, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
@@ -11,11 +13,20 @@ use serde::de::{SeqAccess, Visitor};
 pub(super) struct SetVisitor<'a> {
     set_info: &'static SetInfo,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> SetVisitor<'a> {
-    pub fn new(set_info: &'static SetInfo, registry: &'a TypeRegistry) -> Self {
-        Self { set_info, registry }
+    pub fn new(
+        set_info: &'static SetInfo,
+        registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
+    ) -> Self {
+        Self {
+            set_info,
+            registry,
+            versions,
+        }
     }
 }
 
@@ -35,6 +46,7 @@ impl<'a, 'de> Visitor<'de> for SetVisitor<'a> {
         while let Some(value) = set.next_element_seed(TypedReflectDeserializer::new_internal(
             value_registration,
             self.registry,
+            self.versions,
         ))? {
             dynamic_set.insert_boxed(value);
         }
@@ -5,7 +5,7 @@ use crate::{
             helpers::{ExpectedValues, Ident},
             registration_utils::try_get_registration,
         },
-        SerializationData, TypedReflectDeserializer,
+        SerializationData, TypeVersionTable, TypedReflectDeserializer,
     },
     DynamicStruct, NamedField, StructInfo, StructVariantInfo, TypeRegistration, TypeRegistry,
 };
@@ -88,6 +88,7 @@ pub(super) fn visit_struct<'de, T, V>(
     info: &'static T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    versions: Option<&TypeVersionTable>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
@@ -107,6 +108,7 @@ where
         let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
             registration,
             registry,
+            versions,
         ))?;
         dynamic_struct.insert_boxed(&key, value);
     }
@@ -134,6 +136,7 @@ pub(super) fn visit_struct_seq<'de, T, V>(
     info: &T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    versions: Option<&TypeVersionTable>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
@@ -167,6 +170,7 @@ where
             .next_element_seed(TypedReflectDeserializer::new_internal(
                 try_get_registration(*info.field_at(index)?.ty(), registry)?,
                 registry,
+                versions,
             ))?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         dynamic_struct.insert_boxed(name, value);
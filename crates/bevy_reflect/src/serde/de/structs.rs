@@ -1,5 +1,8 @@
 use crate::{
-    serde::de::struct_utils::{visit_struct, visit_struct_seq},
+    serde::{
+        de::struct_utils::{visit_struct, visit_struct_seq},
+        TypeVersionTable,
+    },
     DynamicStruct, StructInfo, TypeRegistration, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
@@ -12,6 +15,7 @@ pub(super) struct StructVisitor<'a> {
     struct_info: &'static StructInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> StructVisitor<'a> {
@@ -19,11 +23,13 @@ impl<'a> StructVisitor<'a> {
         struct_info: &'static StructInfo,
         registration: &'a TypeRegistration,
         registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
     ) -> Self {
         Self {
             struct_info,
             registration,
             registry,
+            versions,
         }
     }
 }
@@ -39,13 +45,25 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.versions,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.versions,
+        )
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    serde::{de::tuple_utils::visit_tuple, SerializationData},
+    serde::{de::tuple_utils::visit_tuple, SerializationData, TypeVersionTable},
     DynamicTupleStruct, TupleStructInfo, TypeRegistration, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
@@ -14,6 +14,7 @@ pub(super) struct TupleStructVisitor<'a> {
     tuple_struct_info: &'static TupleStructInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> TupleStructVisitor<'a> {
@@ -21,11 +22,13 @@ impl<'a> TupleStructVisitor<'a> {
         tuple_struct_info: &'static TupleStructInfo,
         registration: &'a TypeRegistration,
         registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
     ) -> Self {
         Self {
             tuple_struct_info,
             registration,
             registry,
+            versions,
         }
     }
 }
@@ -46,6 +49,7 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
             self.tuple_struct_info,
             self.registration,
             self.registry,
+            self.versions,
         )
         .map(DynamicTupleStruct::from)
     }
@@ -71,7 +75,7 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
             self.registry,
         )?;
         let reflect_deserializer =
-            TypedReflectDeserializer::new_internal(registration, self.registry);
+            TypedReflectDeserializer::new_internal(registration, self.registry, self.versions);
         let value = reflect_deserializer.deserialize(deserializer)?;
 
         tuple.insert_boxed(value.into_partial_reflect());
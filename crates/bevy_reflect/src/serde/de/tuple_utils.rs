@@ -1,7 +1,7 @@
 use crate::{
     serde::{
         de::{error_utils::make_custom_error, registration_utils::try_get_registration},
-        SerializationData, TypedReflectDeserializer,
+        SerializationData, TypeVersionTable, TypedReflectDeserializer,
     },
     DynamicTuple, TupleInfo, TupleStructInfo, TupleVariantInfo, TypeRegistration, TypeRegistry,
     UnnamedField,
@@ -69,6 +69,7 @@ pub(super) fn visit_tuple<'de, T, V>(
     info: &T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    versions: Option<&TypeVersionTable>,
 ) -> Result<DynamicTuple, V::Error>
 where
     T: TupleLikeInfo,
@@ -95,6 +96,7 @@ where
             .next_element_seed(TypedReflectDeserializer::new_internal(
                 try_get_registration(*info.field_at(index)?.ty(), registry)?,
                 registry,
+                versions,
             ))?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         tuple.insert_boxed(value);
@@ -1,5 +1,6 @@
 use crate::{
-    serde::de::tuple_utils::visit_tuple, DynamicTuple, TupleInfo, TypeRegistration, TypeRegistry,
+    serde::{de::tuple_utils::visit_tuple, TypeVersionTable},
+    DynamicTuple, TupleInfo, TypeRegistration, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
 use serde::de::{SeqAccess, Visitor};
@@ -11,6 +12,7 @@ pub(super) struct TupleVisitor<'a> {
     tuple_info: &'static TupleInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    versions: Option<&'a TypeVersionTable>,
 }
 
 impl<'a> TupleVisitor<'a> {
@@ -18,11 +20,13 @@ impl<'a> TupleVisitor<'a> {
         tuple_info: &'static TupleInfo,
         registration: &'a TypeRegistration,
         registry: &'a TypeRegistry,
+        versions: Option<&'a TypeVersionTable>,
     ) -> Self {
         Self {
             tuple_info,
             registration,
             registry,
+            versions,
         }
     }
 }
@@ -38,6 +42,12 @@ impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.registration,
+            self.registry,
+            self.versions,
+        )
     }
 }
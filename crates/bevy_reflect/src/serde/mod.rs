@@ -1,18 +1,21 @@
 mod de;
 mod ser;
 mod type_data;
+mod version;
 
 pub use de::*;
 pub use ser::*;
 pub use type_data::*;
+pub use version::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        self as bevy_reflect, type_registry::TypeRegistry, DynamicStruct, DynamicTupleStruct,
-        FromReflect, PartialReflect, Reflect, Struct,
+        self as bevy_reflect, type_registry::TypeRegistry, DynamicStruct, DynamicTupleStruct, Enum,
+        FromReflect, GetField, PartialReflect, Reflect, Struct,
     };
+    use bincode::Options;
     use serde::de::DeserializeSeed;
 
     #[test]
@@ -183,6 +186,433 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn should_roundtrip_compact() {
+        use bevy_utils::HashMap;
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        struct Player {
+            name: String,
+            position: (f32, f32),
+            state: State,
+            inventory: Vec<Item>,
+            stats: HashMap<u8, u32>,
+            #[reflect(skip_serializing)]
+            cached: u32,
+        }
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        struct Item(u32, [u8; 2]);
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        enum State {
+            Idle,
+            Walking { speed: f32 },
+            Carrying(Option<Item>),
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+
+        let value = Player {
+            name: String::from("Ferris"),
+            position: (1.0, 2.0),
+            state: State::Carrying(Some(Item(3, [4, 5]))),
+            inventory: vec![Item(1, [0, 1]), Item(2, [1, 0])],
+            stats: HashMap::from_iter([(1, 10)]),
+            cached: 7,
+        };
+        let expected = Player {
+            cached: 0,
+            ..value.clone()
+        };
+
+        let serializer = CompactReflectSerializer::new(&value, &registry);
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(CompactReflectDeserializer::new(&registry), &bytes)
+            .unwrap();
+        assert_eq!(Player::from_reflect(&*output).unwrap(), expected);
+
+        // Types are written as stable ids rather than type paths.
+        registry.register::<Vec<Player>>();
+        let values = vec![expected; 8];
+        let options = bincode::DefaultOptions::new();
+        let compact = options
+            .serialize(&CompactReflectSerializer::new(&values, &registry))
+            .unwrap();
+        let full = options
+            .serialize(&ReflectSerializer::new(&values, &registry))
+            .unwrap();
+        assert!(!compact.windows(6).any(|bytes| bytes == b"Player"));
+        assert!(
+            compact.len() < full.len(),
+            "{} >= {}",
+            compact.len(),
+            full.len()
+        );
+
+        let ron = ron::to_string(&CompactReflectSerializer::new(&values, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+        let output = CompactReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Vec::<Player>::from_reflect(&*output).unwrap(), values);
+    }
+
+    #[test]
+    fn should_not_write_compact_data_with_ambiguous_stable_type_ids() {
+        mod a {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub struct Player(pub u32);
+        }
+
+        mod b {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub struct Player(pub u32);
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<a::Player>();
+        registry.register::<b::Player>();
+
+        let error = bincode::serialize(&CompactReflectSerializer::new(&a::Player(1), &registry))
+            .unwrap_err();
+        assert!(error.to_string().starts_with(
+            "the stable type id of type `game::Player` is shared with another registered type"
+        ));
+    }
+
+    #[test]
+    fn should_read_compact_data_with_appended_fields() {
+        mod v1 {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub struct Player {
+                pub health: u32,
+                pub state: State,
+            }
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub enum State {
+                Idle,
+                Walking(f32),
+            }
+        }
+
+        mod v2 {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect, Debug, PartialEq)]
+            #[type_path = "game"]
+            pub struct Player {
+                pub health: u32,
+                pub state: State,
+                #[reflect(default = "max_health")]
+                pub max_health: u32,
+            }
+
+            fn max_health() -> u32 {
+                100
+            }
+
+            #[derive(Reflect, Debug, PartialEq)]
+            #[type_path = "game"]
+            pub enum State {
+                Idle,
+                Walking(f32, #[reflect(default)] bool),
+                Running,
+            }
+        }
+
+        let mut old_registry = TypeRegistry::default();
+        old_registry.register::<v1::Player>();
+        let mut new_registry = TypeRegistry::default();
+        new_registry.register::<v2::Player>();
+
+        let old = v1::Player {
+            health: 20,
+            state: v1::State::Walking(1.5),
+        };
+        let bytes =
+            bincode::serialize(&CompactReflectSerializer::new(&old, &old_registry)).unwrap();
+
+        let output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(CompactReflectDeserializer::new(&new_registry), &bytes)
+            .unwrap();
+        assert_eq!(
+            v2::Player::from_reflect(&*output).unwrap(),
+            v2::Player {
+                health: 20,
+                state: v2::State::Walking(1.5, false),
+                max_health: 100,
+            }
+        );
+
+        // Data written with more fields than the type has can't be read.
+        let new = v2::Player::from_reflect(&*output).unwrap();
+        let bytes =
+            bincode::serialize(&CompactReflectSerializer::new(&new, &new_registry)).unwrap();
+        let result = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(CompactReflectDeserializer::new(&old_registry), &bytes);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_migrate_older_data() {
+        mod v0 {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub struct Hero {
+                pub hp: u32,
+                pub mana: u32,
+            }
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub enum State {
+                Idle,
+                Walk { speed: f32 },
+            }
+        }
+
+        mod v2 {
+            use crate::{self as bevy_reflect, serde::TypeVersion, Reflect};
+
+            #[derive(Reflect, Debug, PartialEq)]
+            #[type_path = "game"]
+            #[reflect(@TypeVersion(2))]
+            pub struct Player {
+                pub health: u32,
+                pub level: u32,
+            }
+
+            #[derive(Reflect, Debug, PartialEq)]
+            #[type_path = "game"]
+            #[reflect(@TypeVersion(1))]
+            pub enum State {
+                Idle,
+                Walking { speed: f32 },
+            }
+        }
+
+        let mut old_registry = TypeRegistry::default();
+        old_registry.register::<v0::Hero>();
+        old_registry.register::<v0::State>();
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<v2::Player>();
+        registry.register::<v2::State>();
+        registry.register_migrations::<v2::Player>(
+            TypeMigrations::new()
+                .with_alias("game::Hero")
+                .with_migration(Migration::new(1).map_struct(|player| {
+                    let health = player.get_field::<u32>("health").copied().unwrap();
+                    player.insert("level", health / 10);
+                }))
+                .with_migration(Migration::new(0).rename_field("hp", "health"))
+                .with_migration(Migration::new(0).remove_field("mana")),
+        );
+        registry.register_migrations::<v2::State>(
+            TypeMigrations::new().with_migration(
+                Migration::new(0)
+                    .rename_variant("Walk", "Walking")
+                    .map_enum(|state| {
+                        if let Some(speed) = state.field_mut("speed") {
+                            *speed.try_downcast_mut::<f32>().unwrap() *= 2.0;
+                        }
+                    }),
+            ),
+        );
+
+        let roundtrip = |value: &dyn PartialReflect| {
+            let ron = ron::to_string(&ReflectSerializer::new(value, &old_registry)).unwrap();
+            let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+            ReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap()
+        };
+
+        let output = roundtrip(&v0::Hero { hp: 30, mana: 5 });
+        assert_eq!(
+            v2::Player::from_reflect(&*output).unwrap(),
+            v2::Player {
+                health: 30,
+                level: 3,
+            }
+        );
+
+        let output = roundtrip(&v0::State::Walk { speed: 1.5 });
+        assert_eq!(
+            v2::State::from_reflect(&*output).unwrap(),
+            v2::State::Walking { speed: 3.0 }
+        );
+
+        // The current version is written with the data, so it's not migrated again.
+        let value = v2::Player {
+            health: 30,
+            level: 7,
+        };
+        let ron = ron::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+        assert_eq!(ron, r#"{"game::Player@2":(health:30,level:7)}"#);
+        let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(v2::Player::from_reflect(&*output).unwrap(), value);
+
+        // Data written by a newer version can't be read.
+        let ron = r#"{"game::Player@3":(health:30,level:7)}"#;
+        let mut deserializer = ron::Deserializer::from_str(ron).unwrap();
+        let error = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(error.to_string().starts_with(
+            "`game::Player` data was written at version 3, but the current version is 2"
+        ));
+    }
+
+    #[test]
+    fn should_migrate_nested_versioned_values() {
+        use crate::serde::{Migration, TypeMigrations, TypeVersion};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        struct Team {
+            leader: Player,
+            members: Vec<Player>,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        #[reflect(@TypeVersion(1))]
+        struct Player {
+            health: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Team>();
+        registry.register_migrations::<Player>(
+            TypeMigrations::new().with_migration(Migration::new(0).rename_field("hp", "health")),
+        );
+        let deserialize = |ron: &str| {
+            let mut deserializer = ron::Deserializer::from_str(ron).unwrap();
+            ReflectDeserializer::new(&registry).deserialize(&mut deserializer)
+        };
+
+        let value = Team {
+            leader: Player { health: 10 },
+            members: vec![Player { health: 5 }],
+        };
+
+        // The versions of nested types are written before the value.
+        let ron = ron::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+        assert_eq!(
+            ron,
+            r#"{"$versions":["game::Player@1"],"game::Team":(leader:(health:10),members:[(health:5)])}"#
+        );
+        let output = deserialize(&ron).unwrap();
+        assert_eq!(Team::from_reflect(&*output).unwrap(), value);
+
+        // Nested types missing from the table are at version 0.
+        let output = deserialize(r#"{"game::Team":(leader:(hp:10),members:[(hp:5)])}"#).unwrap();
+        assert_eq!(Team::from_reflect(&*output).unwrap(), value);
+
+        let output = deserialize(
+            r#"{"$versions":["game::Player@0"],"game::Team":(leader:(hp:10),members:[(hp:5)])}"#,
+        )
+        .unwrap();
+        assert_eq!(Team::from_reflect(&*output).unwrap(), value);
+
+        let error = deserialize(
+            r#"{"$versions":["game::Player@2"],"game::Team":(leader:(health:10),members:[])}"#,
+        )
+        .unwrap_err();
+        assert!(
+            error.to_string().contains(
+                "`game::Player` data was written at version 2, but the current version is 1"
+            ),
+            "{error}"
+        );
+
+        // Values without nested versioned types are written as a single entry.
+        let ron =
+            ron::to_string(&ReflectSerializer::new(&Player { health: 10 }, &registry)).unwrap();
+        assert_eq!(ron, r#"{"game::Player@1":(health:10)}"#);
+    }
+
+    #[test]
+    fn should_migrate_nested_versioned_values_in_binary_formats() {
+        use crate::serde::{Migration, TypeMigrations, TypeVersion};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        struct Team {
+            leader: Player,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        #[reflect(@TypeVersion(1))]
+        struct Player {
+            health: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Team>();
+        registry.register_migrations::<Player>(TypeMigrations::new().with_migration(
+            Migration::new(0).map_struct(|player| {
+                let health = *player.get_field::<u32>("health").unwrap();
+                player.insert("health", health * 10);
+            }),
+        ));
+
+        let value = Team {
+            leader: Player { health: 10 },
+        };
+        let serialize = |value: &Team| {
+            bincode::DefaultOptions::new()
+                .serialize(&ReflectSerializer::new(value, &registry))
+                .unwrap()
+        };
+        let deserialize = |bytes: &[u8]| {
+            let reflect_deserializer = ReflectDeserializer::new(&registry);
+            let output = bincode::DefaultOptions::new()
+                .deserialize_seed(reflect_deserializer, bytes)
+                .unwrap();
+            Team::from_reflect(&*output).unwrap()
+        };
+
+        assert_eq!(deserialize(&serialize(&value)), value);
+
+        // Data written when `Player` was at version 0 has no version table.
+        let mut old = Vec::new();
+        let options = bincode::DefaultOptions::new();
+        old.extend(options.serialize(&1_usize).unwrap());
+        old.extend(options.serialize("game::Team").unwrap());
+        old.extend(options.serialize(&1_u32).unwrap());
+        assert_eq!(
+            deserialize(&old),
+            Team {
+                leader: Player { health: 10 }
+            }
+        );
+    }
+
     mod type_data {
         use super::*;
         use crate::from_reflect::FromReflect;
@@ -0,0 +1,408 @@
+use crate::{
+    serde::{
+        ser::{custom_serialization::try_custom_serialize, error_utils::make_custom_error},
+        SerializationData,
+    },
+    PartialReflect, ReflectRef, TypeInfo, TypeRegistry, VariantInfo,
+};
+use bevy_utils::HashMap;
+use core::any::TypeId;
+use serde::{
+    ser::{Error, SerializeMap, SerializeSeq, SerializeTuple},
+    Serialize, Serializer,
+};
+
+/// The version of the format written by [`CompactReflectSerializer`].
+///
+/// It is stored in the header of the serialized data, so that the format can evolve without
+/// breaking data written by older versions.
+pub const COMPACT_FORMAT_VERSION: u32 = 1;
+
+/// A serializer for reflected values that produces a compact output for binary formats.
+///
+/// [`ReflectSerializer`] writes the full [type path] of the value, and the field names of structs
+/// in self-describing formats. This serializer doesn't write any of those strings:
+/// types are identified by their [stable type id] in a type table at the start of the output,
+/// and are then referred to by their index in that table, while fields and variants are written in
+/// declaration order, without their names.
+///
+/// This is the serializer counterpart to [`CompactReflectDeserializer`].
+///
+/// # Output
+///
+/// This serializer outputs a tuple of three elements:
+/// 1. A header, made of the [format version] and the type table. Each entry of the type table
+///    contains the stable type id of a type and its layout: the number of fields of a struct or
+///    tuple struct, or the number of fields of each variant of an enum.
+/// 2. The index of the type of the value in the type table.
+/// 3. The serialized value. Structs, tuple structs, tuples and arrays are serialized as tuples
+///    of their fields, and enums other than [`Option`] as a tuple of their variant index and of
+///    a tuple of the fields of the variant.
+///
+/// Since the layout of each type is recorded in the header, fields and variants appended to a
+/// type after the data was written don't prevent it from being read back.
+/// See [`CompactReflectDeserializer`] for more details.
+///
+/// This format works best with a variable-length encoding of integers, since most ids, lengths
+/// and indices are small.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{CompactReflectSerializer, ReflectSerializer}};
+/// # use bincode::Options;
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct MyStruct {
+///   value: i32
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+///
+/// let input = MyStruct { value: 123 };
+///
+/// let reflect_serializer = CompactReflectSerializer::new(&input, &registry);
+/// let output = bincode::DefaultOptions::new().serialize(&reflect_serializer).unwrap();
+///
+/// let reflect_serializer = ReflectSerializer::new(&input, &registry);
+/// let full_output = bincode::DefaultOptions::new().serialize(&reflect_serializer).unwrap();
+/// assert!(output.len() < full_output.len());
+/// ```
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [type path]: crate::TypePath::type_path
+/// [stable type id]: crate::TypeRegistration::stable_type_id
+/// [`CompactReflectDeserializer`]: crate::serde::CompactReflectDeserializer
+/// [format version]: COMPACT_FORMAT_VERSION
+pub struct CompactReflectSerializer<'a> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    pub fn new(value: &'a dyn PartialReflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+}
+
+impl<'a> Serialize for CompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut table = TypeTable::default();
+        let root_id = table.insert(represented_type_info(self.value)?, self.registry)?;
+        table.collect(self.value, self.registry)?;
+
+        let mut state = serializer.serialize_tuple(3)?;
+        state.serialize_element(&(COMPACT_FORMAT_VERSION, &table))?;
+        state.serialize_element(&root_id)?;
+        state.serialize_element(&CompactValueSerializer {
+            value: self.value,
+            registry: self.registry,
+        })?;
+        state.end()
+    }
+}
+
+/// The types whose layout is written in the header, in the order of their ids.
+#[derive(Default)]
+struct TypeTable {
+    types: Vec<(u64, &'static TypeInfo)>,
+    ids: HashMap<TypeId, u32>,
+}
+
+impl TypeTable {
+    /// Adds a type to the table if it isn't in it yet, and returns its id.
+    fn insert<E: Error>(
+        &mut self,
+        info: &'static TypeInfo,
+        registry: &TypeRegistry,
+    ) -> Result<u32, E> {
+        if let Some(id) = self.ids.get(&info.type_id()) {
+            return Ok(*id);
+        }
+        let registration = registry.get(info.type_id()).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "type `{}` is not registered in the type registry",
+                info.type_path(),
+            ))
+        })?;
+        let stable_type_id = registration.stable_type_id();
+        if registry.is_stable_type_id_ambiguous(stable_type_id) {
+            return Err(make_custom_error(format_args!(
+                "the stable type id of type `{}` is shared with another registered type",
+                info.type_path(),
+            )));
+        }
+        let id = self.types.len() as u32;
+        self.types.push((stable_type_id, info));
+        self.ids.insert(info.type_id(), id);
+        Ok(id)
+    }
+
+    /// Adds the types of `value` and its fields whose layout can change to the table.
+    fn collect<E: Error>(
+        &mut self,
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> Result<(), E> {
+        let Some(info) = value.get_represented_type_info() else {
+            return Ok(());
+        };
+        if has_custom_serialization(info, registry) {
+            return Ok(());
+        }
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                self.insert(info, registry)?;
+                for field in value.iter_fields() {
+                    self.collect(field, registry)?;
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                self.insert(info, registry)?;
+                for field in value.iter_fields() {
+                    self.collect(field, registry)?;
+                }
+            }
+            ReflectRef::Enum(value) => {
+                if !is_option(info) {
+                    self.insert(info, registry)?;
+                }
+                for field in value.iter_fields() {
+                    self.collect(field.value(), registry)?;
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                for field in value.iter_fields() {
+                    self.collect(field, registry)?;
+                }
+            }
+            ReflectRef::Array(value) => {
+                for item in value.iter() {
+                    self.collect(item, registry)?;
+                }
+            }
+            ReflectRef::List(value) => {
+                for item in value.iter() {
+                    self.collect(item, registry)?;
+                }
+            }
+            ReflectRef::Set(value) => {
+                for item in value.iter() {
+                    self.collect(item, registry)?;
+                }
+            }
+            ReflectRef::Map(value) => {
+                for (key, value) in value.iter() {
+                    self.collect(key, registry)?;
+                    self.collect(value, registry)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for TypeTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.types.len()))?;
+        for (stable_type_id, info) in &self.types {
+            let layout: Vec<u32> = match info {
+                TypeInfo::Struct(info) => vec![info.field_len() as u32],
+                TypeInfo::TupleStruct(info) => vec![info.field_len() as u32],
+                TypeInfo::Enum(info) => info.iter().map(variant_len).collect(),
+                _ => Vec::new(),
+            };
+            state.serialize_element(&(stable_type_id, layout))?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes a value whose type layout is in the type table.
+struct CompactValueSerializer<'a> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactValueSerializer<'a> {
+    fn field(&self, value: &'a dyn PartialReflect) -> Self {
+        Self {
+            value,
+            registry: self.registry,
+        }
+    }
+}
+
+impl<'a> Serialize for CompactValueSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (serializer, error) = match try_custom_serialize(self.value, self.registry, serializer)
+        {
+            Ok(result) => return result,
+            Err(value) => value,
+        };
+
+        let type_info = represented_type_info(self.value)?;
+        let serialization_data = self
+            .registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<SerializationData>());
+        let is_skipped =
+            |index| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        match (self.value.reflect_ref(), type_info) {
+            (ReflectRef::Struct(value), TypeInfo::Struct(info)) => {
+                let len = (0..info.field_len()).filter(|index| !is_skipped(*index));
+                let mut state = serializer.serialize_tuple(len.count())?;
+                for (index, field) in info.iter().enumerate() {
+                    if is_skipped(index) {
+                        continue;
+                    }
+                    let value = value.field(field.name()).ok_or_else(|| {
+                        make_custom_error(format_args!(
+                            "missing field `{}` in `{}`",
+                            field.name(),
+                            info.type_path()
+                        ))
+                    })?;
+                    state.serialize_element(&self.field(value))?;
+                }
+                state.end()
+            }
+            (ReflectRef::TupleStruct(value), TypeInfo::TupleStruct(info)) => {
+                let len = (0..info.field_len()).filter(|index| !is_skipped(*index));
+                let mut state = serializer.serialize_tuple(len.count())?;
+                for index in 0..info.field_len() {
+                    if is_skipped(index) {
+                        continue;
+                    }
+                    let value = value.field(index).ok_or_else(|| {
+                        make_custom_error(format_args!(
+                            "missing field {index} in `{}`",
+                            info.type_path()
+                        ))
+                    })?;
+                    state.serialize_element(&self.field(value))?;
+                }
+                state.end()
+            }
+            (ReflectRef::Enum(value), TypeInfo::Enum(info)) if is_option(type_info) => {
+                match value.field_at(0) {
+                    Some(field) => serializer.serialize_some(&self.field(field)),
+                    None => serializer.serialize_none(),
+                }
+            }
+            (ReflectRef::Enum(value), TypeInfo::Enum(info)) => {
+                let variant_index = value.variant_index();
+                let variant_len = info.variant_at(variant_index).map(variant_len);
+                if variant_len != Some(value.field_len() as u32) {
+                    return Err(make_custom_error(format_args!(
+                        "variant `{}` of `{}` doesn't match its type info",
+                        value.variant_name(),
+                        info.type_path()
+                    )));
+                }
+                let fields = FieldsSerializer {
+                    fields: value.iter_fields().map(|field| field.value()).collect(),
+                    registry: self.registry,
+                };
+                let mut state = serializer.serialize_tuple(2)?;
+                state.serialize_element(&(variant_index as u32))?;
+                state.serialize_element(&fields)?;
+                state.end()
+            }
+            (ReflectRef::Tuple(value), _) => FieldsSerializer {
+                fields: value.iter_fields().collect(),
+                registry: self.registry,
+            }
+            .serialize(serializer),
+            (ReflectRef::Array(value), _) => FieldsSerializer {
+                fields: value.iter().collect(),
+                registry: self.registry,
+            }
+            .serialize(serializer),
+            (ReflectRef::List(value), _) => {
+                serializer.collect_seq(value.iter().map(|item| self.field(item)))
+            }
+            (ReflectRef::Set(value), _) => {
+                serializer.collect_seq(value.iter().map(|item| self.field(item)))
+            }
+            (ReflectRef::Map(value), _) => {
+                let mut state = serializer.serialize_map(Some(value.len()))?;
+                for (key, value) in value.iter() {
+                    state.serialize_entry(&self.field(key), &self.field(value))?;
+                }
+                state.end()
+            }
+            (ReflectRef::Opaque(_), _) => Err(error),
+            _ => Err(make_custom_error(format_args!(
+                "`{}` doesn't match its type info",
+                self.value.reflect_type_path()
+            ))),
+        }
+    }
+}
+
+/// Serializes a fixed number of values as a tuple.
+struct FieldsSerializer<'a> {
+    fields: Vec<&'a dyn PartialReflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for FieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(self.fields.len())?;
+        for value in &self.fields {
+            state.serialize_element(&CompactValueSerializer {
+                value: *value,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+fn represented_type_info<E: Error>(value: &dyn PartialReflect) -> Result<&'static TypeInfo, E> {
+    value.get_represented_type_info().ok_or_else(|| {
+        make_custom_error(format_args!(
+            "cannot get type info for `{}`",
+            value.reflect_type_path()
+        ))
+    })
+}
+
+fn has_custom_serialization(info: &TypeInfo, registry: &TypeRegistry) -> bool {
+    registry.get(info.type_id()).is_some_and(|registration| {
+        registration.data::<crate::ReflectSerialize>().is_some()
+            || registration
+                .data::<crate::serde::ReflectSerializeWithRegistry>()
+                .is_some()
+    })
+}
+
+fn is_option(info: &TypeInfo) -> bool {
+    info.type_path_table().module_path() == Some("core::option")
+        && info.type_path_table().ident() == Some("Option")
+}
+
+fn variant_len(variant: &VariantInfo) -> u32 {
+    match variant {
+        VariantInfo::Struct(info) => info.field_len() as u32,
+        VariantInfo::Tuple(info) => info.field_len() as u32,
+        VariantInfo::Unit(_) => 0,
+    }
+}
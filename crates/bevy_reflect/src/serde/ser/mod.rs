@@ -1,8 +1,10 @@
+pub use compact::*;
 pub use serializable::*;
 pub use serialize_with_registry::*;
 pub use serializer::*;
 
 mod arrays;
+mod compact;
 mod custom_serialization;
 mod enums;
 mod error_utils;
@@ -7,6 +7,7 @@ use crate::{
         sets::SetSerializer, structs::StructSerializer, tuple_structs::TupleStructSerializer,
         tuples::TupleSerializer,
     },
+    serde::{TypeVersion, TypeVersionTable},
     PartialReflect, ReflectRef, TypeRegistry,
 };
 use serde::{ser::SerializeMap, Serialize};
@@ -23,6 +24,10 @@ use serde::{ser::SerializeMap, Serialize};
 /// where the key is the _full_ [type path] of the reflected type
 /// and the value is the serialized data.
 ///
+/// If the type declares a [`TypeVersion`], the type path is followed by an `@` and the version.
+/// If the value contains versioned types, such as the types of its fields,
+/// their versioned type paths are first written in a sequence under the [`TypeVersionTable::KEY`] key.
+///
 /// # Example
 ///
 /// ```
@@ -47,6 +52,8 @@ use serde::{ser::SerializeMap, Serialize};
 ///
 /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
 /// [type path]: crate::TypePath::type_path
+/// [`TypeVersion`]: crate::serde::TypeVersion
+/// [`TypeVersionTable::KEY`]: crate::serde::TypeVersionTable::KEY
 pub struct ReflectSerializer<'a> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
@@ -63,24 +70,34 @@ impl<'a> Serialize for ReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(1))?;
+        let type_info = self.value.get_represented_type_info().ok_or_else(|| {
+            if self.value.is_dynamic() {
+                make_custom_error(format_args!(
+                    "cannot serialize dynamic value without represented type: `{}`",
+                    self.value.reflect_type_path()
+                ))
+            } else {
+                make_custom_error(format_args!(
+                    "cannot get type info for `{}`",
+                    self.value.reflect_type_path()
+                ))
+            }
+        })?;
+
+        let nested_versions = TypeVersion::nested_types(self.value);
+        let mut state = if nested_versions.is_empty() {
+            serializer.serialize_map(Some(1))?
+        } else {
+            let mut state = serializer.serialize_map(Some(2))?;
+            let nested_versions = nested_versions
+                .into_iter()
+                .map(TypeVersion::versioned_type_path)
+                .collect::<Vec<_>>();
+            state.serialize_entry(TypeVersionTable::KEY, &nested_versions)?;
+            state
+        };
         state.serialize_entry(
-            self.value
-                .get_represented_type_info()
-                .ok_or_else(|| {
-                    if self.value.is_dynamic() {
-                        make_custom_error(format_args!(
-                            "cannot serialize dynamic value without represented type: `{}`",
-                            self.value.reflect_type_path()
-                        ))
-                    } else {
-                        make_custom_error(format_args!(
-                            "cannot get type info for `{}`",
-                            self.value.reflect_type_path()
-                        ))
-                    }
-                })?
-                .type_path(),
+            &TypeVersion::versioned_type_path(type_info),
             &TypedReflectSerializer::new(self.value, self.registry),
         )?;
         state.end()
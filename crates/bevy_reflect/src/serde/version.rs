@@ -0,0 +1,346 @@
+use crate as bevy_reflect;
+use crate::{DynamicEnum, DynamicStruct, PartialReflect, Reflect, ReflectRef, TypeInfo};
+use alloc::{borrow::Cow, format, sync::Arc};
+use bevy_utils::TypeIdMap;
+use core::any::TypeId;
+
+/// A [custom attribute] declaring the version of a type's serialized data.
+///
+/// Types without this attribute are at version `0`.
+/// When a versioned type is serialized with the [`ReflectSerializer`],
+/// its version is written after its type path, separated by an `@` (e.g. `"game::Player@2"`).
+/// The [`ReflectDeserializer`] uses it to run the type's [`TypeMigrations`]
+/// when reading data written by an older version.
+///
+/// The versions of the versioned types nested in the value, such as the types of its fields,
+/// are written in a [`TypeVersionTable`] before the value, so they are migrated as well.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, serde::TypeVersion};
+/// #[derive(Reflect)]
+/// #[reflect(@TypeVersion(2))]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// assert_eq!(TypeVersion::of(Player::type_info()), 2);
+/// ```
+///
+/// [custom attribute]: crate::attributes::CustomAttributes
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeVersion(pub u32);
+
+impl TypeVersion {
+    /// Returns the version declared by the given type, or `0` if it doesn't declare one.
+    pub fn of(type_info: &TypeInfo) -> u32 {
+        let version = match type_info {
+            TypeInfo::Struct(info) => info.get_attribute::<TypeVersion>(),
+            TypeInfo::TupleStruct(info) => info.get_attribute::<TypeVersion>(),
+            TypeInfo::Enum(info) => info.get_attribute::<TypeVersion>(),
+            _ => None,
+        };
+        version.map_or(0, |version| version.0)
+    }
+
+    /// Returns the type path of the given type, followed by its version if it declares one.
+    pub fn versioned_type_path(type_info: &TypeInfo) -> Cow<'static, str> {
+        match Self::of(type_info) {
+            0 => Cow::Borrowed(type_info.type_path()),
+            version => Cow::Owned(format!("{}@{version}", type_info.type_path())),
+        }
+    }
+
+    /// Returns the versioned types of the values nested in `value`, sorted by type path.
+    ///
+    /// These are the types listed in the [`TypeVersionTable`] written by the [`ReflectSerializer`].
+    ///
+    /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+    pub fn nested_types(value: &dyn PartialReflect) -> Vec<&'static TypeInfo> {
+        fn visit(value: &dyn PartialReflect, types: &mut Vec<&'static TypeInfo>) {
+            let mut visit_nested = |nested: &dyn PartialReflect| {
+                if let Some(type_info) = nested.get_represented_type_info() {
+                    if TypeVersion::of(type_info) != 0
+                        && !types
+                            .iter()
+                            .any(|other| other.type_id() == type_info.type_id())
+                    {
+                        types.push(type_info);
+                    }
+                }
+                visit(nested, types);
+            };
+
+            match value.reflect_ref() {
+                ReflectRef::Struct(value) => value.iter_fields().for_each(visit_nested),
+                ReflectRef::TupleStruct(value) => value.iter_fields().for_each(visit_nested),
+                ReflectRef::Tuple(value) => value.iter_fields().for_each(visit_nested),
+                ReflectRef::List(value) => value.iter().for_each(visit_nested),
+                ReflectRef::Array(value) => value.iter().for_each(visit_nested),
+                ReflectRef::Map(value) => value.iter().for_each(|(key, value)| {
+                    visit_nested(key);
+                    visit_nested(value);
+                }),
+                ReflectRef::Set(value) => value.iter().for_each(visit_nested),
+                ReflectRef::Enum(value) => value
+                    .iter_fields()
+                    .for_each(|field| visit_nested(field.value())),
+                _ => {}
+            }
+        }
+
+        let mut types = Vec::new();
+        visit(value, &mut types);
+        types.sort_by_key(|type_info| type_info.type_path());
+        types
+    }
+}
+
+/// The versions at which the types nested in a serialized value were written.
+///
+/// The [`ReflectSerializer`] writes this table as an entry before the value,
+/// under the [`KEY`](Self::KEY) key, listing the versioned types found in the value
+/// (e.g. `"$versions": ["game::Player@2"]`).
+/// The [`ReflectDeserializer`] then uses it to migrate nested values written by older versions.
+///
+/// Types missing from the table are at version `0`.
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeVersionTable(TypeIdMap<u32>);
+
+impl TypeVersionTable {
+    /// The key of the table in the map written by the [`ReflectSerializer`].
+    ///
+    /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+    pub const KEY: &'static str = "$versions";
+
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that the data of the given type was written at `version`.
+    pub fn insert(&mut self, type_id: TypeId, version: u32) {
+        self.0.insert(type_id, version);
+    }
+
+    /// Returns the version at which the data of the given type was written.
+    pub fn get(&self, type_id: TypeId) -> u32 {
+        self.0.get(&type_id).copied().unwrap_or_default()
+    }
+}
+
+/// Type data describing how to read data written by older versions of a type.
+///
+/// Migrations are registered with [`TypeRegistry::register_migrations`].
+/// Field and variant renames and field removals are resolved while the data is read,
+/// so removed fields never need to be deserialized.
+/// Functions added with [`Migration::map_struct`] and [`Migration::map_enum`] then run in
+/// version order on the dynamic value, before it is converted with [`FromReflect`].
+///
+/// Migrations apply to any value whose version is known, see [`TypeVersion`] and [`TypeVersionTable`].
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry, serde::{Migration, ReflectDeserializer, TypeMigrations, TypeVersion}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "game"]
+/// #[reflect(@TypeVersion(2))]
+/// struct Player {
+///     health: u32,
+///     level: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register_migrations::<Player>(
+///     TypeMigrations::new()
+///         .with_alias("game::Hero")
+///         .with_migration(Migration::new(0).rename_field("hp", "health"))
+///         .with_migration(Migration::new(1).remove_field("mana").map_struct(|player| {
+///             player.insert("level", 1_u32);
+///         })),
+/// );
+///
+/// let input = r#"{ "game::Hero": (hp: 10, mana: 5) }"#;
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let output = ReflectDeserializer::new(&registry).deserialize(&mut deserializer).unwrap();
+///
+/// let player = Player::from_reflect(&*output).unwrap();
+/// assert_eq!(player, Player { health: 10, level: 1 });
+/// ```
+///
+/// [`TypeRegistry::register_migrations`]: crate::TypeRegistry::register_migrations
+/// [`FromReflect`]: crate::FromReflect
+#[derive(Clone, Default)]
+pub struct TypeMigrations {
+    aliases: Vec<Cow<'static, str>>,
+    migrations: Vec<Migration>,
+}
+
+impl TypeMigrations {
+    /// Creates an empty set of migrations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a former type path of the type, so that data written under that path can still be read.
+    pub fn with_alias(mut self, type_path: impl Into<Cow<'static, str>>) -> Self {
+        self.aliases.push(type_path.into());
+        self
+    }
+
+    /// Adds a migration.
+    ///
+    /// Migrations run in the order of their [`from_version`](Migration::from_version),
+    /// and migrations with the same version run in the order they were added.
+    pub fn with_migration(mut self, migration: Migration) -> Self {
+        let index = self
+            .migrations
+            .partition_point(|other| other.from_version <= migration.from_version);
+        self.migrations.insert(index, migration);
+        self
+    }
+
+    /// Returns an iterator over the former type paths of the type.
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.aliases.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns the name of the field `name` of data written at `version` in the current version,
+    /// or `None` if the field was removed.
+    pub fn field_name<'a>(&'a self, version: u32, name: &'a str) -> Option<&'a str> {
+        let mut name = name;
+        for step in self.steps_since(version) {
+            match step {
+                MigrationStep::RenameField(from, to) if from == name => name = to,
+                MigrationStep::RemoveField(removed) if removed == name => return None,
+                _ => {}
+            }
+        }
+        Some(name)
+    }
+
+    /// Returns the name of the variant `name` of data written at `version` in the current version.
+    pub fn variant_name<'a>(&'a self, version: u32, name: &'a str) -> &'a str {
+        let mut name = name;
+        for step in self.steps_since(version) {
+            match step {
+                MigrationStep::RenameVariant(from, to) if from == name => name = to,
+                _ => {}
+            }
+        }
+        name
+    }
+
+    /// Runs the [`map_struct`](Migration::map_struct) functions for data written at `version`.
+    ///
+    /// The struct is expected to already use the current field names,
+    /// as returned by [`field_name`](Self::field_name).
+    pub fn migrate_struct(&self, value: &mut DynamicStruct, version: u32) {
+        for step in self.steps_since(version) {
+            if let MigrationStep::MapStruct(function) = step {
+                function(value);
+            }
+        }
+    }
+
+    /// Runs the [`map_enum`](Migration::map_enum) functions for data written at `version`.
+    ///
+    /// The enum is expected to already use the current variant name,
+    /// as returned by [`variant_name`](Self::variant_name).
+    pub fn migrate_enum(&self, value: &mut DynamicEnum, version: u32) {
+        for step in self.steps_since(version) {
+            if let MigrationStep::MapEnum(function) = step {
+                function(value);
+            }
+        }
+    }
+
+    fn steps_since(&self, version: u32) -> impl Iterator<Item = &MigrationStep> {
+        self.migrations
+            .iter()
+            .filter(move |migration| migration.from_version >= version)
+            .flat_map(|migration| &migration.steps)
+    }
+}
+
+/// The changes made to a type between one version and the next.
+///
+/// See [`TypeMigrations`] for more information.
+#[derive(Clone)]
+pub struct Migration {
+    from_version: u32,
+    steps: Vec<MigrationStep>,
+}
+
+#[derive(Clone)]
+enum MigrationStep {
+    RenameField(Cow<'static, str>, Cow<'static, str>),
+    RemoveField(Cow<'static, str>),
+    RenameVariant(Cow<'static, str>, Cow<'static, str>),
+    MapStruct(Arc<dyn Fn(&mut DynamicStruct) + Send + Sync>),
+    MapEnum(Arc<dyn Fn(&mut DynamicEnum) + Send + Sync>),
+}
+
+impl Migration {
+    /// Creates a migration from `from_version` to the version after it.
+    pub fn new(from_version: u32) -> Self {
+        Self {
+            from_version,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Returns the version this migration reads data from.
+    pub fn from_version(&self) -> u32 {
+        self.from_version
+    }
+
+    /// Renames the struct field `from` to `to`.
+    pub fn rename_field(
+        mut self,
+        from: impl Into<Cow<'static, str>>,
+        to: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.steps
+            .push(MigrationStep::RenameField(from.into(), to.into()));
+        self
+    }
+
+    /// Removes the struct field `name`, whose value will be skipped when reading older data.
+    pub fn remove_field(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.steps.push(MigrationStep::RemoveField(name.into()));
+        self
+    }
+
+    /// Renames the enum variant `from` to `to`.
+    pub fn rename_variant(
+        mut self,
+        from: impl Into<Cow<'static, str>>,
+        to: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.steps
+            .push(MigrationStep::RenameVariant(from.into(), to.into()));
+        self
+    }
+
+    /// Transforms the data of a struct, for example to fill in a new field.
+    pub fn map_struct(mut self, f: impl Fn(&mut DynamicStruct) + Send + Sync + 'static) -> Self {
+        self.steps.push(MigrationStep::MapStruct(Arc::new(f)));
+        self
+    }
+
+    /// Transforms the data of an enum, for example to change a variant's fields.
+    pub fn map_enum(mut self, f: impl Fn(&mut DynamicEnum) + Send + Sync + 'static) -> Self {
+        self.steps.push(MigrationStep::MapEnum(Arc::new(f)));
+        self
+    }
+}
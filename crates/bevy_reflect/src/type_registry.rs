@@ -1,4 +1,7 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::{Serializable, TypeMigrations},
+    FromReflect, Reflect, TypeInfo, TypePath, Typed,
+};
 use alloc::sync::Arc;
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet, TypeIdMap};
@@ -27,7 +30,10 @@ pub struct TypeRegistry {
     registrations: TypeIdMap<TypeRegistration>,
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
+    stable_type_id_to_id: HashMap<u64, TypeId>,
+    alias_to_id: HashMap<String, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    ambiguous_stable_type_ids: HashSet<u64>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -90,7 +96,10 @@ impl TypeRegistry {
             registrations: Default::default(),
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
+            stable_type_id_to_id: Default::default(),
+            alias_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            ambiguous_stable_type_ids: Default::default(),
         }
     }
 
@@ -193,7 +202,9 @@ impl TypeRegistry {
             &registration,
             &mut self.short_path_to_id,
             &mut self.type_path_to_id,
+            &mut self.stable_type_id_to_id,
             &mut self.ambiguous_names,
+            &mut self.ambiguous_stable_type_ids,
         );
         self.registrations
             .insert(registration.type_id(), registration);
@@ -219,7 +230,9 @@ impl TypeRegistry {
                     &registration,
                     &mut self.short_path_to_id,
                     &mut self.type_path_to_id,
+                    &mut self.stable_type_id_to_id,
                     &mut self.ambiguous_names,
+                    &mut self.ambiguous_stable_type_ids,
                 );
                 entry.insert(registration);
                 true
@@ -232,7 +245,9 @@ impl TypeRegistry {
         registration: &TypeRegistration,
         short_path_to_id: &mut HashMap<&'static str, TypeId>,
         type_path_to_id: &mut HashMap<&'static str, TypeId>,
+        stable_type_id_to_id: &mut HashMap<u64, TypeId>,
         ambiguous_names: &mut HashSet<&'static str>,
+        ambiguous_stable_type_ids: &mut HashSet<u64>,
     ) {
         let short_name = registration.type_info().type_path_table().short_path();
         if short_path_to_id.contains_key(short_name) || ambiguous_names.contains(short_name) {
@@ -243,6 +258,17 @@ impl TypeRegistry {
             short_path_to_id.insert(short_name, registration.type_id());
         }
         type_path_to_id.insert(registration.type_info().type_path(), registration.type_id());
+        let stable_type_id = registration.stable_type_id();
+        let collides = stable_type_id_to_id
+            .get(&stable_type_id)
+            .is_some_and(|type_id| *type_id != registration.type_id());
+        if collides || ambiguous_stable_type_ids.contains(&stable_type_id) {
+            // Two types hash to the same id, so it can't refer to either of them.
+            stable_type_id_to_id.remove(&stable_type_id);
+            ambiguous_stable_type_ids.insert(stable_type_id);
+        } else {
+            stable_type_id_to_id.insert(stable_type_id, registration.type_id());
+        }
     }
 
     /// Registers the type data `D` for type `T`.
@@ -272,6 +298,30 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers the [`TypeMigrations`] of type `T`,
+    /// which are used to read data written by older versions of `T`.
+    ///
+    /// The [aliases] of the migrations can be used in place of the type path of `T`
+    /// by [`get_with_type_path_alias`](Self::get_with_type_path_alias).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    ///
+    /// [aliases]: TypeMigrations::aliases
+    pub fn register_migrations<T: Reflect + TypePath>(&mut self, migrations: TypeMigrations) {
+        let registration = self.registrations.get_mut(&TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_migrations` for type `{}` without registering it first",
+                T::type_path(),
+            )
+        });
+        for alias in migrations.aliases() {
+            self.alias_to_id.insert(alias.into(), TypeId::of::<T>());
+        }
+        registration.insert(migrations);
+    }
+
     pub fn contains(&self, type_id: TypeId) -> bool {
         self.registrations.contains_key(&type_id)
     }
@@ -305,6 +355,32 @@ impl TypeRegistry {
             .and_then(|id| self.get(*id))
     }
 
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given [type path], or of the type which registered it as an [alias].
+    ///
+    /// If no type with the given path or alias has been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    /// [alias]: TypeMigrations::with_alias
+    pub fn get_with_type_path_alias(&self, type_path: &str) -> Option<&TypeRegistration> {
+        self.get_with_type_path(type_path)
+            .or_else(|| self.alias_to_id.get(type_path).and_then(|id| self.get(*id)))
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given [stable type id].
+    ///
+    /// If no type with the given stable type id has been registered, or if the id is
+    /// [ambiguous], returns `None`.
+    ///
+    /// [stable type id]: TypeRegistration::stable_type_id
+    /// [ambiguous]: Self::is_stable_type_id_ambiguous
+    pub fn get_with_stable_type_id(&self, stable_type_id: u64) -> Option<&TypeRegistration> {
+        self.stable_type_id_to_id
+            .get(&stable_type_id)
+            .and_then(|id| self.get(*id))
+    }
+
     /// Returns a mutable reference to the [`TypeRegistration`] of the type with
     /// the given [type path].
     ///
@@ -373,6 +449,20 @@ impl TypeRegistry {
         self.ambiguous_names.contains(short_type_path)
     }
 
+    /// Returns `true` if the given [stable type id] is shared by several registered types.
+    ///
+    /// Such an id doesn't refer to any type, so [`get_with_stable_type_id`] returns `None` for it
+    /// and these types can't be written with the compact format. Since stable type ids are hashes
+    /// of [type paths], this only happens for types with the same type path, or on the extremely
+    /// unlikely collision of two hashes.
+    ///
+    /// [stable type id]: TypeRegistration::stable_type_id
+    /// [`get_with_stable_type_id`]: Self::get_with_stable_type_id
+    /// [type paths]: TypePath::type_path
+    pub fn is_stable_type_id_ambiguous(&self, stable_type_id: u64) -> bool {
+        self.ambiguous_stable_type_ids.contains(&stable_type_id)
+    }
+
     /// Returns a reference to the [`TypeData`] of type `T` associated with the given [`TypeId`].
     ///
     /// The returned value may be used to downcast [`Reflect`] trait objects to
@@ -497,6 +587,24 @@ impl TypeRegistration {
         self.type_info
     }
 
+    /// Returns an id of the type that, unlike its [`TypeId`], is stable across compilations
+    /// and platforms.
+    ///
+    /// The id is a 64-bit hash of the [type path], so it only changes if the type path changes.
+    /// It can be used to refer to a type in serialized data without writing its type path,
+    /// and to find its registration with [`TypeRegistry::get_with_stable_type_id`].
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn stable_type_id(&self) -> u64 {
+        // 64-bit FNV-1a, which doesn't depend on the platform or on a random seed.
+        self.type_info
+            .type_path()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
     /// Inserts an instance of `T` into this registration's [type data].
     ///
     /// If another instance of `T` was previously inserted, it is replaced.
@@ -936,4 +1044,54 @@ mod test {
         let data = registration.data::<DataA>().unwrap();
         assert_eq!(data.0, 456);
     }
+
+    #[test]
+    fn stable_type_id_collisions_are_ambiguous() {
+        // Both types have the type path `collision::Collision`, so the same stable type id.
+        mod a {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "collision"]
+            pub struct Collision;
+        }
+        mod b {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "collision"]
+            pub struct Collision;
+        }
+        #[derive(Reflect)]
+        struct Unique;
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<a::Collision>();
+        registry.register::<Unique>();
+        let stable_type_id = registry
+            .get(TypeId::of::<a::Collision>())
+            .unwrap()
+            .stable_type_id();
+        assert_eq!(
+            registry
+                .get_with_stable_type_id(stable_type_id)
+                .map(TypeRegistration::type_id),
+            Some(TypeId::of::<a::Collision>())
+        );
+
+        // Registering the same type again doesn't make its id ambiguous.
+        registry.overwrite_registration(TypeRegistration::of::<a::Collision>());
+        assert!(!registry.is_stable_type_id_ambiguous(stable_type_id));
+
+        registry.register::<b::Collision>();
+        assert!(registry.is_stable_type_id_ambiguous(stable_type_id));
+        assert!(registry.get_with_stable_type_id(stable_type_id).is_none());
+
+        let unique = registry
+            .get(TypeId::of::<Unique>())
+            .unwrap()
+            .stable_type_id();
+        assert!(!registry.is_stable_type_id_ambiguous(unique));
+        assert!(registry.get_with_stable_type_id(unique).is_some());
+    }
 }
@@ -3,26 +3,33 @@
 use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
+use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
+    change_detection::DetectChangesMut,
     component::ComponentId,
     entity::Entity,
     event::EventCursor,
+    observer::Trigger,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource},
     removal_detection::RemovedComponentEntity,
-    system::{In, Local},
-    world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
+    system::{In, Local, ResMut, Resource},
+    world::{EntityRef, EntityWorldMut, FilteredEntityRef, Mut, World},
 };
 use bevy_hierarchy::BuildChildren as _;
 use bevy_reflect::{
-    serde::{ReflectSerializer, TypedReflectDeserializer},
-    PartialReflect, TypeRegistration, TypeRegistry,
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
+    ParsedPath, PartialReflect, ReflectPath as _, TypeRegistration, TypeRegistry,
 };
 use bevy_utils::HashMap;
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-use crate::{error_codes, BrpError, BrpResult};
+use crate::{
+    error_codes,
+    schemas::json_schema::{export_type, reflect_types, JsonSchemaBevyType},
+    BrpError, BrpResult, RemoteWatchingRequestClosed, RemoteWatchingRequestId,
+};
 
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
@@ -54,6 +61,36 @@ pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 /// The method path for a `bevy/list+watch` request.
 pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 
+/// The method path for a `bevy/mutate_component` request.
+pub const BRP_MUTATE_COMPONENT_METHOD: &str = "bevy/mutate_component";
+
+/// The method path for a `bevy/get_resource` request.
+pub const BRP_GET_RESOURCE_METHOD: &str = "bevy/get_resource";
+
+/// The method path for a `bevy/insert_resource` request.
+pub const BRP_INSERT_RESOURCE_METHOD: &str = "bevy/insert_resource";
+
+/// The method path for a `bevy/mutate_resource` request.
+pub const BRP_MUTATE_RESOURCE_METHOD: &str = "bevy/mutate_resource";
+
+/// The method path for a `bevy/remove_resource` request.
+pub const BRP_REMOVE_RESOURCE_METHOD: &str = "bevy/remove_resource";
+
+/// The method path for a `bevy/list_resources` request.
+pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
+
+/// The method path for a `bevy/send_event` request.
+pub const BRP_SEND_EVENT_METHOD: &str = "bevy/send_event";
+
+/// The method path for a `bevy/trigger_event` request.
+pub const BRP_TRIGGER_EVENT_METHOD: &str = "bevy/trigger_event";
+
+/// The method path for a `bevy/event+watch` request.
+pub const BRP_EVENT_AND_WATCH_METHOD: &str = "bevy/event+watch";
+
+/// The method path for a `bevy/registry/schema` request.
+pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -160,6 +197,30 @@ pub struct BrpInsertParams {
     pub components: HashMap<String, Value>,
 }
 
+/// `bevy/mutate_component`: Replaces a single field of a component, leaving the rest of it
+/// untouched.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpMutateComponentParams {
+    /// The ID of the entity whose component is to be mutated.
+    pub entity: Entity,
+
+    /// The [full path] of the component type to mutate.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The [reflect path] of the field to replace within the component: e.g. `.translation.y`.
+    /// An empty path replaces the whole component.
+    ///
+    /// [reflect path]: bevy_reflect::GetPath
+    pub path: String,
+
+    /// The serialized value of the field.
+    pub value: Value,
+}
+
 /// `bevy/reparent`: Assign a new parent to one or more entities.
 ///
 /// The server responds with a null.
@@ -187,6 +248,133 @@ pub struct BrpListParams {
     pub entity: Entity,
 }
 
+/// `bevy/get_resource`: Retrieves the value of a resource.
+///
+/// The server responds with a [`BrpGetResourceResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpGetResourceParams {
+    /// The [full path] of the resource type to retrieve.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/insert_resource`: Inserts a resource, replacing its previous value.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpInsertResourceParams {
+    /// The [full path] of the resource type to insert.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The serialized value of the resource.
+    pub value: Value,
+}
+
+/// `bevy/mutate_resource`: Replaces a single field of a resource, leaving the rest of it
+/// untouched.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpMutateResourceParams {
+    /// The [full path] of the resource type to mutate.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The [reflect path] of the field to replace within the resource: e.g. `.difficulty` or
+    /// `.curve[2].0`. An empty path replaces the whole resource.
+    ///
+    /// [reflect path]: bevy_reflect::GetPath
+    pub path: String,
+
+    /// The serialized value of the field.
+    pub value: Value,
+}
+
+/// `bevy/remove_resource`: Removes a resource.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpRemoveResourceParams {
+    /// The [full path] of the resource type to remove.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/send_event`: Sends an event, to be read by the [`EventReader`]s of its type.
+///
+/// The server responds with a null.
+///
+/// [`EventReader`]: bevy_ecs::event::EventReader
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpSendEventParams {
+    /// The [full path] of the event type to send.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+}
+
+/// `bevy/trigger_event`: Triggers an event for the observers watching it.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpTriggerEventParams {
+    /// The [full path] of the event type to trigger.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+
+    /// The entities to trigger the event on. If empty, only the global observers are triggered.
+    #[serde(default)]
+    pub targets: Vec<Entity>,
+}
+
+/// `bevy/event+watch`: Streams the events of a type as they are sent.
+///
+/// The server responds with a [`BrpEventWatchingResponse`] every frame in which events of the type
+/// were sent.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpEventWatchingParams {
+    /// The [full path] of the event type to watch.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+}
+
+/// `bevy/registry/schema`: Retrieves the JSON Schema of the registered types matching the
+/// filters.
+///
+/// The server responds with a [`BrpRegistrySchemaResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BrpRegistrySchemaParams {
+    /// The names of the crates whose types are included. All crates are included if empty.
+    #[serde(default)]
+    pub with_crates: Vec<String>,
+
+    /// The names of the crates whose types are excluded.
+    #[serde(default)]
+    pub without_crates: Vec<String>,
+
+    /// The reflected traits that included types must all register, among `Component`,
+    /// `Resource`, `Event`, `Default`, `Serialize` and `Deserialize`.
+    #[serde(default)]
+    pub with_types: Vec<String>,
+
+    /// The reflected traits that included types must not register.
+    #[serde(default)]
+    pub without_types: Vec<String>,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct BrpQuery {
@@ -291,6 +479,26 @@ pub struct BrpListWatchingResponse {
     removed: Vec<String>,
 }
 
+/// The response to a `bevy/get_resource` request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpGetResourceResponse {
+    /// The serialized value of the resource.
+    pub value: Value,
+}
+
+/// The response to a `bevy/list_resources` request.
+pub type BrpListResourcesResponse = Vec<String>;
+
+/// A single response from a `bevy/event+watch` request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpEventWatchingResponse {
+    /// The serialized values of the events sent since the last response, oldest first.
+    pub events: Vec<Value>,
+}
+
+/// The response to a `bevy/registry/schema` request.
+pub type BrpRegistrySchemaResponse = HashMap<String, JsonSchemaBevyType>;
+
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
@@ -309,7 +517,7 @@ pub struct BrpQueryRow {
 }
 
 /// A helper function used to parse a `serde_json::Value`.
-fn parse<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, BrpError> {
+pub(crate) fn parse<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, BrpError> {
     serde_json::from_value(value).map_err(|err| BrpError {
         code: error_codes::INVALID_PARAMS,
         message: err.to_string(),
@@ -318,7 +526,9 @@ fn parse<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, BrpError> {
 }
 
 /// A helper function used to parse a `serde_json::Value` wrapped in an `Option`.
-fn parse_some<T: for<'de> Deserialize<'de>>(value: Option<Value>) -> Result<T, BrpError> {
+pub(crate) fn parse_some<T: for<'de> Deserialize<'de>>(
+    value: Option<Value>,
+) -> Result<T, BrpError> {
     match value {
         Some(value) => parse(value),
         None => Err(BrpError {
@@ -671,6 +881,39 @@ pub fn process_remote_remove_request(
     Ok(Value::Null)
 }
 
+/// Handles a `bevy/mutate_component` request coming from a client.
+pub fn process_remote_mutate_component_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateComponentParams {
+        entity,
+        component,
+        path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_component =
+        get_reflect_component(&type_registry, &component).map_err(BrpError::component_error)?;
+
+    let mut entity_world_mut = get_entity_mut(world, entity)?;
+    let Some(mut reflected) = reflect_component.reflect_mut(&mut entity_world_mut) else {
+        return Err(BrpError::component_not_present(&component, entity));
+    };
+    apply_at_path(
+        &type_registry,
+        reflected.bypass_change_detection().as_partial_reflect_mut(),
+        &path,
+        value,
+    )
+    .map_err(BrpError::component_error)?;
+    reflected.set_changed();
+
+    Ok(Value::Null)
+}
+
 /// Handles a `bevy/destroy` (despawn entity) request coming from a client.
 pub fn process_remote_destroy_request(
     In(params): In<Option<Value>>,
@@ -793,6 +1036,260 @@ pub fn process_remote_list_watching_request(
     }
 }
 
+/// Handles a `bevy/get_resource` request coming from a client.
+pub fn process_remote_get_resource_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpGetResourceParams { resource } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource).map_err(BrpError::resource_error)?;
+
+    let Some(reflected) = reflect_resource.reflect(world) else {
+        return Err(BrpError::resource_not_present(&resource));
+    };
+    let serializer = TypedReflectSerializer::new(reflected.as_partial_reflect(), &type_registry);
+    let value = serde_json::to_value(serializer).map_err(BrpError::resource_error)?;
+
+    serde_json::to_value(BrpGetResourceResponse { value }).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/insert_resource` request coming from a client.
+pub fn process_remote_insert_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpInsertResourceParams { resource, value } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource).map_err(BrpError::resource_error)?;
+    let reflected =
+        deserialize_value(&type_registry, &resource, &value).map_err(BrpError::resource_error)?;
+
+    reflect_resource.insert(world, &*reflected, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/mutate_resource` request coming from a client.
+pub fn process_remote_mutate_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateResourceParams {
+        resource,
+        path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource).map_err(BrpError::resource_error)?;
+
+    let Some(mut reflected) = reflect_resource.reflect_mut(world) else {
+        return Err(BrpError::resource_not_present(&resource));
+    };
+    apply_at_path(
+        &type_registry,
+        reflected.bypass_change_detection().as_partial_reflect_mut(),
+        &path,
+        value,
+    )
+    .map_err(BrpError::resource_error)?;
+    reflected.set_changed();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/remove_resource` request coming from a client.
+pub fn process_remote_remove_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRemoveResourceParams { resource } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource).map_err(BrpError::resource_error)?;
+
+    reflect_resource.remove(world);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/list_resources` request (list all resources) coming from a client.
+pub fn process_remote_list_resources_request(In(_): In<Option<Value>>, world: &World) -> BrpResult {
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut response = BrpListResourcesResponse::default();
+    for registered_type in type_registry.iter() {
+        if registered_type.data::<ReflectResource>().is_some() {
+            response.push(registered_type.type_info().type_path().to_owned());
+        }
+    }
+
+    // Sort both for cleanliness and to reduce the risk that clients start
+    // accidentally depending on the order.
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/send_event` request coming from a client.
+pub fn process_remote_send_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSendEventParams { event, value } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_event = get_reflect_event(&type_registry, &event).map_err(BrpError::event_error)?;
+    let reflected =
+        deserialize_value(&type_registry, &event, &value).map_err(BrpError::event_error)?;
+
+    if !reflect_event.send(world, &*reflected, &type_registry) {
+        return Err(BrpError::event_not_registered(&event));
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/trigger_event` request coming from a client.
+pub fn process_remote_trigger_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpTriggerEventParams {
+        event,
+        value,
+        targets,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_event = get_reflect_event(&type_registry, &event).map_err(BrpError::event_error)?;
+    let reflected =
+        deserialize_value(&type_registry, &event, &value).map_err(BrpError::event_error)?;
+
+    for &target in &targets {
+        get_entity(world, target)?;
+    }
+    reflect_event.trigger(world, &*reflected, &targets, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// The position of each `bevy/event+watch` request in the events of its type, so that every
+/// request receives all the events, however many requests watch the same type.
+#[derive(Debug, Resource, Default, Deref, DerefMut)]
+pub(crate) struct EventWatchingCursors(HashMap<RemoteWatchingRequestId, Option<usize>>);
+
+/// Drops the cursor of a closed `bevy/event+watch` request.
+pub(crate) fn remove_event_watching_cursor(
+    trigger: Trigger<RemoteWatchingRequestClosed>,
+    mut cursors: ResMut<EventWatchingCursors>,
+) {
+    cursors.remove(&trigger.event().0);
+}
+
+/// Handles a `bevy/event+watch` request coming from a client.
+pub fn process_remote_event_watching_request(
+    In((request_id, params)): In<(RemoteWatchingRequestId, Option<Value>)>,
+    world: &mut World,
+) -> BrpResult<Option<Value>> {
+    let BrpEventWatchingParams { event } = parse_some(params)?;
+
+    world.resource_scope(|world, mut cursors: Mut<EventWatchingCursors>| {
+        read_watched_events(world, &event, cursors.entry(request_id).or_default())
+    })
+}
+
+/// Serializes the events of type `event` sent since the last call with the same `cursor`.
+fn read_watched_events(
+    world: &World,
+    event: &str,
+    cursor: &mut Option<usize>,
+) -> BrpResult<Option<Value>> {
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    let reflect_event = get_reflect_event(&type_registry, event).map_err(BrpError::event_error)?;
+
+    let Some(events) = reflect_event.read(world, cursor) else {
+        return Err(BrpError::event_not_registered(event));
+    };
+    if events.is_empty() {
+        return Ok(None);
+    }
+
+    let events = events
+        .into_iter()
+        .map(|reflected| {
+            let serializer =
+                TypedReflectSerializer::new(reflected.as_partial_reflect(), &type_registry);
+            serde_json::to_value(serializer).map_err(BrpError::event_error)
+        })
+        .collect::<Result<_, _>>()?;
+
+    serde_json::to_value(BrpEventWatchingResponse { events })
+        .map(Some)
+        .map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/registry/schema` request coming from a client.
+pub fn process_remote_registry_schema_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let filter: BrpRegistrySchemaParams = match params {
+        Some(params) => parse(params)?,
+        None => BrpRegistrySchemaParams::default(),
+    };
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut response = BrpRegistrySchemaResponse::default();
+    for registration in type_registry.iter() {
+        // Filter before exporting, as exporting serializes the default values of fields.
+        let crate_name = registration
+            .type_info()
+            .type_path_table()
+            .crate_name()
+            .unwrap_or_default();
+        if (!filter.with_crates.is_empty()
+            && !filter.with_crates.iter().any(|name| name == crate_name))
+            || filter.without_crates.iter().any(|name| name == crate_name)
+        {
+            continue;
+        }
+        let registered_types = reflect_types(registration);
+        if !filter
+            .with_types
+            .iter()
+            .all(|name| registered_types.contains(name))
+            || filter
+                .without_types
+                .iter()
+                .any(|name| registered_types.contains(name))
+        {
+            continue;
+        }
+        let (type_path, schema) = export_type(registration, &type_registry);
+        response.insert(type_path.to_owned(), schema);
+    }
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -969,3 +1466,553 @@ fn get_component_type_registration<'r>(
         .get_with_type_path(component_path)
         .ok_or_else(|| anyhow!("Unknown component type: `{}`", component_path))
 }
+
+/// Given a resource's type path, return the associated [`ReflectResource`] from the given
+/// `type_registry` if possible.
+fn get_reflect_resource<'r>(
+    type_registry: &'r TypeRegistry,
+    resource_path: &str,
+) -> AnyhowResult<&'r ReflectResource> {
+    type_registry
+        .get_with_type_path(resource_path)
+        .ok_or_else(|| anyhow!("Unknown resource type: `{}`", resource_path))?
+        .data::<ReflectResource>()
+        .ok_or_else(|| anyhow!("Resource `{}` isn't reflectable", resource_path))
+}
+
+/// Given an event's type path, return the associated [`ReflectEvent`] from the given
+/// `type_registry` if possible.
+fn get_reflect_event<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+) -> AnyhowResult<&'r ReflectEvent> {
+    type_registry
+        .get_with_type_path(event_path)
+        .ok_or_else(|| anyhow!("Unknown event type: `{}`", event_path))?
+        .data::<ReflectEvent>()
+        .ok_or_else(|| anyhow!("Event `{}` isn't reflectable", event_path))
+}
+
+/// Deserializes `value` as the type with the given type path.
+pub(crate) fn deserialize_value(
+    type_registry: &TypeRegistry,
+    type_path: &str,
+    value: &Value,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    let Some(registration) = type_registry.get_with_type_path(type_path) else {
+        return Err(anyhow!("Unknown type: `{}`", type_path));
+    };
+    Ok(TypedReflectDeserializer::new(registration, type_registry).deserialize(value)?)
+}
+
+/// Replaces the field at the [reflect path] `path` within `root` with the deserialized `value`.
+///
+/// [reflect path]: bevy_reflect::GetPath
+fn apply_at_path(
+    type_registry: &TypeRegistry,
+    root: &mut dyn PartialReflect,
+    path: &str,
+    value: Value,
+) -> AnyhowResult<()> {
+    let parsed_path = ParsedPath::parse(path).map_err(|err| anyhow!("{}", err))?;
+    let field = parsed_path
+        .reflect_element_mut(root)
+        .map_err(|err| anyhow!("{}", err))?;
+
+    let Some(type_info) = field.get_represented_type_info() else {
+        return Err(anyhow!("Field at `{}` has no type information", path));
+    };
+    let value = deserialize_value(type_registry, type_info.type_path(), &value)?;
+    field.try_apply(&*value)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BrpMessage, BrpSender, RemotePlugin};
+    use bevy_app::App;
+    use bevy_ecs::{
+        component::Component,
+        event::Event,
+        query::Changed,
+        reflect::{ReflectComponent, ReflectResource},
+        system::{IntoSystem, Resource, RunSystemOnce},
+    };
+    use bevy_reflect::{Reflect, TypePath};
+    use serde_json::json;
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    struct Clock {
+        paused: bool,
+        relative_speed: f64,
+    }
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Settings {
+        clock: Clock,
+        max_delta: u64,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: Clock,
+        max: u64,
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Settings>();
+            type_registry.register::<Health>();
+        }
+        world
+    }
+
+    fn call<M>(
+        world: &mut World,
+        handler: impl IntoSystem<In<Option<Value>>, BrpResult, M>,
+        params: Value,
+    ) -> BrpResult {
+        world.run_system_once_with(Some(params), handler).unwrap()
+    }
+
+    fn error_code(result: BrpResult) -> i16 {
+        result.unwrap_err().code
+    }
+
+    #[test]
+    fn unknown_resource() {
+        let mut world = world();
+        let params = json!({ "resource": "my_game::Unknown", "path": "", "value": null });
+
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_get_resource_request,
+                params.clone()
+            )),
+            error_codes::RESOURCE_ERROR
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_insert_resource_request,
+                params.clone()
+            )),
+            error_codes::RESOURCE_ERROR
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_resource_request,
+                params.clone()
+            )),
+            error_codes::RESOURCE_ERROR
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_remove_resource_request,
+                params
+            )),
+            error_codes::RESOURCE_ERROR
+        );
+    }
+
+    #[test]
+    fn resource_not_present() {
+        let mut world = world();
+        let resource = Settings::type_path();
+
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_get_resource_request,
+                json!({ "resource": resource }),
+            )),
+            error_codes::RESOURCE_NOT_PRESENT
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_resource_request,
+                json!({ "resource": resource, "path": ".max_delta", "value": 1 }),
+            )),
+            error_codes::RESOURCE_NOT_PRESENT
+        );
+        // Removing an absent resource is a no-op.
+        call(
+            &mut world,
+            process_remote_remove_resource_request,
+            json!({ "resource": resource }),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn insert_get_list_and_remove_resource() {
+        let mut world = world();
+        let resource = Settings::type_path();
+        let value = json!({
+            "clock": { "paused": true, "relative_speed": 2.0 },
+            "max_delta": 250,
+        });
+
+        call(
+            &mut world,
+            process_remote_insert_resource_request,
+            json!({ "resource": resource, "value": value }),
+        )
+        .unwrap();
+        assert_eq!(
+            *world.resource::<Settings>(),
+            Settings {
+                clock: Clock {
+                    paused: true,
+                    relative_speed: 2.0,
+                },
+                max_delta: 250,
+            }
+        );
+
+        let response = call(
+            &mut world,
+            process_remote_get_resource_request,
+            json!({ "resource": resource }),
+        )
+        .unwrap();
+        assert_eq!(response, json!({ "value": value }));
+
+        let response = call(
+            &mut world,
+            process_remote_list_resources_request,
+            Value::Null,
+        )
+        .unwrap();
+        assert_eq!(response, json!([resource]));
+
+        call(
+            &mut world,
+            process_remote_remove_resource_request,
+            json!({ "resource": resource }),
+        )
+        .unwrap();
+        assert!(!world.contains_resource::<Settings>());
+    }
+
+    #[test]
+    fn mutate_resource_at_nested_path() {
+        let mut world = world();
+        world.init_resource::<Settings>();
+        let resource = Settings::type_path();
+
+        call(
+            &mut world,
+            process_remote_mutate_resource_request,
+            json!({ "resource": resource, "path": ".clock.relative_speed", "value": 0.5 }),
+        )
+        .unwrap();
+        assert_eq!(
+            *world.resource::<Settings>(),
+            Settings {
+                clock: Clock {
+                    paused: false,
+                    relative_speed: 0.5,
+                },
+                max_delta: 0,
+            }
+        );
+
+        // An empty path replaces the whole resource.
+        call(
+            &mut world,
+            process_remote_mutate_resource_request,
+            json!({
+                "resource": resource,
+                "path": "",
+                "value": { "clock": { "paused": true, "relative_speed": 1.0 }, "max_delta": 7 },
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            *world.resource::<Settings>(),
+            Settings {
+                clock: Clock {
+                    paused: true,
+                    relative_speed: 1.0,
+                },
+                max_delta: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_resource_with_bad_path_or_value() {
+        let mut world = world();
+        world.init_resource::<Settings>();
+        world.clear_trackers();
+        let resource = Settings::type_path();
+
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_resource_request,
+                json!({ "resource": resource, "path": ".clock.missing", "value": 1 }),
+            )),
+            error_codes::RESOURCE_ERROR
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_resource_request,
+                json!({ "resource": resource, "path": ".clock.paused", "value": "yes" }),
+            )),
+            error_codes::RESOURCE_ERROR
+        );
+        assert_eq!(*world.resource::<Settings>(), Settings::default());
+        assert!(!world.is_resource_changed::<Settings>());
+    }
+
+    #[test]
+    fn mutate_resource_triggers_change_detection() {
+        let mut world = world();
+        world.init_resource::<Settings>();
+        world.clear_trackers();
+        assert!(!world.is_resource_changed::<Settings>());
+
+        call(
+            &mut world,
+            process_remote_mutate_resource_request,
+            json!({ "resource": Settings::type_path(), "path": ".max_delta", "value": 10 }),
+        )
+        .unwrap();
+        assert!(world.is_resource_changed::<Settings>());
+    }
+
+    #[test]
+    fn mutate_component_at_path() {
+        let mut world = world();
+        let entity = world.spawn(Health::default()).id();
+
+        call(
+            &mut world,
+            process_remote_mutate_component_request,
+            json!({
+                "entity": entity,
+                "component": Health::type_path(),
+                "path": ".current.relative_speed",
+                "value": 0.25,
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            *world.get::<Health>(entity).unwrap(),
+            Health {
+                current: Clock {
+                    paused: false,
+                    relative_speed: 0.25,
+                },
+                max: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn mutate_component_errors() {
+        let mut world = world();
+        let entity = world.spawn(Health::default()).id();
+        let without_health = world.spawn_empty().id();
+        world.clear_trackers();
+        let component = Health::type_path();
+
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_component_request,
+                json!({ "entity": entity, "component": component, "path": ".missing", "value": 1 }),
+            )),
+            error_codes::COMPONENT_ERROR
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_component_request,
+                json!({ "entity": entity, "component": component, "path": ".max", "value": "many" }),
+            )),
+            error_codes::COMPONENT_ERROR
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_component_request,
+                json!({ "entity": entity, "component": "my_game::Unknown", "path": "", "value": 1 }),
+            )),
+            error_codes::COMPONENT_ERROR
+        );
+        assert_eq!(
+            error_code(call(
+                &mut world,
+                process_remote_mutate_component_request,
+                json!({ "entity": without_health, "component": component, "path": ".max", "value": 1 }),
+            )),
+            error_codes::COMPONENT_NOT_PRESENT
+        );
+
+        assert_eq!(*world.get::<Health>(entity).unwrap(), Health::default());
+        let mut changed = world.query_filtered::<Entity, Changed<Health>>();
+        assert_eq!(changed.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn mutate_component_triggers_change_detection() {
+        let mut world = world();
+        let entity = world.spawn(Health::default()).id();
+        world.spawn(Health::default());
+        world.clear_trackers();
+        let mut changed = world.query_filtered::<Entity, Changed<Health>>();
+        assert_eq!(changed.iter(&world).count(), 0);
+
+        call(
+            &mut world,
+            process_remote_mutate_component_request,
+            json!({
+                "entity": entity,
+                "component": Health::type_path(),
+                "path": ".max",
+                "value": 100,
+            }),
+        )
+        .unwrap();
+        assert_eq!(changed.iter(&world).collect::<Vec<_>>(), [entity]);
+    }
+
+    #[test]
+    fn registry_schema_filters() {
+        let mut world = world();
+        let schema_keys = |response: Value| {
+            let mut keys: Vec<_> = response.as_object().unwrap().keys().cloned().collect();
+            keys.sort();
+            keys
+        };
+
+        let response = call(
+            &mut world,
+            process_remote_registry_schema_request,
+            json!({ "with_crates": ["bevy_remote"] }),
+        )
+        .unwrap();
+        assert_eq!(
+            schema_keys(response),
+            [
+                Clock::type_path(),
+                Health::type_path(),
+                Settings::type_path()
+            ]
+        );
+
+        let response = call(
+            &mut world,
+            process_remote_registry_schema_request,
+            json!({ "with_crates": ["bevy_remote"], "without_types": ["Component"] }),
+        )
+        .unwrap();
+        assert_eq!(
+            schema_keys(response),
+            [Clock::type_path(), Settings::type_path()]
+        );
+
+        let response = call(
+            &mut world,
+            process_remote_registry_schema_request,
+            json!({ "with_types": ["Resource"] }),
+        )
+        .unwrap();
+        assert_eq!(schema_keys(response), [Settings::type_path()]);
+
+        let response = call(
+            &mut world,
+            process_remote_registry_schema_request,
+            json!({ "without_crates": ["bevy_remote"] }),
+        )
+        .unwrap();
+        let keys = schema_keys(response);
+        assert!(keys.iter().any(|key| key == "u32"));
+        assert!(!keys.iter().any(|key| key.starts_with("bevy_remote::")));
+
+        let response = call(
+            &mut world,
+            process_remote_registry_schema_request,
+            json!({ "with_crates": ["bevy_remote"], "without_crates": ["bevy_remote"] }),
+        )
+        .unwrap();
+        assert_eq!(response, json!({}));
+    }
+
+    #[derive(Event, Reflect, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Ping(u32);
+
+    fn watch_events(app: &mut App) -> async_channel::Receiver<BrpResult> {
+        let (sender, receiver) = async_channel::bounded(8);
+        app.world()
+            .resource::<BrpSender>()
+            .try_send(BrpMessage {
+                method: BRP_EVENT_AND_WATCH_METHOD.to_owned(),
+                params: Some(json!({ "event": Ping::type_path() })),
+                sender,
+            })
+            .unwrap();
+        receiver
+    }
+
+    #[test]
+    fn event_watching_requests_each_receive_all_events() {
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default())
+            .add_event::<Ping>()
+            .register_type::<Ping>();
+        app.update();
+
+        let first = watch_events(&mut app);
+        let second = watch_events(&mut app);
+        app.world_mut().send_event(Ping(0));
+        app.update();
+        // Events sent before the request are skipped.
+        assert!(first.is_empty());
+        assert!(second.is_empty());
+
+        app.world_mut().send_event(Ping(1));
+        app.world_mut().send_event(Ping(2));
+        app.update();
+        let expected = json!({ "events": [1, 2] });
+        assert_eq!(first.try_recv().unwrap().unwrap(), expected);
+        assert_eq!(second.try_recv().unwrap().unwrap(), expected);
+
+        // A new request doesn't affect the ongoing ones.
+        let third = watch_events(&mut app);
+        app.world_mut().send_event(Ping(3));
+        app.update();
+        let expected = json!({ "events": [3] });
+        assert_eq!(first.try_recv().unwrap().unwrap(), expected);
+        assert_eq!(second.try_recv().unwrap().unwrap(), expected);
+        assert!(third.is_empty());
+
+        // The cursors of closed requests are dropped along with them.
+        assert_eq!(app.world().resource::<EventWatchingCursors>().len(), 3);
+        drop(first);
+        app.update();
+        assert_eq!(app.world().resource::<EventWatchingCursors>().len(), 2);
+
+        app.world_mut().send_event(Ping(4));
+        app.update();
+        let expected = json!({ "events": [4] });
+        assert_eq!(second.try_recv().unwrap().unwrap(), expected);
+        assert_eq!(third.try_recv().unwrap().unwrap(), expected);
+    }
+}
@@ -0,0 +1,587 @@
+//! Methods to list and call the functions of the [`AppFunctionRegistry`] through the Bevy Remote
+//! Protocol.
+//!
+//! These methods are only available if `bevy_remote` is compiled with the `reflect_functions`
+//! feature. Functions are registered with
+//! [`App::register_function`](bevy_app::App::register_function).
+
+use anyhow::{anyhow, Result as AnyhowResult};
+use bevy_ecs::{
+    entity::Entity,
+    reflect::{AppFunctionRegistry, AppTypeRegistry, ReflectComponent, ReflectResource},
+    system::In,
+    world::World,
+};
+use bevy_reflect::{
+    func::{
+        args::{ArgInfo, ArgList, Ownership},
+        FunctionInfo, Return,
+    },
+    serde::TypedReflectSerializer,
+    PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    builtin_methods::{deserialize_value, parse_some},
+    BrpError, BrpResult,
+};
+
+/// The method path for a `bevy/list_functions` request.
+pub const BRP_LIST_FUNCTIONS_METHOD: &str = "bevy/list_functions";
+
+/// The method path for a `bevy/call_function` request.
+pub const BRP_CALL_FUNCTION_METHOD: &str = "bevy/call_function";
+
+/// `bevy/call_function`: Calls a function of the [`AppFunctionRegistry`].
+///
+/// The server responds with the serialized return value of the function, or a null if it returns
+/// nothing.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpCallFunctionParams {
+    /// The name of the function, as listed by `bevy/list_functions`.
+    pub function: String,
+
+    /// The arguments to call the function with, in order.
+    ///
+    /// Each argument is deserialized as the type of the corresponding parameter, so null is
+    /// passed as a value: e.g. `None` for an `Option<T>` parameter. Arguments are instead taken
+    /// from the world if they are an object with a single [`WORLD_ARG_KEY`] field:
+    /// - `{"$world": "component"}`: The component of [`entity`](Self::entity) of the type of the
+    ///   parameter.
+    /// - `{"$world": "resource"}`: The resource of the type of the parameter.
+    /// - `{"$world": "entity"}`: [`entity`](Self::entity) itself, for an [`Entity`] parameter.
+    ///
+    /// Changes made through a `&mut T` parameter taken from the world are applied back to the
+    /// world once the function returns.
+    #[serde(default)]
+    pub args: Vec<Value>,
+
+    /// The entity that arguments taken from the world refer to.
+    #[serde(default)]
+    pub entity: Option<Entity>,
+}
+
+/// The signature of a function, as listed by `bevy/list_functions`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BrpFunctionInfo {
+    /// The name the function is registered under.
+    pub name: String,
+
+    /// The parameters of the function, in order.
+    pub args: Vec<BrpFunctionArgInfo>,
+
+    /// The return type of the function.
+    pub return_type: BrpFunctionTypeInfo,
+}
+
+/// A parameter of a function, as listed by `bevy/list_functions`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BrpFunctionArgInfo {
+    /// The name of the parameter, if it is known.
+    pub name: Option<String>,
+
+    /// The type of the parameter.
+    #[serde(flatten)]
+    pub ty: BrpFunctionTypeInfo,
+}
+
+/// The type of a parameter or of a return value.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BrpFunctionTypeInfo {
+    /// The [fully-qualified type name] of the type, without the reference.
+    ///
+    /// [fully-qualified type name]: bevy_reflect::TypePath::type_path
+    pub type_path: String,
+
+    /// Whether the value is owned or borrowed.
+    pub ownership: BrpOwnership,
+}
+
+/// Whether a parameter or return value is owned or borrowed.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpOwnership {
+    /// The type is owned (i.e. `T`).
+    Owned,
+    /// The type is a reference (i.e. `&T`).
+    Ref,
+    /// The type is a mutable reference (i.e. `&mut T`).
+    Mut,
+}
+
+impl From<Ownership> for BrpOwnership {
+    fn from(ownership: Ownership) -> Self {
+        match ownership {
+            Ownership::Owned => Self::Owned,
+            Ownership::Ref => Self::Ref,
+            Ownership::Mut => Self::Mut,
+        }
+    }
+}
+
+/// The response to a `bevy/list_functions` request.
+pub type BrpListFunctionsResponse = Vec<BrpFunctionInfo>;
+
+/// The field marking an argument of a `bevy/call_function` request as taken from the world.
+///
+/// See [`BrpCallFunctionParams::args`].
+pub const WORLD_ARG_KEY: &str = "$world";
+
+/// What an argument marked with [`WORLD_ARG_KEY`] is taken from.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum WorldArg {
+    Component,
+    Resource,
+    Entity,
+}
+
+/// Where the value of an argument taken from the world was read from, so that changes can be
+/// applied back to it.
+enum WorldSource {
+    Component(Entity),
+    Resource,
+}
+
+/// An argument ready to be passed to a function.
+struct PreparedArg<'r> {
+    value: Box<dyn PartialReflect>,
+    ownership: Ownership,
+    source: Option<(WorldSource, &'r TypeRegistration)>,
+}
+
+/// Handles a `bevy/list_functions` request coming from a client.
+pub fn process_remote_list_functions_request(In(_): In<Option<Value>>, world: &World) -> BrpResult {
+    let function_registry = world.resource::<AppFunctionRegistry>().read();
+
+    let mut response: BrpListFunctionsResponse = function_registry
+        .iter()
+        .filter_map(|function| {
+            let info = function.info();
+            Some(BrpFunctionInfo {
+                name: info.name()?.to_string(),
+                args: info
+                    .args()
+                    .iter()
+                    .map(|arg| BrpFunctionArgInfo {
+                        name: arg.name().map(ToString::to_string),
+                        ty: type_info(arg.type_path(), arg.ownership()),
+                    })
+                    .collect(),
+                return_type: type_info(
+                    info.return_info().type_path(),
+                    info.return_info().ownership(),
+                ),
+            })
+        })
+        .collect();
+    response.sort_by(|a, b| a.name.cmp(&b.name));
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/call_function` request coming from a client.
+pub fn process_remote_call_function_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpCallFunctionParams {
+        function,
+        args,
+        entity,
+    } = parse_some(params)?;
+
+    let app_function_registry = world.resource::<AppFunctionRegistry>().clone();
+    let function_registry = app_function_registry.read();
+    let Some(dynamic_function) = function_registry.get(&function) else {
+        return Err(BrpError::function_not_found(&function));
+    };
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let mut prepared = prepare_args(world, &type_registry, dynamic_function.info(), args, entity)
+        .map_err(BrpError::function_error)?;
+
+    let mut arg_list = ArgList::new();
+    for arg in &mut prepared {
+        arg_list = match arg.ownership {
+            // Owned arguments are only passed to the function, so they can be moved out.
+            Ownership::Owned => {
+                arg_list.push_boxed(core::mem::replace(&mut arg.value, Box::new(())))
+            }
+            Ownership::Ref => arg_list.push_ref(&*arg.value),
+            Ownership::Mut => arg_list.push_mut(&mut *arg.value),
+        };
+    }
+
+    let value = match dynamic_function
+        .call(arg_list)
+        .map_err(BrpError::function_error)?
+    {
+        Return::Owned(value) => serialize(&*value, &type_registry)?,
+        Return::Ref(value) => serialize(value, &type_registry)?,
+        Return::Mut(value) => serialize(value, &type_registry)?,
+    };
+
+    // Apply the changes made through mutable references back to the world.
+    for arg in prepared {
+        let (Ownership::Mut, Some((source, registration))) = (arg.ownership, arg.source) else {
+            continue;
+        };
+        match source {
+            WorldSource::Component(entity) => {
+                let reflect_component = registration
+                    .data::<ReflectComponent>()
+                    .ok_or_else(|| BrpError::function_error("Missing `ReflectComponent`"))?;
+                let mut entity_world_mut = world
+                    .get_entity_mut(entity)
+                    .map_err(|_| BrpError::entity_not_found(entity))?;
+                reflect_component.apply(&mut entity_world_mut, &*arg.value);
+            }
+            WorldSource::Resource => {
+                let reflect_resource = registration
+                    .data::<ReflectResource>()
+                    .ok_or_else(|| BrpError::function_error("Missing `ReflectResource`"))?;
+                reflect_resource.apply(world, &*arg.value);
+            }
+        }
+    }
+
+    Ok(value)
+}
+
+/// Deserializes the arguments of a function, or reads them from the world when they are marked
+/// with [`WORLD_ARG_KEY`].
+fn prepare_args<'r>(
+    world: &World,
+    type_registry: &'r TypeRegistry,
+    info: &FunctionInfo,
+    args: Vec<Value>,
+    entity: Option<Entity>,
+) -> AnyhowResult<Vec<PreparedArg<'r>>> {
+    if args.len() > info.arg_count() {
+        return Err(anyhow!(
+            "Expected at most {} arguments, received {}",
+            info.arg_count(),
+            args.len()
+        ));
+    }
+
+    let mut args = args.into_iter();
+    info.args()
+        .iter()
+        .map(|arg_info| prepare_arg(world, type_registry, arg_info, args.next(), entity))
+        .collect()
+}
+
+fn prepare_arg<'r>(
+    world: &World,
+    type_registry: &'r TypeRegistry,
+    arg_info: &ArgInfo,
+    value: Option<Value>,
+    entity: Option<Entity>,
+) -> AnyhowResult<PreparedArg<'r>> {
+    let ownership = arg_info.ownership();
+    let type_path = referent_type_path(arg_info.type_path(), ownership);
+    let registration = type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| anyhow!("Unknown type: `{}`", type_path))?;
+
+    let Some(value) = value else {
+        return Err(anyhow!("Missing argument {}", arg_info.index()));
+    };
+    let Some(world_arg) = world_arg(&value, arg_info)? else {
+        let value = deserialize_value(type_registry, type_path, &value)?;
+        return Ok(PreparedArg {
+            value: concrete(registration, value),
+            ownership,
+            source: None,
+        });
+    };
+
+    let entity =
+        || entity.ok_or_else(|| anyhow!("Argument {} requires an entity", arg_info.index()));
+    let (reflected, source) = match world_arg {
+        WorldArg::Entity => {
+            if type_path != <Entity as bevy_reflect::TypePath>::type_path() {
+                return Err(anyhow!("Argument {} is not an `Entity`", arg_info.index()));
+            }
+            return Ok(PreparedArg {
+                value: Box::new(entity()?),
+                ownership,
+                source: None,
+            });
+        }
+        WorldArg::Component => {
+            let reflect_component = registration
+                .data::<ReflectComponent>()
+                .ok_or_else(|| anyhow!("`{}` is not a reflected component", type_path))?;
+            let entity = entity()?;
+            let entity_ref = world
+                .get_entity(entity)
+                .map_err(|_| anyhow!("Entity {} not found", entity))?;
+            let reflected = reflect_component
+                .reflect(entity_ref)
+                .ok_or_else(|| anyhow!("Component `{}` not present on {}", type_path, entity))?;
+            (reflected, WorldSource::Component(entity))
+        }
+        WorldArg::Resource => {
+            let reflect_resource = registration
+                .data::<ReflectResource>()
+                .ok_or_else(|| anyhow!("`{}` is not a reflected resource", type_path))?;
+            let reflected = reflect_resource
+                .reflect(world)
+                .ok_or_else(|| anyhow!("Resource `{}` not present in the world", type_path))?;
+            (reflected, WorldSource::Resource)
+        }
+    };
+
+    Ok(PreparedArg {
+        value: concrete(registration, reflected.clone_value()),
+        ownership,
+        source: Some((source, registration)),
+    })
+}
+
+/// Returns what an argument is taken from if it is marked with [`WORLD_ARG_KEY`].
+fn world_arg(value: &Value, arg_info: &ArgInfo) -> AnyhowResult<Option<WorldArg>> {
+    let Value::Object(object) = value else {
+        return Ok(None);
+    };
+    let (Some(world_arg), 1) = (object.get(WORLD_ARG_KEY), object.len()) else {
+        return Ok(None);
+    };
+    serde_json::from_value(world_arg.clone())
+        .map(Some)
+        .map_err(|_| {
+            anyhow!(
+                "Argument {} must be taken from a `component`, `resource` or `entity`, not {}",
+                arg_info.index(),
+                world_arg
+            )
+        })
+}
+
+/// Converts `value` into its concrete type if possible, since references must be downcast to the
+/// type of the parameter.
+fn concrete(
+    registration: &TypeRegistration,
+    value: Box<dyn PartialReflect>,
+) -> Box<dyn PartialReflect> {
+    registration
+        .data::<ReflectFromReflect>()
+        .and_then(|reflect_from_reflect| reflect_from_reflect.from_reflect(&*value))
+        .map_or(value, |value| value.into_partial_reflect())
+}
+
+fn serialize(value: &dyn PartialReflect, type_registry: &TypeRegistry) -> BrpResult {
+    if value.try_downcast_ref::<()>().is_some() {
+        return Ok(Value::Null);
+    }
+    serde_json::to_value(TypedReflectSerializer::new(value, type_registry))
+        .map_err(BrpError::function_error)
+}
+
+fn type_info(type_path: &str, ownership: Ownership) -> BrpFunctionTypeInfo {
+    BrpFunctionTypeInfo {
+        type_path: referent_type_path(type_path, ownership).to_owned(),
+        ownership: ownership.into(),
+    }
+}
+
+/// Strips the reference from the type path of a parameter or return value.
+fn referent_type_path(type_path: &str, ownership: Ownership) -> &str {
+    let prefix = match ownership {
+        Ownership::Owned => "",
+        Ownership::Ref => "&",
+        Ownership::Mut => "&mut ",
+    };
+    type_path.strip_prefix(prefix).unwrap_or(type_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_codes;
+    use bevy_ecs::{
+        component::Component,
+        system::{Resource, RunSystemOnce},
+    };
+    use bevy_reflect::{Reflect, TypePath};
+    use serde_json::json;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Difficulty(u32);
+
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    fn heal(health: &mut Health, amount: u32) {
+        health.0 += amount;
+    }
+
+    fn scaled(health: &Health, difficulty: &Difficulty) -> u32 {
+        health.0 * difficulty.0
+    }
+
+    fn or_default(value: Option<u32>) -> u32 {
+        value.unwrap_or_default()
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Health>();
+            type_registry.register::<Difficulty>();
+            type_registry.register::<Option<u32>>();
+        }
+        world.init_resource::<AppFunctionRegistry>();
+        {
+            let mut function_registry = world.resource::<AppFunctionRegistry>().write();
+            function_registry.register_with_name("add", add).unwrap();
+            function_registry.register_with_name("heal", heal).unwrap();
+            function_registry
+                .register_with_name("scaled", scaled)
+                .unwrap();
+            function_registry
+                .register_with_name("or_default", or_default)
+                .unwrap();
+        }
+        world
+    }
+
+    fn call(world: &mut World, params: Value) -> BrpResult {
+        world
+            .run_system_once_with(Some(params), process_remote_call_function_request)
+            .unwrap()
+    }
+
+    #[test]
+    fn list_functions() {
+        let mut world = world();
+        let response = world
+            .run_system_once_with(None, process_remote_list_functions_request)
+            .unwrap()
+            .unwrap();
+        let functions: BrpListFunctionsResponse = serde_json::from_value(response).unwrap();
+
+        let names: Vec<_> = functions.iter().map(|info| info.name.as_str()).collect();
+        assert_eq!(names, ["add", "heal", "or_default", "scaled"]);
+        assert_eq!(
+            functions[1].args[0].ty,
+            BrpFunctionTypeInfo {
+                type_path: Health::type_path().to_owned(),
+                ownership: BrpOwnership::Mut,
+            }
+        );
+        assert_eq!(functions[1].return_type.type_path, "()");
+    }
+
+    #[test]
+    fn call_with_owned_args() {
+        let mut world = world();
+        let response = call(&mut world, json!({ "function": "add", "args": [2, 3] }));
+        assert_eq!(response.unwrap(), json!(5));
+    }
+
+    #[test]
+    fn null_is_passed_as_a_value() {
+        let mut world = world();
+        let response = call(
+            &mut world,
+            json!({ "function": "or_default", "args": [null] }),
+        );
+        assert_eq!(response.unwrap(), json!(0));
+        let response = call(&mut world, json!({ "function": "or_default", "args": [4] }));
+        assert_eq!(response.unwrap(), json!(4));
+    }
+
+    #[test]
+    fn mut_arg_is_written_back_to_entity() {
+        let mut world = world();
+        let entity = world.spawn(Health(10)).id();
+        let other = world.spawn(Health(10)).id();
+
+        let response = call(
+            &mut world,
+            json!({ "function": "heal", "args": [{ "$world": "component" }, 5], "entity": entity }),
+        );
+        assert_eq!(response.unwrap(), Value::Null);
+        assert_eq!(world.get::<Health>(entity), Some(&Health(15)));
+        assert_eq!(world.get::<Health>(other), Some(&Health(10)));
+
+        // Explicit arguments are only passed to the function.
+        let response = call(
+            &mut world,
+            json!({ "function": "heal", "args": [1, 5], "entity": entity }),
+        );
+        assert_eq!(response.unwrap(), Value::Null);
+        assert_eq!(world.get::<Health>(entity), Some(&Health(15)));
+    }
+
+    #[test]
+    fn ref_args_are_read_from_the_world() {
+        let mut world = world();
+        world.insert_resource(Difficulty(3));
+        let entity = world.spawn(Health(7)).id();
+
+        let response = call(
+            &mut world,
+            json!({
+                "function": "scaled",
+                "args": [{ "$world": "component" }, { "$world": "resource" }],
+                "entity": entity,
+            }),
+        );
+        assert_eq!(response.unwrap(), json!(21));
+    }
+
+    #[test]
+    fn argument_errors() {
+        let mut world = world();
+        let entity = world.spawn_empty().id();
+
+        let error = call(&mut world, json!({ "function": "add", "args": [2] })).unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_ERROR);
+        assert_eq!(error.message, "Missing argument 1");
+
+        let error = call(&mut world, json!({ "function": "add", "args": [2, 3, 4] })).unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_ERROR);
+        assert_eq!(error.message, "Expected at most 2 arguments, received 3");
+
+        for params in [
+            // Argument of the wrong type.
+            json!({ "function": "add", "args": [2, "three"] }),
+            // Null isn't a `Health`.
+            json!({ "function": "heal", "args": [null, 5], "entity": entity }),
+            // No entity to read the component from.
+            json!({ "function": "heal", "args": [{ "$world": "component" }, 5] }),
+            // The entity doesn't have the component.
+            json!({ "function": "heal", "args": [{ "$world": "component" }, 5], "entity": entity }),
+            // `Health` isn't a resource.
+            json!({ "function": "heal", "args": [{ "$world": "resource" }, 5], "entity": entity }),
+            // The resource isn't in the world.
+            json!({ "function": "scaled", "args": [1, { "$world": "resource" }] }),
+            // `Health` isn't an `Entity`.
+            json!({ "function": "heal", "args": [{ "$world": "entity" }, 5], "entity": entity }),
+            // Unknown source.
+            json!({ "function": "heal", "args": [{ "$world": "asset" }, 5], "entity": entity }),
+        ] {
+            let error = call(&mut world, params.clone()).unwrap_err();
+            assert_eq!(error.code, error_codes::FUNCTION_ERROR, "{params}");
+        }
+
+        let error = call(&mut world, json!({ "function": "missing" })).unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_NOT_FOUND);
+    }
+}
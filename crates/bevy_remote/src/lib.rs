@@ -6,6 +6,12 @@
 //! over HTTP. These *remote clients* can inspect and alter the state of the
 //! entity-component system.
 //!
+//! Other transports are available behind their own features: the `websocket` feature provides
+//! the [`RemoteWebSocketPlugin`](websocket::RemoteWebSocketPlugin), which multiplexes requests and
+//! watching requests over a single connection, and the `stdio` feature provides the
+//! [`RemoteStdioPlugin`](stdio::RemoteStdioPlugin), for tools that launch the app as a child
+//! process and talk to it through its standard input and output.
+//!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
 //! ## Request objects
@@ -189,6 +195,19 @@
 //!
 //! `result`: null.
 //!
+//! ### `bevy/mutate_component`
+//!
+//! Replace a single field of a component on an entity, leaving the rest of its value untouched.
+//! The component is marked as changed.
+//!
+//! `params`:
+//! - `entity`: The ID of the entity whose component will be mutated.
+//! - `component`: The [fully-qualified type name] of the component to mutate.
+//! - `path`: The [reflect path] of the field within the component: e.g. `.translation.y`.
+//! - `value`: The new value of the field.
+//!
+//! `result`: null.
+//!
 //! ### bevy/reparent
 //!
 //! Assign a new parent to one or more entities.
@@ -256,6 +275,220 @@
 //! - `removed`: An array of fully-qualified type names of components removed from the entity
 //!   in the last tick.
 //!
+//! ### `bevy/unwatch`
+//!
+//! Stop a watching request made earlier on the same connection. This method is only available on
+//! transports that keep a connection open, like WebSocket and stdio; over HTTP, closing the
+//! response stream stops the watching request.
+//!
+//! `params`:
+//! - `id`: The `id` of the watching request to stop.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/get_resource`
+//!
+//! Retrieve the value of a resource.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to fetch.
+//!
+//! `result`:
+//! - `value`: The value of the resource.
+//!
+//! ### `bevy/insert_resource`
+//!
+//! Insert a resource, replacing its previous value if it exists.
+//!
+//! `params`:
+//! - `resource`: The fully-qualified type name of the resource to insert.
+//! - `value`: The value of the resource.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/mutate_resource`
+//!
+//! Replace a single field of a resource, leaving the rest of its value untouched.
+//!
+//! `params`:
+//! - `resource`: The fully-qualified type name of the resource to mutate.
+//! - `path`: The [reflect path] of the field within the resource: e.g. `.speed`.
+//! - `value`: The new value of the field.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/remove_resource`
+//!
+//! Remove a resource.
+//!
+//! `params`:
+//! - `resource`: The fully-qualified type name of the resource to remove.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/list_resources`
+//!
+//! List all registered resources.
+//!
+//! `result`: An array of fully-qualified type names of resources.
+//!
+//! ### `bevy/send_event`
+//!
+//! Send an event, to be read by the `EventReader`s of its type. The event type must register
+//! `ReflectEvent` with `#[reflect(Event)]`, and must have been added to the app.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to send.
+//! - `value`: The serialized value of the event.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/trigger_event`
+//!
+//! Trigger an event for the observers watching it. The event type must register `ReflectEvent`
+//! with `#[reflect(Event)]`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to trigger.
+//! - `value`: The serialized value of the event.
+//! - `targets` (optional): An array of the IDs of the entities to trigger the event on. If
+//!   omitted or empty, only the global observers are triggered.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/event+watch`
+//!
+//! Watch the events of a type, as they are sent. The event type must register `ReflectEvent`
+//! with `#[reflect(Event)]`, and must have been added to the app. Only events sent after the
+//! request are streamed. Every request receives all the events, even when several requests
+//! watch the same type.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to watch.
+//!
+//! `result`:
+//! - `events`: An array of the serialized values of the events sent since the last response,
+//!   oldest first.
+//!
+//! ### `bevy/registry/schema`
+//!
+//! Retrieve the [JSON Schema] of the registered types, describing their serialized values.
+//! Types are referenced by their fully-qualified type name through `$ref`, relative to
+//! `#/$defs/`. With the `documentation` feature, the schemas include the doc comments of the types.
+//!
+//! `params` (optional):
+//! - `with_crates` (optional): An array of crate names; only types from these crates are included.
+//! - `without_crates` (optional): An array of crate names whose types are excluded.
+//! - `with_types` (optional): An array of reflected traits among `Component`, `Resource`,
+//!   `Event`, `Default`, `Serialize` and `Deserialize`; only types registering all of them are
+//!   included.
+//! - `without_types` (optional): An array of reflected traits; types registering any of them
+//!   are excluded.
+//!
+//! `result`: A map associating each fully-qualified type name to its schema. See
+//! [`JsonSchemaBevyType`](schemas::json_schema::JsonSchemaBevyType).
+//!
+//! ### `bevy/list_functions`
+//!
+//! List the functions registered in the `AppFunctionRegistry` with their signatures. This method
+//! requires the `reflect_functions` feature.
+//!
+//! `result`: An array of objects with:
+//! - `name`: The name the function is registered under.
+//! - `args`: An array describing each parameter in order, with its `name` if known, the
+//!   fully-qualified `type_path` of its type without the reference, and its `ownership`: one of
+//!   `owned`, `ref` and `mut`.
+//! - `return_type`: The `type_path` and `ownership` of the return type.
+//!
+//! ### `bevy/call_function`
+//!
+//! Call a function registered in the `AppFunctionRegistry`. This method requires the
+//! `reflect_functions` feature.
+//!
+//! `params`:
+//! - `function`: The name of the function.
+//! - `args` (optional): An array of the values of the arguments, in order. An argument of the
+//!   form `{"$world": "component"}` is the component of `entity` of the parameter's type,
+//!   `{"$world": "resource"}` is the resource of the parameter's type, and
+//!   `{"$world": "entity"}` is `entity`. Changes made through mutable references to components
+//!   and resources are applied back to the world. Other values, including null, are passed as
+//!   they are.
+//! - `entity` (optional): The ID of the entity that arguments taken from the world refer to.
+//!
+//! `result`: The serialized return value of the function, or null if it returns nothing.
+//!
+//! ### `bevy/stepping/enable`
+//!
+//! Enable [`Stepping`] for one or more schedules. Stepping starts at the beginning of the next
+//! frame, and requires Bevy to be compiled with the `bevy_debug_stepping` feature: otherwise the
+//! request fails with a `STEPPING_UNAVAILABLE` error. The systems processing remote requests keep
+//! running in stepped schedules.
+//!
+//! `params` (optional):
+//! - `schedules` (optional): An array of schedule names, as printed by their `Debug`
+//!   implementation: e.g. `Update`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/disable`
+//!
+//! Disable stepping, running all systems normally from the next frame onwards.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/step_system`
+//!
+//! Run the next system of the stepping frame during the next frame.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/step_frame`
+//!
+//! Run the remaining systems of the stepping frame during the next frame, stopping at the
+//! first breakpoint.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/continue`
+//!
+//! Run frames normally until a system with a breakpoint is reached.
+//!
+//! As with [`Stepping::continue_frame`], the system the stepping cursor points at runs even if it
+//! has a breakpoint. Since each frame starts with the cursor on the first stepped system, a
+//! breakpoint on that system doesn't stop the frames that follow.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/set_breakpoint`
+//!
+//! Set a breakpoint on the systems of a stepped schedule matching a name.
+//!
+//! `params`:
+//! - `schedule`: The name of the schedule containing the system: e.g. `Update`.
+//! - `system`: The full name of the system, or a suffix of it starting after a `::`: e.g.
+//!   `apply_velocity`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/clear_breakpoint`
+//!
+//! Clear the breakpoint of the systems of a stepped schedule matching a name.
+//!
+//! `params`:
+//! - `schedule`: The name of the schedule containing the system: e.g. `Update`.
+//! - `system`: The full name of the system, or a suffix of it starting after a `::`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/cursor`
+//!
+//! Report the next system to run in the stepping frame.
+//!
+//! `result`:
+//! - `enabled`: Whether stepping is enabled.
+//! - `schedule`: The name of the schedule containing the next system, or null.
+//! - `system`: The full name of the next system, or null if the stepping frame is over.
+//!
 //!
 //! ## Custom methods
 //!
@@ -296,15 +529,27 @@
 //! Arbitrary system parameters can be used in conjunction with the optional `Value` input. The
 //! handler system will always run with exclusive `World` access.
 //!
+//! Watching handlers that keep state for each request, such as the event cursor of
+//! `bevy/event+watch`, can be added with [`RemotePlugin::with_watching_method_with_id`], which also
+//! passes the [`RemoteWatchingRequestId`] of the request. The state can be dropped by an observer of
+//! [`RemoteWatchingRequestClosed`].
+//!
 //! [the `serde` documentation]: https://serde.rs/
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
+//! [reflect path]: bevy_reflect::GetPath
+//! [JSON Schema]: https://json-schema.org/specification
+//! [`Stepping`]: bevy_ecs::schedule::Stepping
+//! [`Stepping::continue_frame`]: bevy_ecs::schedule::Stepping::continue_frame
+
+extern crate alloc;
 
 use async_channel::{Receiver, Sender};
 use bevy_app::prelude::*;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     entity::Entity,
+    event::Event,
     schedule::IntoSystemConfigs,
     system::{Commands, In, IntoSystem, ResMut, Resource, System, SystemId},
     world::World,
@@ -315,8 +560,18 @@ use serde_json::Value;
 use std::sync::RwLock;
 
 pub mod builtin_methods;
+#[cfg(feature = "reflect_functions")]
+pub mod functions;
 #[cfg(feature = "http")]
 pub mod http;
+pub mod schemas;
+#[cfg(any(feature = "websocket", feature = "stdio"))]
+pub mod session;
+#[cfg(feature = "stdio")]
+pub mod stdio;
+pub mod stepping;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -357,9 +612,28 @@ impl RemotePlugin {
     /// Add a remote method with a watching handler to the plugin using the given `name`.
     #[must_use]
     pub fn with_watching_method<M>(
-        mut self,
+        self,
         name: impl Into<String>,
         handler: impl IntoSystem<In<Option<Value>>, BrpResult<Option<Value>>, M>,
+    ) -> Self {
+        let without_id = |In((_, params)): In<(RemoteWatchingRequestId, Option<Value>)>| params;
+        self.with_watching_method_with_id(name, without_id.pipe(handler))
+    }
+
+    /// Add a remote method with a watching handler to the plugin using the given `name`.
+    ///
+    /// Unlike [`with_watching_method`](Self::with_watching_method), the handler also receives the
+    /// [`RemoteWatchingRequestId`] of the request it processes, so that it can keep state for each
+    /// request. Observe [`RemoteWatchingRequestClosed`] to drop that state.
+    #[must_use]
+    pub fn with_watching_method_with_id<M>(
+        mut self,
+        name: impl Into<String>,
+        handler: impl IntoSystem<
+            In<(RemoteWatchingRequestId, Option<Value>)>,
+            BrpResult<Option<Value>>,
+            M,
+        >,
     ) -> Self {
         self.methods.get_mut().unwrap().push((
             name.into(),
@@ -371,7 +645,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -392,6 +666,10 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_REMOVE_METHOD,
                 builtin_methods::process_remote_remove_request,
             )
+            .with_method(
+                builtin_methods::BRP_MUTATE_COMPONENT_METHOD,
+                builtin_methods::process_remote_mutate_component_request,
+            )
             .with_method(
                 builtin_methods::BRP_DESTROY_METHOD,
                 builtin_methods::process_remote_destroy_request,
@@ -404,6 +682,38 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_METHOD,
                 builtin_methods::process_remote_list_request,
             )
+            .with_method(
+                builtin_methods::BRP_GET_RESOURCE_METHOD,
+                builtin_methods::process_remote_get_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_INSERT_RESOURCE_METHOD,
+                builtin_methods::process_remote_insert_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_MUTATE_RESOURCE_METHOD,
+                builtin_methods::process_remote_mutate_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REMOVE_RESOURCE_METHOD,
+                builtin_methods::process_remote_remove_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_RESOURCES_METHOD,
+                builtin_methods::process_remote_list_resources_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SEND_EVENT_METHOD,
+                builtin_methods::process_remote_send_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_TRIGGER_EVENT_METHOD,
+                builtin_methods::process_remote_trigger_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+                builtin_methods::process_remote_registry_schema_request,
+            )
             .with_watching_method(
                 builtin_methods::BRP_GET_AND_WATCH_METHOD,
                 builtin_methods::process_remote_get_watching_request,
@@ -412,6 +722,55 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_watching_method_with_id(
+                builtin_methods::BRP_EVENT_AND_WATCH_METHOD,
+                builtin_methods::process_remote_event_watching_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_ENABLE_METHOD,
+                stepping::process_remote_stepping_enable_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_DISABLE_METHOD,
+                stepping::process_remote_stepping_disable_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_STEP_SYSTEM_METHOD,
+                stepping::process_remote_stepping_step_system_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_STEP_FRAME_METHOD,
+                stepping::process_remote_stepping_step_frame_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_CONTINUE_METHOD,
+                stepping::process_remote_stepping_continue_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_SET_BREAKPOINT_METHOD,
+                stepping::process_remote_stepping_set_breakpoint_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_CLEAR_BREAKPOINT_METHOD,
+                stepping::process_remote_stepping_clear_breakpoint_request,
+            )
+            .with_method(
+                stepping::BRP_STEPPING_CURSOR_METHOD,
+                stepping::process_remote_stepping_cursor_request,
+            );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin
+            .with_method(
+                functions::BRP_LIST_FUNCTIONS_METHOD,
+                functions::process_remote_list_functions_request,
+            )
+            .with_method(
+                functions::BRP_CALL_FUNCTION_METHOD,
+                functions::process_remote_call_function_request,
+            );
+
+        plugin
     }
 }
 
@@ -436,7 +795,24 @@ impl Plugin for RemotePlugin {
 
         app.insert_resource(remote_methods)
             .init_resource::<RemoteWatchingRequests>()
+            .init_resource::<builtin_methods::EventWatchingCursors>()
+            .add_observer(builtin_methods::remove_event_watching_cursor)
+            .init_resource::<stepping::RemoteSteppingState>()
             .add_systems(PreStartup, setup_mailbox_channel)
+            .add_systems(
+                First,
+                (
+                    stepping::cache_stepping_systems,
+                    stepping::begin_continue_frame,
+                ),
+            )
+            .add_systems(
+                Last,
+                (
+                    stepping::cache_stepping_systems,
+                    stepping::end_continue_frame,
+                ),
+            )
             .add_systems(
                 Update,
                 (
@@ -455,7 +831,14 @@ pub enum RemoteMethodHandler {
     /// A handler that only runs once and returns one response.
     Instant(Box<dyn System<In = In<Option<Value>>, Out = BrpResult>>),
     /// A handler that watches for changes and response when a change is detected.
-    Watching(Box<dyn System<In = In<Option<Value>>, Out = BrpResult<Option<Value>>>>),
+    Watching(
+        Box<
+            dyn System<
+                In = In<(RemoteWatchingRequestId, Option<Value>)>,
+                Out = BrpResult<Option<Value>>,
+            >,
+        >,
+    ),
 }
 
 /// The [`SystemId`] of a function that implements a remote instant method (`bevy/get`, `bevy/query`, etc.)
@@ -469,13 +852,14 @@ pub type RemoteInstantMethodSystemId = SystemId<In<Option<Value>>, BrpResult>;
 
 /// The [`SystemId`] of a function that implements a remote watching method (`bevy/get+watch`, `bevy/list+watch`, etc.)
 ///
-/// The first parameter is the JSON value of the `params`. Typically, an
-/// implementation will deserialize these as the first thing they do.
+/// The first parameter is the id of the request along with the JSON value of the `params`.
+/// Typically, an implementation will deserialize these as the first thing they do.
 ///
 /// The optional returned JSON value will be sent as a response. If no
 /// changes were detected this should be [`None`]. Re-running of this
 /// handler is done in the [`RemotePlugin`].
-pub type RemoteWatchingMethodSystemId = SystemId<In<Option<Value>>, BrpResult<Option<Value>>>;
+pub type RemoteWatchingMethodSystemId =
+    SystemId<In<(RemoteWatchingRequestId, Option<Value>)>, BrpResult<Option<Value>>>;
 
 /// The [`SystemId`] of a function that can be used as a remote method.
 #[derive(Debug, Clone, Copy)]
@@ -517,7 +901,26 @@ impl RemoteMethods {
 
 /// Holds the [`BrpMessage`]'s of all ongoing watching requests along with their handlers.
 #[derive(Debug, Resource, Default)]
-pub struct RemoteWatchingRequests(Vec<(BrpMessage, RemoteWatchingMethodSystemId)>);
+pub struct RemoteWatchingRequests {
+    requests: Vec<(
+        RemoteWatchingRequestId,
+        BrpMessage,
+        RemoteWatchingMethodSystemId,
+    )>,
+    next_id: u64,
+}
+
+/// Identifies an ongoing watching request.
+///
+/// Handlers added with [`RemotePlugin::with_watching_method_with_id`] receive the id of the
+/// request they process. Handlers that keep state across frames should key it by this id rather
+/// than by their params, since several requests may watch the same thing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RemoteWatchingRequestId(u64);
+
+/// Triggered when a watching request is closed, so that the state kept for it can be dropped.
+#[derive(Debug, Event, Clone, Copy)]
+pub struct RemoteWatchingRequestClosed(pub RemoteWatchingRequestId);
 
 /// A single request from a Bevy Remote Protocol client to the server,
 /// serialized in JSON.
@@ -665,6 +1068,108 @@ impl BrpError {
             data: None,
         }
     }
+
+    /// Schedule wasn't found.
+    #[must_use]
+    pub fn schedule_not_found(schedule: &str) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_NOT_FOUND,
+            message: format!("Schedule `{schedule}` not found"),
+            data: None,
+        }
+    }
+
+    /// System wasn't found in a schedule.
+    #[must_use]
+    pub fn system_not_found(system: &str, schedule: &str) -> Self {
+        Self {
+            code: error_codes::SYSTEM_NOT_FOUND,
+            message: format!("System `{system}` not found in Schedule `{schedule}`"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary resource error. Possibly related to reflection.
+    #[must_use]
+    pub fn resource_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::RESOURCE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// Resource wasn't found in the world.
+    #[must_use]
+    pub fn resource_not_present(resource: &str) -> Self {
+        Self {
+            code: error_codes::RESOURCE_NOT_PRESENT,
+            message: format!("Resource `{resource}` not present in the world"),
+            data: None,
+        }
+    }
+
+    /// Function wasn't found in the `AppFunctionRegistry`.
+    #[must_use]
+    pub fn function_not_found(function: &str) -> Self {
+        Self {
+            code: error_codes::FUNCTION_NOT_FOUND,
+            message: format!("Function `{function}` not registered"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary error while calling a function. Possibly related to its arguments.
+    #[must_use]
+    pub fn function_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::FUNCTION_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// An arbitrary event error. Possibly related to reflection.
+    #[must_use]
+    pub fn event_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::EVENT_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// The [`Events`](bevy_ecs::event::Events) resource of an event type wasn't found in the world.
+    #[must_use]
+    pub fn event_not_registered(event: &str) -> Self {
+        Self {
+            code: error_codes::EVENT_NOT_REGISTERED,
+            message: format!("Event `{event}` not added to the app"),
+            data: None,
+        }
+    }
+
+    /// Stepping was controlled before being enabled.
+    #[must_use]
+    pub fn stepping_not_enabled() -> Self {
+        Self {
+            code: error_codes::STEPPING_NOT_ENABLED,
+            message: String::from("Stepping is not enabled"),
+            data: None,
+        }
+    }
+
+    /// Bevy was compiled without the `bevy_debug_stepping` feature.
+    #[must_use]
+    pub fn stepping_unavailable() -> Self {
+        Self {
+            code: error_codes::STEPPING_UNAVAILABLE,
+            message: String::from(
+                "Stepping is unavailable: Bevy was compiled without the `bevy_debug_stepping` feature",
+            ),
+            data: None,
+        }
+    }
 }
 
 /// Error codes used by BRP.
@@ -700,6 +1205,37 @@ pub mod error_codes {
 
     /// Cannot reparent an entity to itself.
     pub const SELF_REPARENT: i16 = -23404;
+
+    /// Could not find schedule.
+    pub const SCHEDULE_NOT_FOUND: i16 = -23405;
+
+    /// Could not find system in schedule.
+    pub const SYSTEM_NOT_FOUND: i16 = -23406;
+
+    /// Stepping has not been enabled with `bevy/stepping/enable`.
+    pub const STEPPING_NOT_ENABLED: i16 = -23407;
+
+    /// Could not reflect or find resource.
+    pub const RESOURCE_ERROR: i16 = -23408;
+
+    /// Could not find resource in the world.
+    pub const RESOURCE_NOT_PRESENT: i16 = -23409;
+
+    /// Could not find function in the function registry.
+    pub const FUNCTION_NOT_FOUND: i16 = -23410;
+
+    /// Could not prepare the arguments of a function, or the call failed.
+    pub const FUNCTION_ERROR: i16 = -23411;
+
+    /// Could not reflect or find event.
+    pub const EVENT_ERROR: i16 = -23412;
+
+    /// Could not find the `Events` resource of an event type in the world.
+    pub const EVENT_NOT_REGISTERED: i16 = -23413;
+
+    /// Stepping can't be enabled because Bevy was compiled without the `bevy_debug_stepping`
+    /// feature.
+    pub const STEPPING_UNAVAILABLE: i16 = -23414;
 }
 
 /// The result of a request.
@@ -757,7 +1293,7 @@ fn setup_mailbox_channel(mut commands: Commands) {
 ///
 /// This needs exclusive access to the [`World`] because clients can manipulate
 /// anything in the ECS.
-fn process_remote_requests(world: &mut World) {
+pub(crate) fn process_remote_requests(world: &mut World) {
     if !world.contains_resource::<BrpReceiver>() {
         return;
     }
@@ -791,10 +1327,10 @@ fn process_remote_requests(world: &mut World) {
                 let _ = message.sender.force_send(result);
             }
             RemoteMethodSystemId::Watching(id) => {
-                world
-                    .resource_mut::<RemoteWatchingRequests>()
-                    .0
-                    .push((message, id));
+                let mut requests = world.resource_mut::<RemoteWatchingRequests>();
+                let request_id = RemoteWatchingRequestId(requests.next_id);
+                requests.next_id += 1;
+                requests.requests.push((request_id, message, id));
             }
         }
     }
@@ -802,10 +1338,11 @@ fn process_remote_requests(world: &mut World) {
 
 /// A system that checks all ongoing watching requests for changes that should be sent
 /// and handles it if so.
-fn process_ongoing_watching_requests(world: &mut World) {
+pub(crate) fn process_ongoing_watching_requests(world: &mut World) {
     world.resource_scope::<RemoteWatchingRequests, ()>(|world, requests| {
-        for (message, system_id) in requests.0.iter() {
-            let handler_result = process_single_ongoing_watching_request(world, message, system_id);
+        for (request_id, message, system_id) in requests.requests.iter() {
+            let handler_result =
+                process_single_ongoing_watching_request(world, *request_id, message, system_id);
             let sender_result = match handler_result {
                 Ok(Some(value)) => message.sender.try_send(Ok(value)),
                 Err(err) => message.sender.try_send(Err(err)),
@@ -822,11 +1359,12 @@ fn process_ongoing_watching_requests(world: &mut World) {
 
 fn process_single_ongoing_watching_request(
     world: &mut World,
+    request_id: RemoteWatchingRequestId,
     message: &BrpMessage,
     system_id: &RemoteWatchingMethodSystemId,
 ) -> BrpResult<Option<Value>> {
     world
-        .run_system_with_input(*system_id, message.params.clone())
+        .run_system_with_input(*system_id, (request_id, message.params.clone()))
         .map_err(|error| BrpError {
             code: error_codes::INTERNAL_ERROR,
             message: format!("Failed to run method handler: {error}"),
@@ -834,14 +1372,18 @@ fn process_single_ongoing_watching_request(
         })?
 }
 
-fn remove_closed_watching_requests(mut requests: ResMut<RemoteWatchingRequests>) {
-    for i in (0..requests.0.len()).rev() {
-        let Some((message, _)) = requests.0.get(i) else {
+pub(crate) fn remove_closed_watching_requests(
+    mut requests: ResMut<RemoteWatchingRequests>,
+    mut commands: Commands,
+) {
+    for i in (0..requests.requests.len()).rev() {
+        let Some((request_id, message, _)) = requests.requests.get(i) else {
             unreachable!()
         };
 
         if message.sender.is_closed() {
-            requests.0.swap_remove(i);
+            commands.trigger(RemoteWatchingRequestClosed(*request_id));
+            requests.requests.swap_remove(i);
         }
     }
 }
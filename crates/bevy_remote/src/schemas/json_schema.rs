@@ -0,0 +1,594 @@
+//! Conversion of reflected types into [JSON Schema] documents.
+//!
+//! The schemas describe the JSON representation produced by the reflection serializer of
+//! `bevy_reflect`, which is what the Bevy Remote Protocol sends and expects. Types registered
+//! with [`ReflectSerialize`] use their own `serde` implementation instead, so their actual
+//! representation may differ from the one described by their [`TypeInfo`]; such types list
+//! `Serialize` in their [`reflect_types`](JsonSchemaBevyType::reflect_types).
+//!
+//! Other types are referenced by their [type path] through `$ref`, relative to `#/$defs/`. A
+//! complete document can be built by placing the exported schemas under `$defs`.
+//!
+//! [JSON Schema]: https://json-schema.org/specification
+//! [type path]: bevy_reflect::TypePath::type_path
+
+use alloc::borrow::Cow;
+use core::any::TypeId;
+use std::{ffi::OsString, path::PathBuf};
+
+use bevy_ecs::reflect::{ReflectComponent, ReflectEvent, ReflectResource};
+use bevy_reflect::{
+    prelude::ReflectDefault, serde::TypedReflectSerializer, EnumInfo, FieldMetadata, NamedField,
+    ReflectDeserialize, ReflectSerialize, Type, TypeInfo, TypeRegistration, TypeRegistry,
+    UnnamedField, VariantInfo,
+};
+use bevy_utils::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// The JSON Schema of a reflected type, extended with information about the type itself.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaBevyType {
+    /// The [short path](bevy_reflect::TypePath::short_type_path) of the type.
+    pub short_path: String,
+    /// The [full path](bevy_reflect::TypePath::type_path) of the type.
+    pub type_path: String,
+    /// The module containing the type, if known.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub module_path: Option<String>,
+    /// The crate containing the type, if known.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub crate_name: Option<String>,
+    /// The type data registered for the type, among `Component`, `Resource`, `Event`,
+    /// `Default`, `Serialize` and `Deserialize`.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reflect_types: Vec<String>,
+    /// The reflection kind of the type.
+    pub kind: SchemaKind,
+    /// The JSON type of the serialized value, if it is restricted to a single one.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    pub schema_type: Option<SchemaType>,
+    /// The schema of the keys of a map.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub key_type: Option<Value>,
+    /// The schema of the values of a map.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub value_type: Option<Value>,
+    /// The schemas of the named fields of an object.
+    #[serde(skip_serializing_if = "Map::is_empty", default)]
+    pub properties: Map<String, Value>,
+    /// The names of the fields an object must contain.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub required: Vec<String>,
+    /// Whether an object may contain other fields, or the schema of those fields.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub additional_properties: Option<Value>,
+    /// The schemas of the elements of an array, by position.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub prefix_items: Vec<Value>,
+    /// The schema of the elements of an array after [`prefix_items`](Self::prefix_items), or
+    /// `false` if there can't be any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub items: Option<Value>,
+    /// The minimum length of an array.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub min_items: Option<usize>,
+    /// The maximum length of an array.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_items: Option<usize>,
+    /// The schemas of which exactly one must match, used for enums.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub one_of: Vec<Value>,
+    /// The documentation of the type, with the `documentation` feature.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+}
+
+/// The reflection kind of a type, see [`ReflectKind`](bevy_reflect::ReflectKind).
+///
+/// Options are reported separately from other enums, as they serialize to their value or null.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchemaKind {
+    /// A struct with named fields.
+    #[default]
+    Struct,
+    /// A struct with unnamed fields.
+    TupleStruct,
+    /// A tuple.
+    Tuple,
+    /// A list.
+    List,
+    /// A fixed-size array.
+    Array,
+    /// A map.
+    Map,
+    /// A set.
+    Set,
+    /// An enum.
+    Enum,
+    /// An [`Option`].
+    Option,
+    /// A type serialized through its own `serde` implementation.
+    Opaque,
+}
+
+/// The type of a JSON value.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaType {
+    /// A JSON object.
+    Object,
+    /// A JSON array.
+    Array,
+    /// A JSON string.
+    String,
+    /// A JSON number without a fractional part.
+    Integer,
+    /// A JSON number.
+    Number,
+    /// A JSON boolean.
+    Boolean,
+    /// The JSON null value.
+    Null,
+}
+
+/// Exports the JSON Schema of a registered type, returning it along with the type path.
+///
+/// The `registry` is used to serialize the default values of fields.
+pub fn export_type(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> (&'static str, JsonSchemaBevyType) {
+    let info = registration.type_info();
+    let path_table = info.type_path_table();
+
+    let mut schema = JsonSchemaBevyType {
+        short_path: path_table.short_path().to_owned(),
+        type_path: path_table.path().to_owned(),
+        module_path: path_table.module_path().map(str::to_owned),
+        crate_name: path_table.crate_name().map(str::to_owned),
+        reflect_types: reflect_types(registration),
+        #[cfg(feature = "documentation")]
+        description: info.docs().map(str::to_owned),
+        ..Default::default()
+    };
+
+    match info {
+        TypeInfo::Struct(info) => {
+            schema.kind = SchemaKind::Struct;
+            schema.schema_type = Some(SchemaType::Object);
+            schema.additional_properties = Some(Value::Bool(false));
+            for field in info.iter() {
+                schema
+                    .properties
+                    .insert(field.name().to_owned(), named_field_ref(field, registry));
+                schema.required.push(field.name().to_owned());
+            }
+        }
+        TypeInfo::TupleStruct(info) => {
+            schema.kind = SchemaKind::TupleStruct;
+            // Tuple structs with a single field serialize to the value of that field.
+            if info.field_len() == 1 {
+                schema
+                    .one_of
+                    .push(unnamed_field_ref(info.field_at(0).unwrap(), registry));
+            } else {
+                set_tuple_items(&mut schema, info.iter(), registry);
+            }
+        }
+        TypeInfo::Tuple(info) => {
+            schema.kind = SchemaKind::Tuple;
+            set_tuple_items(&mut schema, info.iter(), registry);
+        }
+        TypeInfo::List(info) => {
+            schema.kind = SchemaKind::List;
+            schema.schema_type = Some(SchemaType::Array);
+            schema.items = Some(type_ref(&info.item_ty()));
+        }
+        TypeInfo::Array(info) => {
+            schema.kind = SchemaKind::Array;
+            schema.schema_type = Some(SchemaType::Array);
+            schema.items = Some(type_ref(&info.item_ty()));
+            schema.min_items = Some(info.capacity());
+            schema.max_items = Some(info.capacity());
+        }
+        TypeInfo::Map(info) => {
+            schema.kind = SchemaKind::Map;
+            schema.schema_type = Some(SchemaType::Object);
+            schema.key_type = Some(type_ref(&info.key_ty()));
+            schema.value_type = Some(type_ref(&info.value_ty()));
+            schema.additional_properties = Some(type_ref(&info.value_ty()));
+        }
+        TypeInfo::Set(info) => {
+            schema.kind = SchemaKind::Set;
+            schema.schema_type = Some(SchemaType::Array);
+            schema.items = Some(type_ref(&info.value_ty()));
+        }
+        TypeInfo::Enum(info) if is_option(info) => {
+            schema.kind = SchemaKind::Option;
+            schema.one_of.push(json!({ "type": SchemaType::Null }));
+            if let Some(VariantInfo::Tuple(some)) = info.variant("Some") {
+                schema
+                    .one_of
+                    .push(unnamed_field_ref(some.field_at(0).unwrap(), registry));
+            }
+        }
+        TypeInfo::Enum(info) => {
+            schema.kind = SchemaKind::Enum;
+            schema.one_of = info
+                .iter()
+                .map(|variant| variant_schema(variant, registry))
+                .collect();
+        }
+        TypeInfo::Opaque(info) => {
+            schema.kind = SchemaKind::Opaque;
+            schema.schema_type = primitive_type(info.type_id());
+        }
+    }
+
+    (info.type_path(), schema)
+}
+
+/// Exports the JSON Schemas of all the given registered types, keyed by type path.
+pub fn export_types<'a>(
+    registrations: impl IntoIterator<Item = &'a TypeRegistration>,
+    registry: &TypeRegistry,
+) -> HashMap<String, JsonSchemaBevyType> {
+    registrations
+        .into_iter()
+        .map(|registration| {
+            let (type_path, schema) = export_type(registration, registry);
+            (type_path.to_owned(), schema)
+        })
+        .collect()
+}
+
+/// Returns the names of the type data registered for a type that are relevant to clients.
+pub(crate) fn reflect_types(registration: &TypeRegistration) -> Vec<String> {
+    [
+        ("Component", registration.contains::<ReflectComponent>()),
+        ("Resource", registration.contains::<ReflectResource>()),
+        ("Event", registration.contains::<ReflectEvent>()),
+        ("Default", registration.contains::<ReflectDefault>()),
+        ("Serialize", registration.contains::<ReflectSerialize>()),
+        ("Deserialize", registration.contains::<ReflectDeserialize>()),
+    ]
+    .into_iter()
+    .filter(|(_, registered)| *registered)
+    .map(|(name, _)| name.to_owned())
+    .collect()
+}
+
+/// Returns a schema referring to the schema of `ty`.
+fn type_ref(ty: &Type) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", ty.path()) })
+}
+
+fn named_field_ref(field: &NamedField, registry: &TypeRegistry) -> Value {
+    let mut schema = type_ref(field.ty());
+    #[cfg(feature = "documentation")]
+    add_description(&mut schema, field.docs());
+    add_field_metadata(&mut schema, field.metadata(), *field.ty(), registry);
+    schema
+}
+
+fn unnamed_field_ref(field: &UnnamedField, registry: &TypeRegistry) -> Value {
+    let mut schema = type_ref(field.ty());
+    #[cfg(feature = "documentation")]
+    add_description(&mut schema, field.docs());
+    add_field_metadata(&mut schema, field.metadata(), *field.ty(), registry);
+    schema
+}
+
+/// Adds the JSON Schema keywords matching the well-known [`FieldMetadata`] of a field of type
+/// `ty`.
+///
+/// Hidden fields are marked with the non-standard `hidden` keyword. The default value is omitted
+/// if it can't be created or serialized with the `registry`.
+fn add_field_metadata(
+    schema: &mut Value,
+    metadata: &FieldMetadata,
+    ty: Type,
+    registry: &TypeRegistry,
+) {
+    let Value::Object(schema) = schema else {
+        return;
+    };
+    if let Some(display_name) = metadata.display_name() {
+        schema.insert("title".to_owned(), Value::String(display_name.to_owned()));
+    }
+    if let Some(min) = metadata.min() {
+        schema.insert("minimum".to_owned(), json!(min));
+    }
+    if let Some(max) = metadata.max() {
+        schema.insert("maximum".to_owned(), json!(max));
+    }
+    if let Some(step) = metadata.step().filter(|step| *step > 0.0) {
+        schema.insert("multipleOf".to_owned(), json!(step));
+    }
+    if metadata.is_read_only() {
+        schema.insert("readOnly".to_owned(), Value::Bool(true));
+    }
+    if metadata.is_hidden() {
+        schema.insert("hidden".to_owned(), Value::Bool(true));
+    }
+    let default = metadata.default_value(ty, registry).and_then(|value| {
+        serde_json::to_value(TypedReflectSerializer::new(&*value, registry)).ok()
+    });
+    if let Some(default) = default {
+        schema.insert("default".to_owned(), default);
+    }
+}
+
+#[cfg(feature = "documentation")]
+fn add_description(schema: &mut Value, docs: Option<&str>) {
+    if let (Value::Object(schema), Some(docs)) = (schema, docs) {
+        schema.insert("description".to_owned(), Value::String(docs.to_owned()));
+    }
+}
+
+/// Describes a tuple serialized as an array with one element per field.
+fn set_tuple_items<'a>(
+    schema: &mut JsonSchemaBevyType,
+    fields: impl Iterator<Item = &'a UnnamedField>,
+    registry: &TypeRegistry,
+) {
+    schema.schema_type = Some(SchemaType::Array);
+    schema.prefix_items = fields
+        .map(|field| unnamed_field_ref(field, registry))
+        .collect();
+    schema.min_items = Some(schema.prefix_items.len());
+    schema.items = Some(Value::Bool(false));
+}
+
+/// Returns `true` if the enum is [`Option`], which serializes to either null or its value.
+fn is_option(info: &EnumInfo) -> bool {
+    let path_table = info.type_path_table();
+    path_table.module_path() == Some("core::option") && path_table.ident() == Some("Option")
+}
+
+/// Returns the schema of a single enum variant.
+///
+/// Unit variants serialize to their name, and other variants to an object with their name as
+/// the only key.
+fn variant_schema(variant: &VariantInfo, registry: &TypeRegistry) -> Value {
+    let value = match variant {
+        VariantInfo::Unit(_) => json!({ "type": SchemaType::String, "const": variant.name() }),
+        VariantInfo::Tuple(info) if info.field_len() == 1 => {
+            unnamed_field_ref(info.field_at(0).unwrap(), registry)
+        }
+        VariantInfo::Tuple(info) => {
+            let prefix_items: Vec<_> = info
+                .iter()
+                .map(|field| unnamed_field_ref(field, registry))
+                .collect();
+            json!({
+                "type": SchemaType::Array,
+                "prefixItems": prefix_items,
+                "minItems": prefix_items.len(),
+                "items": false,
+            })
+        }
+        VariantInfo::Struct(info) => {
+            let properties: Map<_, _> = info
+                .iter()
+                .map(|field| (field.name().to_owned(), named_field_ref(field, registry)))
+                .collect();
+            json!({
+                "type": SchemaType::Object,
+                "properties": properties,
+                "required": info.field_names(),
+                "additionalProperties": false,
+            })
+        }
+    };
+
+    #[cfg_attr(not(feature = "documentation"), allow(unused_mut))]
+    let mut schema = match variant {
+        VariantInfo::Unit(_) => value,
+        _ => json!({
+            "type": SchemaType::Object,
+            "properties": { variant.name(): value },
+            "required": [variant.name()],
+            "additionalProperties": false,
+        }),
+    };
+    #[cfg(feature = "documentation")]
+    add_description(&mut schema, variant.docs());
+    schema
+}
+
+/// Returns the JSON type that a primitive serializes to, if the type is one.
+fn primitive_type(type_id: TypeId) -> Option<SchemaType> {
+    let is = |ids: &[TypeId]| ids.contains(&type_id);
+    if type_id == TypeId::of::<bool>() {
+        Some(SchemaType::Boolean)
+    } else if is(&[TypeId::of::<f32>(), TypeId::of::<f64>()]) {
+        Some(SchemaType::Number)
+    } else if is(&[
+        TypeId::of::<u8>(),
+        TypeId::of::<u16>(),
+        TypeId::of::<u32>(),
+        TypeId::of::<u64>(),
+        TypeId::of::<u128>(),
+        TypeId::of::<usize>(),
+        TypeId::of::<i8>(),
+        TypeId::of::<i16>(),
+        TypeId::of::<i32>(),
+        TypeId::of::<i64>(),
+        TypeId::of::<i128>(),
+        TypeId::of::<isize>(),
+    ]) {
+        Some(SchemaType::Integer)
+    } else if is(&[
+        TypeId::of::<String>(),
+        TypeId::of::<char>(),
+        TypeId::of::<&'static str>(),
+        TypeId::of::<Cow<'static, str>>(),
+        TypeId::of::<PathBuf>(),
+        TypeId::of::<OsString>(),
+    ]) {
+        Some(SchemaType::String)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::component::Component;
+    use bevy_reflect::{GetTypeRegistration, Reflect, TypePath};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Player {
+        name: String,
+        level: u32,
+    }
+
+    #[derive(Reflect)]
+    enum Shape {
+        Empty,
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+    }
+
+    fn export<T: GetTypeRegistration>() -> JsonSchemaBevyType {
+        let mut registry = TypeRegistry::default();
+        registry.register::<T>();
+        export_type(registry.get(TypeId::of::<T>()).unwrap(), &registry).1
+    }
+
+    fn type_ref<T: TypePath>() -> Value {
+        json!({ "$ref": format!("#/$defs/{}", T::type_path()) })
+    }
+
+    #[test]
+    fn struct_schema() {
+        let registry = TypeRegistry::default();
+        let (type_path, schema) = export_type(&Player::get_type_registration(), &registry);
+
+        assert_eq!(type_path, Player::type_path());
+        assert_eq!(schema.short_path, "Player");
+        assert_eq!(schema.crate_name.as_deref(), Some("bevy_remote"));
+        assert_eq!(schema.reflect_types, ["Component", "Default"]);
+        assert_eq!(schema.kind, SchemaKind::Struct);
+        assert_eq!(schema.schema_type, Some(SchemaType::Object));
+        assert_eq!(schema.additional_properties, Some(Value::Bool(false)));
+        assert_eq!(schema.required, ["name", "level"]);
+        assert_eq!(schema.properties["name"], type_ref::<String>());
+        assert_eq!(schema.properties["level"], type_ref::<u32>());
+    }
+
+    #[test]
+    fn enum_schema() {
+        let schema = export::<Shape>();
+
+        assert_eq!(schema.kind, SchemaKind::Enum);
+        assert_eq!(schema.schema_type, None);
+        assert_eq!(
+            schema.one_of,
+            [
+                json!({ "type": "string", "const": "Empty" }),
+                json!({
+                    "type": "object",
+                    "properties": { "Circle": type_ref::<f32>() },
+                    "required": ["Circle"],
+                    "additionalProperties": false,
+                }),
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "Rect": {
+                            "type": "object",
+                            "properties": {
+                                "width": type_ref::<f32>(),
+                                "height": type_ref::<f32>(),
+                            },
+                            "required": ["width", "height"],
+                            "additionalProperties": false,
+                        },
+                    },
+                    "required": ["Rect"],
+                    "additionalProperties": false,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn option_schema() {
+        let schema = export::<Option<u32>>();
+
+        assert_eq!(schema.kind, SchemaKind::Option);
+        assert_eq!(
+            schema.one_of,
+            [json!({ "type": "null" }), type_ref::<u32>()]
+        );
+    }
+
+    #[test]
+    fn map_schema() {
+        let schema = export::<HashMap<String, f32>>();
+
+        assert_eq!(schema.kind, SchemaKind::Map);
+        assert_eq!(schema.schema_type, Some(SchemaType::Object));
+        assert_eq!(schema.key_type, Some(type_ref::<String>()));
+        assert_eq!(schema.value_type, Some(type_ref::<f32>()));
+        assert_eq!(schema.additional_properties, Some(type_ref::<f32>()));
+    }
+
+    #[test]
+    fn primitive_schema() {
+        let schema = export::<u32>();
+
+        assert_eq!(schema.kind, SchemaKind::Opaque);
+        assert_eq!(schema.schema_type, Some(SchemaType::Integer));
+    }
+
+    #[test]
+    fn field_metadata() {
+        #[derive(Reflect)]
+        struct Settings {
+            #[reflect(display_name = "Volume", range = 0..=100, step = 5, default = "volume")]
+            volume: u32,
+            #[reflect(default, read_only)]
+            level: u32,
+            #[reflect(hidden)]
+            seed: u64,
+            name: String,
+        }
+
+        fn volume() -> u32 {
+            50
+        }
+
+        let schema = export::<Settings>();
+        let mut volume = type_ref::<u32>();
+        volume.as_object_mut().unwrap().extend([
+            ("title".to_owned(), json!("Volume")),
+            ("minimum".to_owned(), json!(0.0)),
+            ("maximum".to_owned(), json!(100.0)),
+            ("multipleOf".to_owned(), json!(5.0)),
+            ("default".to_owned(), json!(50)),
+        ]);
+        assert_eq!(schema.properties["volume"], volume);
+
+        let mut level = type_ref::<u32>();
+        level.as_object_mut().unwrap().extend([
+            ("readOnly".to_owned(), json!(true)),
+            ("default".to_owned(), json!(0)),
+        ]);
+        assert_eq!(schema.properties["level"], level);
+
+        let mut seed = type_ref::<u64>();
+        seed.as_object_mut()
+            .unwrap()
+            .insert("hidden".to_owned(), json!(true));
+        assert_eq!(schema.properties["seed"], seed);
+
+        assert_eq!(schema.properties["name"], type_ref::<String>());
+    }
+}
@@ -0,0 +1,3 @@
+//! Schemas describing the reflected types of an app to remote clients.
+
+pub mod json_schema;
@@ -0,0 +1,466 @@
+//! Request multiplexing shared by the transports keeping a connection open with their client.
+//!
+//! Unlike HTTP, where every request gets its own response body, the WebSocket and stdio
+//! transports send every response over the same connection. Responses are sent as soon as they
+//! are ready, so they may arrive in a different order than the requests; clients match them to
+//! their requests with the `id` field.
+
+use async_channel::{Sender, TrySendError};
+use bevy_tasks::{IoTaskPool, Task};
+use bevy_utils::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult};
+
+/// The number of responses that can wait to be sent to a client. A client that falls further
+/// behind is disconnected, rather than letting its responses pile up.
+pub(crate) const RESPONSE_CHANNEL_SIZE: usize = 256;
+
+/// The method path for a `bevy/unwatch` request, handled by the transport itself.
+pub const BRP_UNWATCH_METHOD: &str = "bevy/unwatch";
+
+/// `bevy/unwatch`: Stops a `+watch` request made on the same connection.
+///
+/// The server responds with a null, and no longer sends responses for the watching request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpUnwatchParams {
+    /// The `id` of the watching request to stop.
+    pub id: Value,
+}
+
+/// The requests of a single client connection.
+pub(crate) struct BrpSession {
+    /// The channel on which requests are sent to the main world.
+    request_sender: Sender<BrpMessage>,
+    /// The channel on which serialized responses are sent to the client.
+    ///
+    /// The channel is closed if it is full, which tells the transport to close the connection.
+    response_sender: Sender<String>,
+    /// The tasks forwarding the responses of ongoing watching requests, by serialized request id.
+    ///
+    /// Dropping a task closes its result channel, which ends the watching request.
+    watches: HashMap<String, Task<()>>,
+}
+
+impl BrpSession {
+    pub(crate) fn new(request_sender: Sender<BrpMessage>, response_sender: Sender<String>) -> Self {
+        Self {
+            request_sender,
+            response_sender,
+            watches: HashMap::default(),
+        }
+    }
+
+    /// Returns true if the responses of the session were closed because the client didn't read
+    /// them fast enough.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.response_sender.is_closed()
+    }
+
+    /// Processes a message received from the client, containing a request or a batch of requests.
+    pub(crate) fn handle_message(&mut self, message: &str) {
+        self.watches.retain(|_, task| !task.is_finished());
+
+        match serde_json::from_str::<BrpBatch>(message) {
+            Ok(BrpBatch::Single(request)) => self.handle_request(request),
+            Ok(BrpBatch::Batch(requests)) => self.handle_batch(requests),
+            Err(err) => self.respond(BrpResponse::new(
+                None,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: err.to_string(),
+                    data: None,
+                }),
+            )),
+        }
+    }
+
+    fn handle_request(&mut self, request: Value) {
+        let request = match parse_request(request) {
+            Ok(request) => request,
+            Err(response) => return self.respond(response),
+        };
+
+        if request.method == BRP_UNWATCH_METHOD {
+            let result = self.unwatch(request.params);
+            return self.respond(BrpResponse::new(request.id, result));
+        }
+
+        let watch = request.method.contains("+watch");
+        let size = if watch { 8 } else { 1 };
+        let (result_sender, result_receiver) = async_channel::bounded(size);
+        let message = BrpMessage {
+            method: request.method,
+            params: request.params,
+            sender: result_sender,
+        };
+
+        let id = request.id;
+        let key = id.as_ref().map(serialize_id);
+        let request_sender = self.request_sender.clone();
+        let response_sender = self.response_sender.clone();
+        let task = IoTaskPool::get().spawn(async move {
+            let _ = request_sender.send(message).await;
+            while let Ok(result) = result_receiver.recv().await {
+                let response = BrpResponse::new(id.clone(), result);
+                let Ok(serialized) = serde_json::to_string(&response) else {
+                    continue;
+                };
+                if !send_response(&response_sender, serialized) || !watch {
+                    break;
+                }
+            }
+        });
+
+        match key {
+            // Replacing an ongoing watch with the same id cancels it.
+            Some(key) if watch => {
+                self.watches.insert(key, task);
+            }
+            _ => task.detach(),
+        }
+    }
+
+    /// Processes a batch of requests, responding with the array of their responses once they are
+    /// all complete.
+    fn handle_batch(&mut self, requests: Vec<Value>) {
+        let request_sender = self.request_sender.clone();
+        let response_sender = self.response_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let mut responses = Vec::with_capacity(requests.len());
+                for request in requests {
+                    let request = match parse_request(request) {
+                        Ok(request) => request,
+                        Err(response) => {
+                            responses.push(response);
+                            continue;
+                        }
+                    };
+                    if request.method.contains("+watch") || request.method == BRP_UNWATCH_METHOD {
+                        responses.push(BrpResponse::new(
+                            request.id,
+                            Err(BrpError {
+                                code: error_codes::INVALID_REQUEST,
+                                message: "Watching can not be used in batch requests".to_string(),
+                                data: None,
+                            }),
+                        ));
+                        continue;
+                    }
+
+                    let (result_sender, result_receiver) = async_channel::bounded(1);
+                    let _ = request_sender
+                        .send(BrpMessage {
+                            method: request.method,
+                            params: request.params,
+                            sender: result_sender,
+                        })
+                        .await;
+                    let result = result_receiver.recv().await.unwrap_or_else(|err| {
+                        Err(BrpError::internal(format!("No response to request: {err}")))
+                    });
+                    responses.push(BrpResponse::new(request.id, result));
+                }
+
+                if let Ok(serialized) = serde_json::to_string(&responses) {
+                    send_response(&response_sender, serialized);
+                }
+            })
+            .detach();
+    }
+
+    /// Handles a `bevy/unwatch` request.
+    fn unwatch(&mut self, params: Option<Value>) -> BrpResult {
+        let Some(BrpUnwatchParams { id }) =
+            params.and_then(|params| serde_json::from_value(params).ok())
+        else {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: String::from("Expected the `id` of a watching request"),
+                data: None,
+            });
+        };
+
+        match self.watches.remove(&serialize_id(&id)) {
+            Some(_) => Ok(Value::Null),
+            None => Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("No watching request with id {id}"),
+                data: None,
+            }),
+        }
+    }
+
+    fn respond(&self, response: BrpResponse) {
+        if let Ok(serialized) = serde_json::to_string(&response) {
+            send_response(&self.response_sender, serialized);
+        }
+    }
+}
+
+/// Queues a serialized response, closing the responses of the session if the client has too
+/// many pending responses. Returns false if the response couldn't be queued.
+fn send_response(response_sender: &Sender<String>, response: String) -> bool {
+    match response_sender.try_send(response) {
+        Ok(()) => true,
+        Err(TrySendError::Full(_)) => {
+            response_sender.close();
+            false
+        }
+        Err(TrySendError::Closed(_)) => false,
+    }
+}
+
+/// Parses a single request, or returns the error response to send back.
+fn parse_request(request: Value) -> Result<BrpRequest, BrpResponse> {
+    // Reach in and get the request ID early so that we can report it even when parsing fails.
+    let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+    let request: BrpRequest = serde_json::from_value(request).map_err(|err| {
+        BrpResponse::new(
+            id.clone(),
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: err.to_string(),
+                data: None,
+            }),
+        )
+    })?;
+
+    if request.jsonrpc != "2.0" {
+        return Err(BrpResponse::new(
+            id,
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                data: None,
+            }),
+        ));
+    }
+
+    Ok(request)
+}
+
+/// Serializes a request id, so that ids are compared by value.
+fn serialize_id(id: &Value) -> String {
+    id.to_string()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use async_channel::Receiver;
+    use bevy_tasks::{block_on, TaskPool};
+    use core::{future::Future, pin::pin};
+    use serde_json::json;
+
+    /// A session along with the ends of its channels normally held by the world and the client.
+    struct TestSession {
+        session: BrpSession,
+        requests: Receiver<BrpMessage>,
+        responses: Receiver<String>,
+    }
+
+    impl TestSession {
+        fn new() -> Self {
+            Self::with_capacity(RESPONSE_CHANNEL_SIZE)
+        }
+
+        fn with_capacity(capacity: usize) -> Self {
+            IoTaskPool::get_or_init(TaskPool::default);
+            let (request_sender, requests) = async_channel::unbounded();
+            let (response_sender, responses) = async_channel::bounded(capacity);
+            Self {
+                session: BrpSession::new(request_sender, response_sender),
+                requests,
+                responses,
+            }
+        }
+
+        fn send(&mut self, message: Value) {
+            self.session.handle_message(&message.to_string());
+        }
+
+        /// Receives the next request forwarded to the world.
+        fn request(&self) -> BrpMessage {
+            recv(&self.requests)
+        }
+
+        /// Receives the next response sent to the client.
+        fn response(&self) -> Value {
+            serde_json::from_str(&recv(&self.responses)).unwrap()
+        }
+
+        fn assert_no_response(&self) {
+            tick();
+            assert!(self.responses.is_empty());
+        }
+    }
+
+    /// Ticks the local executor of the I/O task pool, in case it is single-threaded.
+    pub(crate) fn tick() {
+        IoTaskPool::get().with_local_executor(|executor| while executor.try_tick() {});
+    }
+
+    /// Blocks until `future` completes, running the tasks it spawns meanwhile in case the I/O
+    /// task pool is single-threaded.
+    pub(crate) fn run_local<T>(future: impl Future<Output = T>) -> T {
+        IoTaskPool::get_or_init(TaskPool::default);
+        IoTaskPool::get().with_local_executor(|executor| block_on(executor.run(future)))
+    }
+
+    /// Blocks until a value is received, running the tasks of the session meanwhile.
+    pub(crate) fn recv<T>(receiver: &Receiver<T>) -> T {
+        let mut recv = pin!(receiver.recv());
+        block_on(core::future::poll_fn(|cx| {
+            tick();
+            recv.as_mut().poll(cx)
+        }))
+        .unwrap()
+    }
+
+    /// Waits until the watching request of `message` has been cancelled, which may take a
+    /// moment if its task is running on another thread.
+    fn assert_cancelled(message: &BrpMessage) {
+        for _ in 0..1000 {
+            tick();
+            if message.sender.is_closed() {
+                return;
+            }
+            std::thread::sleep(core::time::Duration::from_millis(1));
+        }
+        panic!("The watching request `{}` wasn't cancelled", message.method);
+    }
+
+    fn request(id: Value, method: &str) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method })
+    }
+
+    fn result(id: Value, result: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "result": result })
+    }
+
+    #[test]
+    fn responses_are_sent_as_soon_as_ready() {
+        let mut session = TestSession::new();
+        session.send(request(json!(1), "bevy/list"));
+        session.send(request(json!(2), "bevy/list_resources"));
+        let first = session.request();
+        let second = session.request();
+        assert_eq!(first.method, "bevy/list");
+        assert_eq!(second.method, "bevy/list_resources");
+
+        second.sender.try_send(Ok(json!(["second"]))).unwrap();
+        assert_eq!(session.response(), result(json!(2), json!(["second"])));
+        first.sender.try_send(Ok(json!(["first"]))).unwrap();
+        assert_eq!(session.response(), result(json!(1), json!(["first"])));
+    }
+
+    #[test]
+    fn too_many_pending_responses_close_the_session() {
+        let mut session = TestSession::with_capacity(2);
+        session.send(request(json!("watch"), "bevy/get+watch"));
+        let watch = session.request();
+        for value in 0..2 {
+            watch.sender.try_send(Ok(json!(value))).unwrap();
+            tick();
+        }
+        assert!(!session.session.is_closed());
+
+        // The client hasn't read any response.
+        watch.sender.try_send(Ok(json!(2))).unwrap();
+        assert_cancelled(&watch);
+        assert!(session.session.is_closed());
+        assert_eq!(session.response(), result(json!("watch"), json!(0)));
+        assert_eq!(session.response(), result(json!("watch"), json!(1)));
+        assert!(session.responses.recv_blocking().is_err());
+    }
+
+    #[test]
+    fn invalid_requests() {
+        let mut session = TestSession::new();
+        session.session.handle_message("{");
+        let response = session.response();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], error_codes::INVALID_REQUEST);
+
+        session.send(json!({ "jsonrpc": "1.0", "id": 3, "method": "bevy/list" }));
+        let response = session.response();
+        assert_eq!(response["id"], 3);
+        assert_eq!(response["error"]["code"], error_codes::INVALID_REQUEST);
+        assert!(session.requests.is_empty());
+    }
+
+    #[test]
+    fn unwatch_stops_watching_request() {
+        let mut session = TestSession::new();
+        session.send(request(json!("watch"), "bevy/get+watch"));
+        let watch = session.request();
+        for value in [1, 2] {
+            watch.sender.try_send(Ok(json!(value))).unwrap();
+            assert_eq!(session.response(), result(json!("watch"), json!(value)));
+        }
+
+        session.send(json!({
+            "jsonrpc": "2.0",
+            "id": 4,
+            "method": BRP_UNWATCH_METHOD,
+            "params": { "id": "watch" },
+        }));
+        assert_eq!(session.response(), result(json!(4), Value::Null));
+        assert_cancelled(&watch);
+        session.assert_no_response();
+
+        session.send(json!({
+            "jsonrpc": "2.0",
+            "id": 5,
+            "method": BRP_UNWATCH_METHOD,
+            "params": { "id": "watch" },
+        }));
+        let response = session.response();
+        assert_eq!(response["id"], 5);
+        assert_eq!(response["error"]["code"], error_codes::INVALID_PARAMS);
+    }
+
+    #[test]
+    fn watching_request_with_same_id_replaces_previous_one() {
+        let mut session = TestSession::new();
+        session.send(request(json!(7), "bevy/get+watch"));
+        let first = session.request();
+        session.send(request(json!(7), "bevy/list+watch"));
+        let second = session.request();
+        assert_cancelled(&first);
+
+        second.sender.try_send(Ok(json!("second"))).unwrap();
+        assert_eq!(session.response(), result(json!(7), json!("second")));
+        session.assert_no_response();
+    }
+
+    #[test]
+    fn batches_reject_watching_requests() {
+        let mut session = TestSession::new();
+        session.send(json!([
+            request(json!(1), "bevy/list"),
+            request(json!(2), "bevy/get+watch"),
+            {
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": BRP_UNWATCH_METHOD,
+                "params": { "id": 2 },
+            },
+        ]));
+        let list = session.request();
+        assert_eq!(list.method, "bevy/list");
+        list.sender.try_send(Ok(json!([]))).unwrap();
+
+        let responses = session.response();
+        assert_eq!(responses[0], result(json!(1), json!([])));
+        assert_eq!(responses[1]["id"], 2);
+        assert_eq!(responses[1]["error"]["code"], error_codes::INVALID_REQUEST);
+        assert_eq!(responses[2]["id"], 3);
+        assert_eq!(responses[2]["error"]["code"], error_codes::INVALID_REQUEST);
+        assert!(session.requests.is_empty());
+    }
+}
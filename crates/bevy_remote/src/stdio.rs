@@ -0,0 +1,160 @@
+//! The BRP transport using JSON-RPC over the standard input and output of the process.
+//!
+//! Adding the [`RemoteStdioPlugin`] to your [`App`] lets the process that launched your app
+//! send requests through its standard input, one request or batch of requests per line. Every
+//! response is written to the standard output on its own line, as soon as it is ready; responses
+//! are matched to their request by their `id`. Watching requests send a response every time a
+//! change is detected, until they are stopped with
+//! [`bevy/unwatch`](crate::session::BRP_UNWATCH_METHOD).
+//!
+//! Anything else the app writes to its standard output, such as logs, is interleaved with the
+//! responses. Clients should skip the lines that aren't JSON-RPC responses, or the app should
+//! write its logs to the standard error instead.
+//!
+//! The transport stops reading requests if the parent process doesn't read its responses fast
+//! enough.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    session::{BrpSession, RESPONSE_CHANNEL_SIZE},
+    BrpSender,
+};
+use async_channel::Receiver;
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use std::{
+    io::{self, BufRead, Write},
+    thread,
+};
+
+/// Add this plugin to your [`App`] to allow the parent process to inspect and modify entities
+/// through the standard input and output. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+#[derive(Default)]
+pub struct RemoteStdioPlugin;
+
+impl Plugin for RemoteStdioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, start_stdio_transport);
+    }
+}
+
+/// A system that starts the threads reading requests from the standard input and writing
+/// responses to the standard output.
+fn start_stdio_transport(request_sender: Res<BrpSender>) {
+    let (response_sender, response_receiver) = async_channel::bounded(RESPONSE_CHANNEL_SIZE);
+
+    let _ = thread::Builder::new()
+        .name(String::from("BRP stdout"))
+        .spawn(move || write_responses(&response_receiver, io::stdout()));
+
+    let mut session = BrpSession::new(request_sender.clone(), response_sender);
+    let _ = thread::Builder::new()
+        .name(String::from("BRP stdin"))
+        .spawn(move || read_requests(io::stdin().lock(), &mut session));
+}
+
+/// Handles each line of `input` as a message, until the input or the session is closed.
+fn read_requests(input: impl BufRead, session: &mut BrpSession) {
+    for line in input.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if !line.trim().is_empty() {
+            session.handle_message(&line);
+        }
+        if session.is_closed() {
+            break;
+        }
+    }
+}
+
+/// Writes each response on its own line of `output`, until the responses are closed.
+fn write_responses(responses: &Receiver<String>, mut output: impl Write) {
+    while let Ok(response) = responses.recv_blocking() {
+        // Only write a line at a time, so that other output can still be written.
+        if writeln!(output, "{response}")
+            .and_then(|_| output.flush())
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error_codes,
+        session::tests::{recv, tick},
+        BrpMessage,
+    };
+    use serde_json::{json, Value};
+
+    fn lines(output: &[u8]) -> Vec<Value> {
+        String::from_utf8_lossy(output)
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn requests_and_responses_are_lines() {
+        bevy_tasks::IoTaskPool::get_or_init(bevy_tasks::TaskPool::default);
+        let (request_sender, requests) = async_channel::unbounded::<BrpMessage>();
+        let (response_sender, response_receiver) = async_channel::bounded(RESPONSE_CHANNEL_SIZE);
+        let mut session = BrpSession::new(request_sender, response_sender);
+
+        let input = [
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "bevy/list" }).to_string(),
+            String::from("   "),
+            String::from("not json"),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "bevy/list_resources" }).to_string(),
+        ]
+        .join("\n");
+        read_requests(input.as_bytes(), &mut session);
+
+        let first = recv(&requests);
+        let second = recv(&requests);
+        assert_eq!(first.method, "bevy/list");
+        assert_eq!(second.method, "bevy/list_resources");
+        assert!(requests.is_empty());
+        second.sender.try_send(Ok(json!(2))).unwrap();
+        tick();
+        first.sender.try_send(Ok(json!(1))).unwrap();
+        tick();
+
+        // The output ends once every sender of responses is gone.
+        drop((session, first, second));
+        let mut output = Vec::new();
+        write_responses(&response_receiver, &mut output);
+        let responses = lines(&output);
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0]["error"]["code"], error_codes::INVALID_REQUEST);
+        assert_eq!(
+            responses[1],
+            json!({ "jsonrpc": "2.0", "id": 2, "result": 2 })
+        );
+        assert_eq!(
+            responses[2],
+            json!({ "jsonrpc": "2.0", "id": 1, "result": 1 })
+        );
+    }
+
+    #[test]
+    fn reading_stops_once_the_session_is_closed() {
+        bevy_tasks::IoTaskPool::get_or_init(bevy_tasks::TaskPool::default);
+        let (request_sender, requests) = async_channel::unbounded::<BrpMessage>();
+        let (response_sender, response_receiver) = async_channel::bounded(1);
+        let mut session = BrpSession::new(request_sender, response_sender);
+
+        // Invalid requests are answered immediately, and nobody reads the responses.
+        read_requests("{\n{\n{\n{".as_bytes(), &mut session);
+        assert!(session.is_closed());
+        assert!(requests.is_empty());
+        assert_eq!(response_receiver.len(), 1);
+    }
+}
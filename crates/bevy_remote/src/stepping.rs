@@ -0,0 +1,537 @@
+//! Methods to control [`Stepping`] through the Bevy Remote Protocol.
+//!
+//! Stepping is only available if Bevy is compiled with the `bevy_debug_stepping` feature:
+//! otherwise `bevy/stepping/enable` fails.
+//!
+//! The schedule that is currently running is removed from the [`Schedules`] resource, so the
+//! system names of all schedules are cached by the [`RemotePlugin`](crate::RemotePlugin) in
+//! [`First`](bevy_app::First) and [`Last`](bevy_app::Last). This allows breakpoints to be set by
+//! name on the systems of the schedule that processes remote requests.
+
+use core::any::TypeId;
+
+use bevy_ecs::{
+    change_detection::Mut,
+    schedule::{InternedScheduleLabel, NodeId, Schedules, Stepping},
+    system::{In, Res, ResMut, Resource},
+    world::World,
+};
+use bevy_utils::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    builtin_methods::{parse, parse_some},
+    process_ongoing_watching_requests, process_remote_requests, remove_closed_watching_requests,
+    BrpError, BrpResult,
+};
+
+/// The method path for a `bevy/stepping/enable` request.
+pub const BRP_STEPPING_ENABLE_METHOD: &str = "bevy/stepping/enable";
+
+/// The method path for a `bevy/stepping/disable` request.
+pub const BRP_STEPPING_DISABLE_METHOD: &str = "bevy/stepping/disable";
+
+/// The method path for a `bevy/stepping/step_system` request.
+pub const BRP_STEPPING_STEP_SYSTEM_METHOD: &str = "bevy/stepping/step_system";
+
+/// The method path for a `bevy/stepping/step_frame` request.
+pub const BRP_STEPPING_STEP_FRAME_METHOD: &str = "bevy/stepping/step_frame";
+
+/// The method path for a `bevy/stepping/continue` request.
+pub const BRP_STEPPING_CONTINUE_METHOD: &str = "bevy/stepping/continue";
+
+/// The method path for a `bevy/stepping/set_breakpoint` request.
+pub const BRP_STEPPING_SET_BREAKPOINT_METHOD: &str = "bevy/stepping/set_breakpoint";
+
+/// The method path for a `bevy/stepping/clear_breakpoint` request.
+pub const BRP_STEPPING_CLEAR_BREAKPOINT_METHOD: &str = "bevy/stepping/clear_breakpoint";
+
+/// The method path for a `bevy/stepping/cursor` request.
+pub const BRP_STEPPING_CURSOR_METHOD: &str = "bevy/stepping/cursor";
+
+/// `bevy/stepping/enable`: Enables stepping for the given schedules.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BrpSteppingEnableParams {
+    /// The names of the schedules to step, as printed by their [`Debug`] implementation: e.g.
+    /// `Update`.
+    ///
+    /// Schedules that were already added to [`Stepping`] stay stepped.
+    #[serde(default)]
+    pub schedules: Vec<String>,
+}
+
+/// `bevy/stepping/set_breakpoint` and `bevy/stepping/clear_breakpoint`: Sets or clears a
+/// breakpoint on a system.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpBreakpointParams {
+    /// The name of the schedule containing the system: e.g. `Update`.
+    pub schedule: String,
+
+    /// The name of the system. This can be the full name of the system, such as
+    /// `my_game::movement::apply_velocity`, or any suffix of it starting after a `::`, such as
+    /// `apply_velocity`.
+    ///
+    /// If several systems of the schedule match the name, all of them are affected.
+    pub system: String,
+}
+
+/// The response to a `bevy/stepping/cursor` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BrpSteppingCursorResponse {
+    /// Whether stepping is enabled.
+    pub enabled: bool,
+
+    /// The name of the schedule containing the next system to run, if any.
+    pub schedule: Option<String>,
+
+    /// The name of the next system to run, if any.
+    pub system: Option<String>,
+}
+
+/// The state of remote stepping, including the systems of the schedules in the [`Schedules`]
+/// resource, cached so that they can be looked up while their schedule is running.
+#[derive(Resource, Default)]
+pub(crate) struct RemoteSteppingState {
+    /// The [`NodeId`], [`TypeId`] and name of each system, by schedule.
+    systems: HashMap<InternedScheduleLabel, Vec<(NodeId, TypeId, String)>>,
+    /// Set by `bevy/stepping/continue`; the frame starts continuing once the next frame begins.
+    continue_requested: bool,
+    /// Whether the current frame is continuing because of `bevy/stepping/continue`.
+    continuing: bool,
+}
+
+/// Caches the systems of all schedules that aren't running, so that they can be found by name.
+pub(crate) fn cache_stepping_systems(
+    schedules: Option<Res<Schedules>>,
+    mut state: ResMut<RemoteSteppingState>,
+) {
+    let Some(schedules) = schedules else {
+        return;
+    };
+    for (_, schedule) in schedules.iter() {
+        let Ok(systems) = schedule.systems() else {
+            continue;
+        };
+        let label = schedule.label();
+        // A rebuilt schedule can have as many systems as before, so compare each of them.
+        let up_to_date = state.systems.get(&label).is_some_and(|cached| {
+            cached.len() == schedule.systems_len()
+                && cached.iter().zip(systems).all(
+                    |((cached_id, cached_type_id, _), (node_id, system))| {
+                        *cached_id == node_id && *cached_type_id == system.type_id()
+                    },
+                )
+        });
+        if up_to_date {
+            continue;
+        }
+        if let Ok(systems) = schedule.systems() {
+            let systems = systems
+                .map(|(node_id, system)| (node_id, system.type_id(), system.name().into_owned()))
+                .collect();
+            state.systems.insert(label, systems);
+        }
+    }
+}
+
+/// Marks the start of a frame continued by `bevy/stepping/continue`.
+pub(crate) fn begin_continue_frame(mut state: ResMut<RemoteSteppingState>) {
+    if state.continue_requested {
+        state.continue_requested = false;
+        state.continuing = true;
+    }
+}
+
+/// Keeps continuing frames after a `bevy/stepping/continue` request, until a breakpoint stops one.
+pub(crate) fn end_continue_frame(
+    stepping: Option<ResMut<Stepping>>,
+    mut state: ResMut<RemoteSteppingState>,
+) {
+    if !core::mem::take(&mut state.continuing) {
+        return;
+    }
+    let Some(mut stepping) = stepping else {
+        return;
+    };
+    // The cursor is past the last schedule at the end of a frame, unless a breakpoint stopped it.
+    if stepping.is_enabled() && stepping.cursor().is_none() {
+        stepping.continue_frame();
+        state.continue_requested = true;
+    }
+}
+
+/// Handles a `bevy/stepping/enable` request coming from a client.
+pub fn process_remote_stepping_enable_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    if !Stepping::is_available() {
+        return Err(BrpError::stepping_unavailable());
+    }
+    let BrpSteppingEnableParams { schedules } = match params {
+        Some(params) => parse(params)?,
+        None => BrpSteppingEnableParams::default(),
+    };
+    let labels = schedules
+        .iter()
+        .map(|name| find_schedule(world, name))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut stepping = world.get_resource_or_init::<Stepping>();
+    for label in labels {
+        // Remote requests must still be processed while the schedule is stepped.
+        stepping
+            .add_schedule(label)
+            .always_run(label, process_remote_requests)
+            .always_run(label, process_ongoing_watching_requests)
+            .always_run(label, remove_closed_watching_requests)
+            .always_run(label, cache_stepping_systems)
+            .always_run(label, begin_continue_frame)
+            .always_run(label, end_continue_frame);
+    }
+    stepping.enable();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/disable` request coming from a client.
+pub fn process_remote_stepping_disable_request(
+    In(_): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    stop_continuing(world);
+    if let Some(mut stepping) = world.get_resource_mut::<Stepping>() {
+        stepping.disable();
+    }
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/step_system` request coming from a client.
+pub fn process_remote_stepping_step_system_request(
+    In(_): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    stop_continuing(world);
+    get_stepping(world)?.step_frame();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/step_frame` request coming from a client.
+pub fn process_remote_stepping_step_frame_request(
+    In(_): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    stop_continuing(world);
+    get_stepping(world)?.continue_frame();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/continue` request coming from a client.
+pub fn process_remote_stepping_continue_request(
+    In(_): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping(world)?.continue_frame();
+    world
+        .resource_mut::<RemoteSteppingState>()
+        .continue_requested = true;
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/set_breakpoint` request coming from a client.
+pub fn process_remote_stepping_set_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let (label, nodes) = find_systems(world, parse_some(params)?)?;
+    let mut stepping = get_stepping(world)?;
+    for node in nodes {
+        stepping.set_breakpoint_node(label, node);
+    }
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/clear_breakpoint` request coming from a client.
+pub fn process_remote_stepping_clear_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let (label, nodes) = find_systems(world, parse_some(params)?)?;
+    let mut stepping = get_stepping(world)?;
+    for node in nodes {
+        stepping.clear_breakpoint_node(label, node);
+    }
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/cursor` request coming from a client.
+pub fn process_remote_stepping_cursor_request(
+    In(_): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let stepping = world.get_resource::<Stepping>();
+    let cursor = stepping.and_then(Stepping::cursor);
+    let response = BrpSteppingCursorResponse {
+        enabled: stepping.is_some_and(Stepping::is_enabled),
+        schedule: cursor.map(|(label, _)| format!("{label:?}")),
+        system: cursor.and_then(|(label, node)| {
+            world
+                .resource::<RemoteSteppingState>()
+                .systems
+                .get(&label)?
+                .iter()
+                .find(|(node_id, _, _)| *node_id == node)
+                .map(|(_, _, name)| name.clone())
+        }),
+    };
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+fn get_stepping(world: &mut World) -> Result<Mut<'_, Stepping>, BrpError> {
+    world
+        .get_resource_mut::<Stepping>()
+        .ok_or_else(BrpError::stepping_not_enabled)
+}
+
+/// Cancels a pending `bevy/stepping/continue` request.
+fn stop_continuing(world: &mut World) {
+    let mut state = world.resource_mut::<RemoteSteppingState>();
+    state.continue_requested = false;
+    state.continuing = false;
+}
+
+/// Finds a schedule by the [`Debug`] representation of its label.
+fn find_schedule(world: &World, name: &str) -> Result<InternedScheduleLabel, BrpError> {
+    let running = world
+        .resource::<RemoteSteppingState>()
+        .systems
+        .keys()
+        .copied();
+    let available = world
+        .get_resource::<Schedules>()
+        .into_iter()
+        .flat_map(Schedules::iter)
+        .map(|(_, schedule)| schedule.label());
+    running
+        .chain(available)
+        .find(|label| format!("{label:?}") == name)
+        .ok_or_else(|| BrpError::schedule_not_found(name))
+}
+
+/// Finds the systems of a schedule matching the given name.
+fn find_systems(
+    world: &World,
+    BrpBreakpointParams { schedule, system }: BrpBreakpointParams,
+) -> Result<(InternedScheduleLabel, Vec<NodeId>), BrpError> {
+    let label = find_schedule(world, &schedule)?;
+    let suffix = format!("::{system}");
+    let nodes: Vec<_> = world
+        .resource::<RemoteSteppingState>()
+        .systems
+        .get(&label)
+        .into_iter()
+        .flatten()
+        .filter(|(_, _, name)| *name == system || name.ends_with(&suffix))
+        .map(|(node_id, _, _)| *node_id)
+        .collect();
+    if nodes.is_empty() {
+        return Err(BrpError::system_not_found(&system, &schedule));
+    }
+    Ok((label, nodes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error_codes, RemotePlugin};
+    use bevy_app::{App, Update};
+    use bevy_ecs::{
+        schedule::{IntoSystemConfigs, Schedule, ScheduleLabel},
+        system::{IntoSystem, RunSystemOnce},
+    };
+    use serde_json::json;
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    fn count(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default())
+            .init_resource::<Counter>()
+            .add_systems(Update, count);
+        // Caches the systems of `Update`.
+        app.update();
+        assert_eq!(app.world().resource::<Counter>().0, 1);
+        app
+    }
+
+    fn call<M>(
+        app: &mut App,
+        handler: impl IntoSystem<In<Option<Value>>, BrpResult, M>,
+        params: Option<Value>,
+    ) -> BrpResult {
+        app.world_mut()
+            .run_system_once_with(params, handler)
+            .unwrap()
+    }
+
+    fn counter(app: &App) -> u32 {
+        app.world().resource::<Counter>().0
+    }
+
+    fn enable(app: &mut App) {
+        call(
+            app,
+            process_remote_stepping_enable_request,
+            Some(json!({ "schedules": ["Update"] })),
+        )
+        .unwrap();
+    }
+
+    fn breakpoint(system: &str) -> Option<Value> {
+        Some(json!({ "schedule": "Update", "system": system }))
+    }
+
+    #[test]
+    fn step_frame_runs_one_frame() {
+        let mut app = app();
+        enable(&mut app);
+        app.update();
+        assert_eq!(counter(&app), 1);
+
+        call(&mut app, process_remote_stepping_step_frame_request, None).unwrap();
+        app.update();
+        assert_eq!(counter(&app), 2);
+        app.update();
+        assert_eq!(counter(&app), 2);
+
+        call(&mut app, process_remote_stepping_disable_request, None).unwrap();
+        app.update();
+        assert_eq!(counter(&app), 3);
+    }
+
+    #[test]
+    fn continue_runs_frames_until_stopped() {
+        let mut app = app();
+        enable(&mut app);
+
+        call(&mut app, process_remote_stepping_continue_request, None).unwrap();
+        for expected in 2..5 {
+            app.update();
+            assert_eq!(counter(&app), expected);
+        }
+
+        // Any other stepping request stops continuing.
+        call(&mut app, process_remote_stepping_step_frame_request, None).unwrap();
+        app.update();
+        assert_eq!(counter(&app), 5);
+        app.update();
+        assert_eq!(counter(&app), 5);
+    }
+
+    #[test]
+    fn continue_stops_at_breakpoint() {
+        fn before_count() {}
+
+        let mut app = app();
+        // The system at the cursor always runs, so break on a system after the first one.
+        app.add_systems(Update, before_count.before(count));
+        app.update();
+        enable(&mut app);
+        call(
+            &mut app,
+            process_remote_stepping_set_breakpoint_request,
+            breakpoint("count"),
+        )
+        .unwrap();
+
+        call(&mut app, process_remote_stepping_continue_request, None).unwrap();
+        app.update();
+        app.update();
+        assert_eq!(counter(&app), 2);
+
+        let cursor = call(&mut app, process_remote_stepping_cursor_request, None).unwrap();
+        let cursor: BrpSteppingCursorResponse = serde_json::from_value(cursor).unwrap();
+        assert!(cursor.enabled);
+        assert_eq!(cursor.schedule.as_deref(), Some("Update"));
+        assert!(cursor.system.unwrap().ends_with("::count"));
+
+        call(
+            &mut app,
+            process_remote_stepping_clear_breakpoint_request,
+            breakpoint("count"),
+        )
+        .unwrap();
+        call(&mut app, process_remote_stepping_continue_request, None).unwrap();
+        app.update();
+        assert_eq!(counter(&app), 3);
+        app.update();
+        assert_eq!(counter(&app), 4);
+    }
+
+    #[test]
+    fn find_systems_by_suffix() {
+        let mut app = app();
+        let world = app.world_mut();
+        let params = |system: &str| BrpBreakpointParams {
+            schedule: "Update".into(),
+            system: system.into(),
+        };
+
+        let (_, nodes) = find_systems(world, params("count")).unwrap();
+        assert_eq!(nodes.len(), 1);
+        let (_, full) = find_systems(world, params("bevy_remote::stepping::tests::count")).unwrap();
+        assert_eq!(nodes, full);
+
+        // Suffixes must start after a `::`.
+        let error = find_systems(world, params("ount")).unwrap_err();
+        assert_eq!(error.code, error_codes::SYSTEM_NOT_FOUND);
+        let error = find_systems(
+            world,
+            BrpBreakpointParams {
+                schedule: "Missing".into(),
+                system: "count".into(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::SCHEDULE_NOT_FOUND);
+    }
+
+    #[test]
+    fn controlling_stepping_requires_enable() {
+        let mut app = app();
+        let error = call(&mut app, process_remote_stepping_step_frame_request, None).unwrap_err();
+        assert_eq!(error.code, error_codes::STEPPING_NOT_ENABLED);
+    }
+
+    #[test]
+    fn cache_refreshes_rebuilt_schedules() {
+        fn first() {}
+        fn second() {}
+
+        fn rebuild<M>(world: &mut World, system: impl IntoSystemConfigs<M>) {
+            let mut schedule = Schedule::new(Update);
+            schedule.add_systems(system);
+            schedule.initialize(world).unwrap();
+            world.resource_mut::<Schedules>().insert(schedule);
+            world.run_system_once(cache_stepping_systems).unwrap();
+        }
+
+        let mut world = World::new();
+        world.init_resource::<RemoteSteppingState>();
+        rebuild(&mut world, first);
+        rebuild(&mut world, second);
+
+        let systems = &world.resource::<RemoteSteppingState>().systems[&Update.intern()];
+        assert_eq!(systems.len(), 1);
+        assert!(systems[0].2.ends_with("::second"));
+    }
+}
@@ -0,0 +1,331 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept WebSocket
+//! connections (by default, on port 15703) while your app is running.
+//!
+//! Each text message sent by a client contains a request or a batch of requests, and each
+//! message sent by the server contains a single response or the responses of a batch. Any number
+//! of requests can be ongoing on a connection: responses are sent as soon as they are ready and
+//! are matched to their request by their `id`. Watching requests send a response every time a
+//! change is detected, until they are stopped with [`bevy/unwatch`](crate::session::BRP_UNWATCH_METHOD)
+//! or the connection is closed.
+//!
+//! Binary messages aren't supported and close the connection. The connection is also closed if
+//! the client doesn't read its responses fast enough.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    session::{BrpSession, RESPONSE_CHANNEL_SIZE},
+    BrpMessage, BrpSender,
+};
+use anyhow::Result as AnyhowResult;
+use async_channel::Sender;
+use async_io::Async;
+use async_tungstenite::{
+    tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame, WebSocketConfig},
+        Error as WsError, Message,
+    },
+    WebSocketStream,
+};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use bevy_tasks::{
+    futures_lite::{future, AsyncRead, AsyncWrite, StreamExt as _},
+    IoTaskPool,
+};
+use core::net::{IpAddr, Ipv4Addr};
+use futures_util::SinkExt as _;
+use std::net::TcpListener;
+
+/// The default port that Bevy will listen on for WebSocket connections.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The largest message that clients are allowed to send, in bytes.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Add this plugin to your [`App`] to allow remote connections over WebSocket to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        let (address, port) = (self.address, self.port);
+        app.add_systems(Startup, move |request_sender: Res<BrpSender>| {
+            IoTaskPool::get()
+                .spawn(server_main(address, port, request_sender.clone()))
+                .detach();
+        });
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+/// Something that happened on a connection.
+enum ConnectionEvent {
+    /// A message was received from the client, or the connection ended if [`None`].
+    Received(Option<Result<Message, WsError>>),
+    /// A response is ready to be sent, or the session closed its responses if [`None`].
+    Response(Option<String>),
+}
+
+async fn handle_client(
+    client: impl AsyncRead + AsyncWrite + Unpin,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let config = WebSocketConfig {
+        max_message_size: Some(MAX_MESSAGE_SIZE),
+        max_frame_size: Some(MAX_MESSAGE_SIZE),
+        ..Default::default()
+    };
+    let mut websocket = async_tungstenite::accept_async_with_config(client, Some(config)).await?;
+
+    let (response_sender, response_receiver) = async_channel::bounded(RESPONSE_CHANNEL_SIZE);
+    // Dropping the session when the connection ends cancels its watching requests.
+    let mut session = BrpSession::new(request_sender, response_sender);
+    loop {
+        let event = future::or(
+            async { ConnectionEvent::Received(websocket.next().await) },
+            async { ConnectionEvent::Response(response_receiver.recv().await.ok()) },
+        )
+        .await;
+
+        match event {
+            ConnectionEvent::Received(Some(Ok(message))) => match message {
+                Message::Text(text) => session.handle_message(&text),
+                Message::Binary(_) => {
+                    return close(&mut websocket, CloseCode::Unsupported, "Expected text").await;
+                }
+                // Pings are answered and closing handshakes completed by `tungstenite`.
+                Message::Ping(_) | Message::Pong(_) | Message::Close(_) | Message::Frame(_) => {}
+            },
+            ConnectionEvent::Received(Some(Err(error))) => {
+                let close_frame = match error {
+                    WsError::Protocol(_) => Some((CloseCode::Protocol, "Protocol error")),
+                    WsError::Utf8 => Some((CloseCode::Invalid, "Invalid UTF-8")),
+                    WsError::Capacity(_) => Some((CloseCode::Size, "Message too large")),
+                    _ => None,
+                };
+                if let Some((code, reason)) = close_frame {
+                    let _ = close(&mut websocket, code, reason).await;
+                }
+                return Err(error.into());
+            }
+            ConnectionEvent::Received(None) => return Ok(()),
+            ConnectionEvent::Response(Some(response)) => {
+                websocket.send(Message::Text(response)).await?;
+            }
+            ConnectionEvent::Response(None) => {
+                return close(
+                    &mut websocket,
+                    CloseCode::Policy,
+                    "Too many pending responses",
+                )
+                .await;
+            }
+        }
+    }
+}
+
+/// Starts the closing handshake of the connection.
+async fn close(
+    websocket: &mut WebSocketStream<impl AsyncRead + AsyncWrite + Unpin>,
+    code: CloseCode,
+    reason: &'static str,
+) -> AnyhowResult<()> {
+    websocket
+        .close(Some(CloseFrame {
+            code,
+            reason: reason.into(),
+        }))
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::tests::run_local;
+    use async_tungstenite::tungstenite::protocol::frame::{
+        coding::{Data, OpCode},
+        Frame,
+    };
+    use serde_json::{json, Value};
+    use std::net::TcpStream;
+
+    /// Connects a client to a server handling a single connection, returning the client along
+    /// with the requests forwarded by the server and the task running the server.
+    async fn connect() -> (
+        WebSocketStream<Async<TcpStream>>,
+        async_channel::Receiver<BrpMessage>,
+        bevy_tasks::Task<AnyhowResult<()>>,
+    ) {
+        let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+        let address = listener.get_ref().local_addr().unwrap();
+        let (request_sender, requests) = async_channel::unbounded();
+        let server = IoTaskPool::get().spawn(async move {
+            let (client, _) = listener.accept().await?;
+            handle_client(client, request_sender).await
+        });
+
+        let stream = Async::<TcpStream>::connect(address).await.unwrap();
+        let (client, _) = async_tungstenite::client_async(format!("ws://{address}/"), stream)
+            .await
+            .unwrap();
+        (client, requests, server)
+    }
+
+    async fn next_text(client: &mut WebSocketStream<Async<TcpStream>>) -> Value {
+        loop {
+            match client.next().await.unwrap().unwrap() {
+                Message::Text(text) => return serde_json::from_str(&text).unwrap(),
+                Message::Ping(_) | Message::Pong(_) => {}
+                message => panic!("Expected a text message, received {message:?}"),
+            }
+        }
+    }
+
+    async fn close_code(client: &mut WebSocketStream<Async<TcpStream>>) -> Option<CloseCode> {
+        loop {
+            match client.next().await? {
+                Ok(Message::Close(frame)) => return frame.map(|frame| frame.code),
+                Ok(_) => {}
+                Err(_) => return None,
+            }
+        }
+    }
+
+    #[test]
+    fn requests_and_responses() {
+        run_local(async {
+            let (mut client, requests, _server) = connect().await;
+            let request = json!({ "jsonrpc": "2.0", "id": 1, "method": "bevy/list" });
+            client
+                .send(Message::Text(request.to_string()))
+                .await
+                .unwrap();
+
+            let message = requests.recv().await.unwrap();
+            assert_eq!(message.method, "bevy/list");
+            message.sender.send(Ok(json!(["a"]))).await.unwrap();
+            assert_eq!(
+                next_text(&mut client).await,
+                json!({ "jsonrpc": "2.0", "id": 1, "result": ["a"] })
+            );
+
+            // Pings are answered without involving the session.
+            client.send(Message::Ping(vec![1, 2])).await.unwrap();
+            assert_eq!(
+                client.next().await.unwrap().unwrap(),
+                Message::Pong(vec![1, 2])
+            );
+        });
+    }
+
+    #[test]
+    fn binary_messages_close_the_connection() {
+        run_local(async {
+            let (mut client, requests, server) = connect().await;
+            client.send(Message::Binary(b"{}".to_vec())).await.unwrap();
+            assert_eq!(close_code(&mut client).await, Some(CloseCode::Unsupported));
+            assert!(server.await.is_ok());
+            assert!(requests.is_empty());
+        });
+    }
+
+    #[test]
+    fn invalid_frames_close_the_connection() {
+        let continuation = Frame::message(b"{}".to_vec(), OpCode::Data(Data::Continue), true);
+        let mut unfinished_ping = Frame::ping(vec![]);
+        unfinished_ping.header_mut().is_final = false;
+        let long_ping = Frame::ping(vec![0; 126]);
+
+        for frame in [continuation, unfinished_ping, long_ping] {
+            run_local(async {
+                let (mut client, requests, server) = connect().await;
+                client.send(Message::Frame(frame)).await.unwrap();
+                assert_eq!(close_code(&mut client).await, Some(CloseCode::Protocol));
+                assert!(server.await.is_err());
+                assert!(requests.is_empty());
+            });
+        }
+    }
+
+    #[test]
+    fn invalid_handshakes_are_rejected() {
+        run_local(async {
+            let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+            let address = listener.get_ref().local_addr().unwrap();
+            let server = IoTaskPool::get().spawn(async move {
+                let (client, _) = listener.accept().await?;
+                handle_client(client, async_channel::unbounded().0).await
+            });
+
+            let mut stream = Async::<TcpStream>::connect(address).await.unwrap();
+            bevy_tasks::futures_lite::AsyncWriteExt::write_all(
+                &mut stream,
+                b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            )
+            .await
+            .unwrap();
+            assert!(server.await.is_err());
+        });
+    }
+}
@@ -2,10 +2,12 @@ use crate::{DynamicScene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::{
     entity::{Entity, EntityHashMap, SceneEntityMapper},
+    entity_disabling::DefaultQueryFilters,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
     world::World,
 };
 use bevy_reflect::{PartialReflect, TypePath};
+use core::any::TypeId;
 
 /// A composition of [`World`] objects.
 ///
@@ -75,6 +77,11 @@ impl Scene {
                 .type_id()
                 .expect("reflected resources must have a type_id");
 
+            // Every world is created with its own default query filters, which are not part of the scene.
+            if type_id == TypeId::of::<DefaultQueryFilters>() {
+                continue;
+            }
+
             let registration =
                 type_registry
                     .get(type_id)
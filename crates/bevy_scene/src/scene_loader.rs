@@ -14,6 +14,10 @@ use serde::de::DeserializeSeed;
 /// Asset loader for a Bevy dynamic scene (`.scn` / `.scn.ron`).
 ///
 /// The loader handles assets serialized with [`DynamicScene::serialize`].
+/// Components and resources written by older versions of their type are migrated
+/// using the [`TypeMigrations`] registered in the [`AppTypeRegistry`].
+///
+/// [`TypeMigrations`]: bevy_reflect::serde::TypeMigrations
 #[derive(Debug)]
 pub struct SceneLoader {
     type_registry: TypeRegistryArc,
@@ -4,15 +4,16 @@ use crate::{DynamicEntity, DynamicScene};
 use bevy_ecs::entity::Entity;
 use bevy_reflect::{
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        MigratingReflectDeserializer, ReflectDeserializer, TypeVersion, TypeVersionTable,
+        TypeVersionTableDeserializer, TypedReflectSerializer,
+        VersionedTypeRegistrationDeserializer,
     },
     PartialReflect, ReflectFromReflect, TypeRegistry,
 };
 use bevy_utils::HashSet;
 use core::fmt::Formatter;
 use serde::{
-    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    de::{DeserializeSeed, Error, IntoDeserializer, MapAccess, SeqAccess, Visitor},
     ser::{SerializeMap, SerializeStruct},
     Deserialize, Deserializer, Serialize, Serializer,
 };
@@ -156,6 +157,10 @@ impl<'a> Serialize for EntitySerializer<'a> {
 /// deserializing through [`SceneMapDeserializer`].
 ///
 /// Note: The entries are sorted by type path before they're serialized.
+/// Types declaring a [`TypeVersion`] are written with their version, so that [`SceneMapDeserializer`]
+/// can migrate them once the type changes.
+/// The versions of the versioned types nested in the entries are written first,
+/// in a [`TypeVersionTable`] under the [`TypeVersionTable::KEY`] key.
 pub struct SceneMapSerializer<'a> {
     /// List of boxed values of unique type to serialize.
     pub entries: &'a [Box<dyn PartialReflect>],
@@ -168,25 +173,42 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
         let sorted_entries = {
             let mut entries = self
                 .entries
                 .iter()
                 .map(|entry| {
                     (
-                        entry.get_represented_type_info().unwrap().type_path(),
+                        entry.get_represented_type_info().unwrap(),
                         entry.as_partial_reflect(),
                     )
                 })
                 .collect::<Vec<_>>();
-            entries.sort_by_key(|(type_path, _partial_reflect)| *type_path);
+            entries.sort_by_key(|(type_info, _partial_reflect)| type_info.type_path());
             entries
         };
 
-        for (type_path, partial_reflect) in sorted_entries {
+        let mut nested_versions = Vec::new();
+        for (_type_info, partial_reflect) in &sorted_entries {
+            for type_info in TypeVersion::nested_types(*partial_reflect) {
+                let versioned_type_path = TypeVersion::versioned_type_path(type_info);
+                if !nested_versions.contains(&versioned_type_path) {
+                    nested_versions.push(versioned_type_path);
+                }
+            }
+        }
+        nested_versions.sort();
+
+        let mut state = if nested_versions.is_empty() {
+            serializer.serialize_map(Some(self.entries.len()))?
+        } else {
+            let mut state = serializer.serialize_map(Some(self.entries.len() + 1))?;
+            state.serialize_entry(TypeVersionTable::KEY, &nested_versions)?;
+            state
+        };
+        for (type_info, partial_reflect) in sorted_entries {
             state.serialize_entry(
-                type_path,
+                &TypeVersion::versioned_type_path(type_info),
                 &TypedReflectSerializer::new(partial_reflect, self.registry),
             )?;
         }
@@ -431,6 +453,13 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
 }
 
 /// Handles deserialization of a sequence of values with unique types.
+///
+/// Values written by older versions of their type are migrated using the type's
+/// [`TypeMigrations`] before being converted with `FromReflect`,
+/// as are their nested values, whose versions are read from the [`TypeVersionTable`]
+/// at the start of the map. Nested types missing from the table are at version `0`.
+///
+/// [`TypeMigrations`]: bevy_reflect::serde::TypeMigrations
 pub struct SceneMapDeserializer<'a> {
     /// Type registry in which the types of the values to deserialize are registered.
     pub registry: &'a TypeRegistry,
@@ -478,9 +507,21 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     {
         let mut added = HashSet::new();
         let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-        {
+        let mut versions = TypeVersionTable::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if key == TypeVersionTable::KEY {
+                if !entries.is_empty() {
+                    return Err(Error::custom(format_args!(
+                        "`{}` must be the first entry",
+                        TypeVersionTable::KEY
+                    )));
+                }
+                versions = map.next_value_seed(TypeVersionTableDeserializer::new(self.registry))?;
+                continue;
+            }
+
+            let (registration, version) = VersionedTypeRegistrationDeserializer::new(self.registry)
+                .deserialize(key.as_str().into_deserializer())?;
             if !added.insert(registration.type_id()) {
                 return Err(Error::custom(format_args!(
                     "duplicate reflect type: `{}`",
@@ -488,8 +529,10 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
                 )));
             }
 
-            let value =
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            let value = map.next_value_seed(
+                MigratingReflectDeserializer::new(registration, version, self.registry)
+                    .with_versions(&versions),
+            )?;
 
             // Attempt to convert using FromReflect.
             let value = self
@@ -521,7 +564,10 @@ mod tests {
         reflect::{AppTypeRegistry, ReflectMapEntities},
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{
+        serde::{Migration, TypeMigrations, TypeVersion},
+        FromReflect, GetField, Reflect, ReflectDeserialize, ReflectSerialize,
+    };
     use bincode::Options;
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
@@ -727,6 +773,156 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_migrate_older_components() {
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[reflect(Component, @TypeVersion(1))]
+        struct Health {
+            current: u32,
+            max: u32,
+        }
+
+        let world = create_world();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register_migrations::<Health>(
+                TypeMigrations::new()
+                    .with_alias("bevy_scene::serde::tests::Hp")
+                    .with_migration(
+                        Migration::new(0)
+                            .rename_field("value", "current")
+                            .map_struct(|health| {
+                                let current = *health.get_field::<u32>("current").unwrap();
+                                health.insert("max", current);
+                            }),
+                    ),
+            );
+        }
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Hp": (value: 50),
+      },
+    ),
+  },
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let mut dst_world = create_world();
+        dst_world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+        let health = dst_world.query::<&Health>().single(&dst_world);
+        assert_eq!(
+            health,
+            &Health {
+                current: 50,
+                max: 50
+            }
+        );
+
+        // The current version is written with the component.
+        let serialized = scene
+            .serialize(&world.resource::<AppTypeRegistry>().read())
+            .unwrap();
+        assert!(serialized.contains(r#""bevy_scene::serde::tests::Health@1": ("#));
+    }
+
+    #[test]
+    fn should_migrate_nested_values_of_components() {
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[reflect(Component)]
+        struct Inventory {
+            items: Vec<Item>,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(@TypeVersion(1))]
+        struct Item {
+            count: u32,
+        }
+
+        let world = create_world();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Inventory>();
+            registry.register_migrations::<Item>(
+                TypeMigrations::new()
+                    .with_migration(Migration::new(0).rename_field("amount", "count")),
+            );
+        }
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Inventory": (items: [(amount: 3)]),
+      },
+    ),
+  },
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let mut dst_world = create_world();
+        dst_world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Inventory>();
+        scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+        let inventory = dst_world.query::<&Inventory>().single(&dst_world);
+        assert_eq!(
+            inventory,
+            &Inventory {
+                items: vec![Item { count: 3 }]
+            }
+        );
+
+        // The versions of nested types are written before the components.
+        let serialized = scene
+            .serialize(&world.resource::<AppTypeRegistry>().read())
+            .unwrap();
+        assert!(
+            serialized.contains(
+                r#""$versions": [
+          "bevy_scene::serde::tests::Item@1",
+        ],
+        "bevy_scene::serde::tests::Inventory": ("#
+            ),
+            "{serialized}"
+        );
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+        let inventory = scene.entities[0].components[0].as_partial_reflect();
+        assert_eq!(
+            Inventory::from_reflect(inventory).unwrap(),
+            Inventory {
+                items: vec![Item { count: 3 }]
+            }
+        );
+    }
+
     fn roundtrip_ron(world: &World) -> (DynamicScene, DynamicScene) {
         let scene = DynamicScene::from_world(world);
         let registry = world.resource::<AppTypeRegistry>().read();
@@ -4,7 +4,7 @@ use bevy_hierarchy::ValidParentCheckPlugin;
 
 use crate::{
     prelude::{GlobalTransform, Transform},
-    systems::{propagate_transforms, sync_simple_transforms},
+    systems::{mark_enabled_transforms_changed, propagate_transforms, sync_simple_transforms},
 };
 
 /// Set enum for the systems relating to transform propagation
@@ -28,6 +28,7 @@ impl Plugin for TransformPlugin {
         app.register_type::<Transform>()
             .register_type::<GlobalTransform>()
             .add_plugins(ValidParentCheckPlugin::<GlobalTransform>::default())
+            .add_observer(mark_enabled_transforms_changed)
             .configure_sets(
                 PostStartup,
                 PropagateTransformsSet.in_set(TransformSystem::TransformPropagate),
@@ -1,10 +1,13 @@
 use crate::components::{GlobalTransform, Transform};
 use bevy_ecs::{
     change_detection::Ref,
-    prelude::{Changed, DetectChanges, Entity, Query, With, Without},
+    entity_disabling::Disabled,
+    observer::Trigger,
+    prelude::{Changed, DetectChanges, DetectChangesMut, Entity, Query, With, Without},
     query::{Added, Or},
     removal_detection::RemovedComponents,
     system::{Local, ParamSet},
+    world::OnRemove,
 };
 use bevy_hierarchy::{Children, Parent};
 
@@ -94,6 +97,20 @@ pub fn propagate_transforms(
     );
 }
 
+/// Marks the [`Transform`] of an entity that is being enabled as changed.
+///
+/// Transform propagation skips [`Disabled`] entities, so their [`GlobalTransform`] (and that of
+/// their descendants) may be stale by the time they are enabled again. This forces it to be
+/// recomputed on the next propagation.
+pub fn mark_enabled_transforms_changed(
+    trigger: Trigger<OnRemove, Disabled>,
+    mut transforms: Query<&mut Transform, With<Disabled>>,
+) {
+    if let Ok(mut transform) = transforms.get_mut(trigger.entity()) {
+        transform.set_changed();
+    }
+}
+
 /// Recursively propagates the transforms for `entity` and all of its descendants.
 ///
 /// # Panics
@@ -515,4 +532,42 @@ mod test {
             *world.entity(child).get::<GlobalTransform>().unwrap()
         );
     }
+
+    #[test]
+    fn enabled_subtree_is_propagated() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+        world.add_observer(mark_enabled_transforms_changed);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+
+        let child = world.spawn(Transform::from_xyz(1., 0., 0.)).id();
+        let parent = world
+            .spawn(Transform::from_xyz(1., 0., 0.))
+            .add_child(child)
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<GlobalTransform>(child).unwrap().translation(),
+            vec3(2., 0., 0.)
+        );
+
+        // Move the parent while the child is disabled; the child is not updated
+        world.entity_mut(child).insert(Disabled);
+        world.get_mut::<Transform>(parent).unwrap().translation = vec3(5., 0., 0.);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<GlobalTransform>(child).unwrap().translation(),
+            vec3(2., 0., 0.)
+        );
+
+        // Enabling the child catches it up with its parent
+        world.entity_mut(child).remove::<Disabled>();
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<GlobalTransform>(child).unwrap().translation(),
+            vec3(6., 0., 0.)
+        );
+    }
 }